@@ -0,0 +1,6 @@
+use gloo_worker::Registrable;
+use rust_wasm_github::distributions::continuous::bivariate_normal::surface::SurfaceWorker;
+
+fn main() {
+    SurfaceWorker::registrar().register();
+}
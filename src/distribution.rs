@@ -1,44 +1,82 @@
 use rust_wasm_github::distributions::continuous::beta::BetaDistribution;
 use rust_wasm_github::distributions::continuous::bivariate_normal::BivariateNormalDistribution;
+use rust_wasm_github::distributions::continuous::cauchy::CauchyDistribution;
 use rust_wasm_github::distributions::continuous::chi_squared::ChiSquaredDistribution;
 use rust_wasm_github::distributions::continuous::exponential::ExponentialDistribution;
 use rust_wasm_github::distributions::continuous::f::FDistribution;
 use rust_wasm_github::distributions::continuous::gamma::GammaDistribution;
+use rust_wasm_github::distributions::continuous::gumbel::GumbelDistribution;
+use rust_wasm_github::distributions::continuous::laplace::LaplaceDistribution;
 use rust_wasm_github::distributions::continuous::log_normal::LogNormalDistribution;
+use rust_wasm_github::distributions::continuous::logistic::LogisticDistribution;
+use rust_wasm_github::distributions::continuous::multivariate_normal::MultivariateNormalDistribution;
+use rust_wasm_github::distributions::continuous::normal::NormalDistribution;
+use rust_wasm_github::distributions::continuous::pareto::ParetoDistribution;
 use rust_wasm_github::distributions::continuous::student::TDistribution;
+use rust_wasm_github::distributions::continuous::uniform::UniformDistribution;
 use rust_wasm_github::distributions::continuous::WeibullDistribution;
+use rust_wasm_github::distributions::discrete::bernoulli::BernoulliDistribution;
+use rust_wasm_github::distributions::discrete::beta_binomial::BetaBinomialDistribution;
 use rust_wasm_github::distributions::discrete::binomial::BinomialDistribution;
+use rust_wasm_github::distributions::discrete::discrete_uniform::DiscreteUniformDistribution;
 use rust_wasm_github::distributions::discrete::geometric::GeometricDistribution;
 use rust_wasm_github::distributions::discrete::hypergeometric::HypergeometricDistribution;
 use rust_wasm_github::distributions::discrete::negative_binomial::NegativeBinomialDistribution;
 use rust_wasm_github::distributions::discrete::poisson::PoissonDistribution;
+use rust_wasm_github::distributions::discrete::skellam::SkellamDistribution;
+use rust_wasm_github::distributions::discrete::zipf::ZipfDistribution;
+use rust_wasm_github::distributions::pins::{PinnedSeries, Pins};
+use rust_wasm_github::distributions::query::query_dist;
 
 use yew::prelude::*;
+use yew_router::prelude::*;
 
 #[derive(Properties, PartialEq)]
 struct DistributionProps {
     name: &'static str,
+    /// Identifies the item in URLs.
+    slug: &'static str,
+    /// Slug of the item the URL points at.
+    focus: Option<AttrValue>,
     children: Children,
     global_visibility: bool,
 }
 
 #[function_component(DistributionItem)]
 fn distribution_item(props: &DistributionProps) -> Html {
-    let visible = use_state(|| false);
+    let focused = props.focus.as_deref() == Some(props.slug);
+    let visible = use_state(|| focused);
+    let item_ref = use_node_ref();
 
     // Update visibility based on global visibility changes
     {
         let visible = visible.clone();
         let global_visibility = props.global_visibility;
+        let first_run = use_mut_ref(|| true);
         use_effect_with_deps(
             move |_| {
-                visible.set(global_visibility);
+                // The item the URL points at stays open on the first run
+                let opened_from_url = first_run.replace(false) && focused;
+                visible.set(global_visibility || opened_from_url);
                 || ()
             },
             props.global_visibility,
         );
     }
 
+    {
+        let item_ref = item_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(item) = item_ref.cast::<web_sys::Element>().filter(|_| focused) {
+                    item.scroll_into_view();
+                }
+                || ()
+            },
+            (),
+        );
+    }
+
     let toggle_visibility = {
         let visible = visible.clone();
         Callback::from(move |_| {
@@ -49,7 +87,7 @@ fn distribution_item(props: &DistributionProps) -> Html {
     let is_visible = *visible;
 
     html! {
-        <li>
+        <li id={props.slug} ref={item_ref}>
             <div onclick={toggle_visibility} style="cursor: pointer;">
                 { if is_visible { format!("{} ▼", props.name) } else { format!("{} ►", props.name) } }
             </div>
@@ -62,9 +100,31 @@ fn distribution_item(props: &DistributionProps) -> Html {
     }
 }
 
+#[derive(Properties, PartialEq)]
+pub struct DistributionPageProps {
+    /// Slug from the `/distribution/:name` route.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+}
+
 #[function_component(Distribution)]
-pub fn distribution() -> Html {
+pub fn distribution(props: &DistributionPageProps) -> Html {
     let global_visibility = use_state(|| false);
+    // Shared by all charts so series can be compared across distributions
+    let pins = use_state(Vec::<PinnedSeries>::new);
+    // Parameter queries name their distribution too; they win over the route
+    // because they follow the last edit. Only the URL the page opened with counts.
+    let location = use_location();
+    let focus = {
+        let name = props.name.clone();
+        use_state(move || {
+            location
+                .and_then(|location| location.query::<Vec<(String, String)>>().ok())
+                .and_then(|query| query_dist(&query).map(|dist| AttrValue::from(dist.to_string())))
+                .or(name)
+        })
+    };
+    let focus = (*focus).clone();
     let toggle_global_visibility = {
         let global_visibility = global_visibility.clone();
         Callback::from(move |_| global_visibility.set(!*global_visibility))
@@ -94,7 +154,7 @@ pub fn distribution() -> Html {
     );
 
     html! {
-        <>
+        <ContextProvider<Pins> context={pins}>
             <h1>{ "Distribution" }</h1>
             <div style="position: relative;">
                 <button onclick={toggle_global_visibility} style={button_style}>
@@ -102,53 +162,92 @@ pub fn distribution() -> Html {
                 </button>
                 <h2>{"🤯 Discrete Distributions" }</h2>
                 <ul>
-                    <DistributionItem name="Binomial Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Bernoulli Distribution" slug="bernoulli" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <BernoulliDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Discrete Uniform Distribution" slug="discrete-uniform" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <DiscreteUniformDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Binomial Distribution" slug="binomial" focus={focus.clone()} global_visibility={*global_visibility}>
                         <BinomialDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Poisson Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Poisson Distribution" slug="poisson" focus={focus.clone()} global_visibility={*global_visibility}>
                         <PoissonDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Geometric Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Geometric Distribution" slug="geometric" focus={focus.clone()} global_visibility={*global_visibility}>
                         <GeometricDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Negative Binomial Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Negative Binomial Distribution" slug="negative-binomial" focus={focus.clone()} global_visibility={*global_visibility}>
                         <NegativeBinomialDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Hypergeometric Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Hypergeometric Distribution" slug="hypergeometric" focus={focus.clone()} global_visibility={*global_visibility}>
                         <HypergeometricDistribution />
                     </DistributionItem>
+                    <DistributionItem name="Beta-Binomial Distribution" slug="beta-binomial" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <BetaBinomialDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Zipf Distribution" slug="zipf" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <ZipfDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Skellam Distribution" slug="skellam" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <SkellamDistribution />
+                    </DistributionItem>
                 </ul>
                 <h2>{"😂 Continuous Distributions" }</h2>
                 <ul>
-                    <DistributionItem name="Exponential Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Normal Distribution" slug="normal" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <NormalDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Exponential Distribution" slug="exponential" focus={focus.clone()} global_visibility={*global_visibility}>
                         <ExponentialDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Weibull Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Weibull Distribution" slug="weibull" focus={focus.clone()} global_visibility={*global_visibility}>
                         <WeibullDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Gamma Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Gamma Distribution" slug="gamma" focus={focus.clone()} global_visibility={*global_visibility}>
                         <GammaDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Beta Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Beta Distribution" slug="beta" focus={focus.clone()} global_visibility={*global_visibility}>
                         <BetaDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Chi Squared Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Chi Squared Distribution" slug="chi-squared" focus={focus.clone()} global_visibility={*global_visibility}>
                         <ChiSquaredDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Student's T Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Student's T Distribution" slug="student-t" focus={focus.clone()} global_visibility={*global_visibility}>
                         <TDistribution />
                     </DistributionItem>
-                    <DistributionItem name="F Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="F Distribution" slug="f" focus={focus.clone()} global_visibility={*global_visibility}>
                         <FDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Log Normal Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Log Normal Distribution" slug="log-normal" focus={focus.clone()} global_visibility={*global_visibility}>
                         <LogNormalDistribution />
                     </DistributionItem>
-                    <DistributionItem name="Bivariate Normal Distribution" global_visibility={*global_visibility}>
+                    <DistributionItem name="Uniform Distribution" slug="uniform" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <UniformDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Cauchy Distribution" slug="cauchy" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <CauchyDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Laplace Distribution" slug="laplace" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <LaplaceDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Logistic Distribution" slug="logistic" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <LogisticDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Pareto Distribution" slug="pareto" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <ParetoDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Gumbel Distribution" slug="gumbel" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <GumbelDistribution />
+                    </DistributionItem>
+                    <DistributionItem name="Bivariate Normal Distribution" slug="bivariate-normal" focus={focus.clone()} global_visibility={*global_visibility}>
                         <BivariateNormalDistribution />
                     </DistributionItem>
+                    <DistributionItem name="Multivariate Normal Distribution" slug="multivariate-normal" focus={focus.clone()} global_visibility={*global_visibility}>
+                        <MultivariateNormalDistribution />
+                    </DistributionItem>
                 </ul>
             </div>
-        </>
+        </ContextProvider<Pins>>
     }
 }
@@ -1,2 +1,18 @@
+pub mod approximations;
+pub mod axes;
+pub mod bayes;
+pub mod clt;
+pub mod confidence;
 pub mod continuous;
+pub mod controls;
+pub mod diagnostics;
 pub mod discrete;
+pub mod export;
+pub mod fit;
+pub mod hypothesis;
+pub mod input;
+pub mod interval;
+pub mod moments;
+pub mod pins;
+pub mod query;
+pub mod simulation;
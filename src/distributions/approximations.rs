@@ -0,0 +1,625 @@
+//! Limit theorems and approximations between the families.
+//!
+//! Each limit pairs an exact distribution with the one it approaches as a
+//! parameter grows. Both are drawn on one chart, and their total variation
+//! and Kolmogorov distances are shown live and along the whole slider range.
+
+use crate::distributions::export::{draw_svg, ExportButtons};
+use crate::distributions::query::{use_query_state, use_query_state_in, QueryValue};
+use crate::stats::continuous::{ChiSquared, Gamma, Normal, StudentT};
+use crate::stats::discrete::{Binomial, Hypergeometric, Poisson};
+use crate::stats::distance::{
+    continuity_corrected, continuous_total_variation, discrete_total_variation, kolmogorov_distance,
+};
+use crate::stats::{Continuous, Discrete};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+/// Sample size of the hypergeometric limit.
+const DRAWS: u64 = 10;
+/// Parameter values along the slider range in the convergence chart.
+const CONVERGENCE_STEPS: usize = 50;
+/// Points where continuous distributions are drawn and compared.
+const CURVE_POINTS: usize = 1000;
+
+/// A convergence relationship between two families.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Limit {
+    BinomialPoisson,
+    BinomialNormal,
+    StudentNormal,
+    GammaChiSquared,
+    HypergeometricBinomial,
+}
+
+/// A slider: label, minimum, maximum, step and default value.
+type Slider = (&'static str, f64, f64, f64, f64);
+
+impl Limit {
+    const ALL: [Limit; 5] = [
+        Self::BinomialPoisson,
+        Self::BinomialNormal,
+        Self::StudentNormal,
+        Self::GammaChiSquared,
+        Self::HypergeometricBinomial,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::BinomialPoisson => "Binomial → Poisson",
+            Self::BinomialNormal => "Binomial → Normal",
+            Self::StudentNormal => "Student's t → Normal",
+            Self::GammaChiSquared => "Gamma → Chi-squared",
+            Self::HypergeometricBinomial => "Hypergeometric → Binomial",
+        }
+    }
+
+    fn slug(self) -> &'static str {
+        match self {
+            Self::BinomialPoisson => "binomial-poisson",
+            Self::BinomialNormal => "binomial-normal",
+            Self::StudentNormal => "student-normal",
+            Self::GammaChiSquared => "gamma-chi-squared",
+            Self::HypergeometricBinomial => "hypergeometric-binomial",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::BinomialPoisson => {
+                "Binomial(n, λ/n) → Poisson(λ) as n → ∞ with the mean np = λ held fixed: rare events among many trials."
+            }
+            Self::BinomialNormal => {
+                "Binomial(n, p) ≈ Normal(np, np(1 - p)) for large n. With the continuity correction the normal gives k the mass of [k - ½, k + ½]."
+            }
+            Self::StudentNormal => {
+                "Student's t(ν) → Normal(0, 1) as ν → ∞: the estimated standard deviation becomes exact."
+            }
+            Self::GammaChiSquared => {
+                "Gamma(k/2, rate ½) is exactly χ²(k), so the distance stays at zero up to rounding."
+            }
+            Self::HypergeometricBinomial => {
+                "Hypergeometric(N, pN, K) → Binomial(K, p) as N → ∞: drawing without replacement from a large population is almost drawing with replacement."
+            }
+        }
+    }
+
+    /// The parameter that tends to infinity.
+    fn parameter(self) -> Slider {
+        match self {
+            // n stays at least twice the largest λ, so p = λ/n never exceeds ½
+            Self::BinomialPoisson => ("n", 20.0, 1000.0, 1.0, 20.0),
+            Self::BinomialNormal => ("n", 1.0, 500.0, 1.0, 10.0),
+            Self::StudentNormal => ("ν", 1.0, 100.0, 1.0, 3.0),
+            Self::GammaChiSquared => ("k", 1.0, 50.0, 1.0, 4.0),
+            Self::HypergeometricBinomial => ("N", 20.0, 2000.0, 10.0, 40.0),
+        }
+    }
+
+    /// The parameter held fixed along the limit, if any.
+    fn fixed(self) -> Option<Slider> {
+        match self {
+            Self::BinomialPoisson => Some(("λ = np", 0.5, 10.0, 0.5, 4.0)),
+            Self::BinomialNormal => Some(("p", 0.01, 0.99, 0.01, 0.3)),
+            Self::HypergeometricBinomial => Some(("p = M/N", 0.05, 0.95, 0.05, 0.3)),
+            Self::StudentNormal | Self::GammaChiSquared => None,
+        }
+    }
+
+    fn default_fixed(self) -> f64 {
+        self.fixed().map_or(0.0, |(_, _, _, _, default)| default)
+    }
+}
+
+impl QueryValue for Limit {
+    fn to_query(&self) -> String {
+        self.slug().to_string()
+    }
+
+    fn from_query(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|limit| limit.slug() == value)
+    }
+}
+
+enum Law {
+    Discrete(Box<dyn Discrete>),
+    Continuous(Box<dyn Continuous>),
+}
+
+impl Law {
+    fn quantile(&self, p: f64) -> f64 {
+        match self {
+            Law::Discrete(dist) => dist.quantile(p) as f64,
+            Law::Continuous(dist) => dist.quantile(p),
+        }
+    }
+
+    /// Mass at the integer k, continuity corrected for continuous laws.
+    fn mass(&self, k: i64) -> f64 {
+        match self {
+            Law::Discrete(dist) => dist.pmf(k),
+            Law::Continuous(dist) => continuity_corrected(dist.as_ref(), k),
+        }
+    }
+
+    /// P(X <= k) at the integer k, continuity corrected for continuous laws.
+    fn cdf_at(&self, k: i64) -> f64 {
+        match self {
+            Law::Discrete(dist) => dist.cdf(k),
+            Law::Continuous(dist) => dist.cdf(k as f64 + 0.5),
+        }
+    }
+}
+
+/// An exact distribution and its approximation at one point along a limit.
+struct Comparison {
+    exact: Law,
+    exact_label: String,
+    approximation: Law,
+    approximation_label: String,
+}
+
+impl Comparison {
+    fn new(limit: Limit, parameter: f64, fixed: f64) -> Self {
+        match limit {
+            Limit::BinomialPoisson => {
+                let n = parameter as u64;
+                let p = fixed / n as f64;
+                Self {
+                    exact: Law::Discrete(Box::new(Binomial::new(n, p))),
+                    exact_label: format!("Binomial(n = {}, p = {:.3})", n, p),
+                    approximation: Law::Discrete(Box::new(Poisson::new(fixed))),
+                    approximation_label: format!("Poisson(λ = {})", fixed),
+                }
+            }
+            Limit::BinomialNormal => {
+                let n = parameter as u64;
+                let mean = n as f64 * fixed;
+                let std_dev = (mean * (1.0 - fixed)).sqrt();
+                Self {
+                    exact: Law::Discrete(Box::new(Binomial::new(n, fixed))),
+                    exact_label: format!("Binomial(n = {}, p = {:.2})", n, fixed),
+                    approximation: Law::Continuous(Box::new(Normal::new(mean, std_dev))),
+                    approximation_label: format!("Normal(μ = {:.2}, σ = {:.2})", mean, std_dev),
+                }
+            }
+            Limit::StudentNormal => Self {
+                exact: Law::Continuous(Box::new(StudentT::new(parameter))),
+                exact_label: format!("t(ν = {})", parameter),
+                approximation: Law::Continuous(Box::new(Normal::new(0.0, 1.0))),
+                approximation_label: "Normal(0, 1)".to_string(),
+            },
+            Limit::GammaChiSquared => Self {
+                exact: Law::Continuous(Box::new(Gamma::new(parameter / 2.0, 0.5))),
+                exact_label: format!("Gamma(α = {}, β = 0.5)", parameter / 2.0),
+                approximation: Law::Continuous(Box::new(ChiSquared::new(parameter))),
+                approximation_label: format!("χ²(k = {})", parameter),
+            },
+            Limit::HypergeometricBinomial => {
+                let population = parameter as u64;
+                let successes = (fixed * population as f64).round() as u64;
+                let p = successes as f64 / population as f64;
+                Self {
+                    exact: Law::Discrete(Box::new(Hypergeometric::new(
+                        population, successes, DRAWS,
+                    ))),
+                    exact_label: format!(
+                        "Hypergeometric(N = {}, M = {}, K = {})",
+                        population, successes, DRAWS
+                    ),
+                    approximation: Law::Discrete(Box::new(Binomial::new(DRAWS, p))),
+                    approximation_label: format!("Binomial(n = {}, p = {:.2})", DRAWS, p),
+                }
+            }
+        }
+    }
+
+    /// Smallest and largest of the two distributions' `p` and `1 - p` quantiles.
+    fn span(&self, p: f64) -> (f64, f64) {
+        (
+            self.exact.quantile(p).min(self.approximation.quantile(p)),
+            self.exact
+                .quantile(1.0 - p)
+                .max(self.approximation.quantile(1.0 - p)),
+        )
+    }
+
+    /// Total variation and Kolmogorov distances.
+    fn distances(&self) -> (f64, f64) {
+        match &self.exact {
+            Law::Discrete(_) => {
+                let (lower, upper) = self.span(1e-12);
+                let ks = lower.floor() as i64..=upper.ceil() as i64;
+                let total_variation = discrete_total_variation(
+                    |k| self.exact.mass(k),
+                    |k| self.approximation.mass(k),
+                    ks.clone(),
+                );
+                let kolmogorov = kolmogorov_distance(
+                    |x| self.exact.cdf_at(x as i64),
+                    |x| self.approximation.cdf_at(x as i64),
+                    ks.map(|k| k as f64),
+                );
+                (total_variation, kolmogorov)
+            }
+            Law::Continuous(exact) => {
+                let Law::Continuous(approximation) = &self.approximation else {
+                    unreachable!("continuous laws are only approximated by continuous ones")
+                };
+                let (lower, upper) = self.span(0.001);
+                let total_variation = continuous_total_variation(
+                    exact.as_ref(),
+                    approximation.as_ref(),
+                    lower,
+                    upper,
+                );
+                let kolmogorov = kolmogorov_distance(
+                    |x| exact.cdf(x),
+                    |x| approximation.cdf(x),
+                    curve_points(lower, upper),
+                );
+                (total_variation, kolmogorov)
+            }
+        }
+    }
+}
+
+fn curve_points(lower: f64, upper: f64) -> impl Iterator<Item = f64> {
+    (0..=CURVE_POINTS).map(move |i| lower + (upper - lower) * i as f64 / CURVE_POINTS as f64)
+}
+
+/// Total variation distance at evenly spaced parameter values along the slider.
+fn convergence(limit: Limit, fixed: f64) -> Vec<(f64, f64)> {
+    let (_, min, max, step, _) = limit.parameter();
+    let mut values: Vec<f64> = (0..=CONVERGENCE_STEPS)
+        .map(|i| {
+            let value = min + (max - min) * i as f64 / CONVERGENCE_STEPS as f64;
+            (value / step).round() * step
+        })
+        .collect();
+    values.dedup();
+    values
+        .into_iter()
+        .map(|value| (value, Comparison::new(limit, value, fixed).distances().0))
+        .collect()
+}
+
+#[function_component(Approximations)]
+pub fn approximations() -> Html {
+    let limit = use_query_state("approximations", "limit", Limit::BinomialPoisson);
+    let (_, min, max, _, default) = limit.parameter();
+    let parameter = use_query_state_in("approximations", "t", default, min..=max);
+    let fixed_range = limit
+        .fixed()
+        .map_or(0.0..=0.0, |(_, min, max, _, _)| min..=max);
+    let fixed = use_query_state_in(
+        "approximations",
+        "fixed",
+        limit.default_fixed(),
+        fixed_range,
+    );
+    let comparison_ref = use_node_ref();
+    let convergence_ref = use_node_ref();
+
+    let (total_variation, kolmogorov) = Comparison::new(*limit, *parameter, *fixed).distances();
+    let path = use_memo(
+        |&(limit, fixed)| convergence(limit, fixed),
+        (*limit, *fixed),
+    );
+
+    {
+        let limit = *limit;
+        let parameter = *parameter;
+        let fixed = *fixed;
+        let comparison_ref = comparison_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = fit_canvas(&comparison_ref) {
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_comparison(&backend.into_drawing_area(), limit, parameter, fixed);
+                }
+                || ()
+            },
+            (limit, parameter, fixed),
+        );
+    }
+
+    {
+        let limit = *limit;
+        let parameter = *parameter;
+        let drawn = path.clone();
+        let convergence_ref = convergence_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = fit_canvas(&convergence_ref) {
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_convergence(&backend.into_drawing_area(), limit, parameter, &drawn);
+                }
+                || ()
+            },
+            (limit, parameter, path.clone()),
+        );
+    }
+
+    let onchange_limit = |option: Limit| {
+        let limit = limit.clone();
+        let parameter = parameter.clone();
+        let fixed = fixed.clone();
+        Callback::from(move |_: Event| {
+            limit.set(option);
+            parameter.set(option.parameter().4);
+            fixed.set(option.default_fixed());
+        })
+    };
+
+    let oninput = |state: UseStateHandle<f64>| {
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    state.set(value);
+                }
+            }
+        })
+    };
+
+    let slider = |(label, min, max, step, _): Slider, state: &UseStateHandle<f64>| {
+        html! {
+            <div>
+                <label>{ format!("{}: ", label) }</label>
+                <input type="range" min={min.to_string()} max={max.to_string()} step={step.to_string()}
+                value={(**state).to_string()} oninput={oninput(state.clone())} style="width: 70%; " />
+                <span>{ **state }</span>
+            </div>
+        }
+    };
+
+    let render_comparison_svg = {
+        let (limit, parameter, fixed) = (*limit, *parameter, *fixed);
+        Callback::from(move |size| {
+            draw_svg(size, |root| draw_comparison(root, limit, parameter, fixed))
+        })
+    };
+
+    let render_convergence_svg = {
+        let (limit, parameter) = (*limit, *parameter);
+        let path = path.clone();
+        Callback::from(move |size| {
+            draw_svg(size, |root| draw_convergence(root, limit, parameter, &path))
+        })
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h1>{ "Limits and Approximations" }</h1>
+            <div>
+                { for Limit::ALL.into_iter().map(|option| html! {
+                    <label>
+                        <input type="radio" name="limit" checked={*limit == option}
+                        onchange={onchange_limit(option)} />
+                        { format!(" {} ", option.label()) }
+                    </label>
+                }) }
+            </div>
+            <p>{ limit.description() }</p>
+            { slider(limit.parameter(), &parameter) }
+            if let Some(fixed_slider) = limit.fixed() {
+                { slider(fixed_slider, &fixed) }
+            }
+            <p>
+                { format!("Total variation distance: {:.5}", total_variation) }
+                { " | " }
+                { format!("Kolmogorov distance: {:.5}", kolmogorov) }
+            </p>
+            <canvas ref={comparison_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="approximation" canvas_ref={comparison_ref} render_svg={render_comparison_svg} />
+            <canvas ref={convergence_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="approximation-convergence" canvas_ref={convergence_ref} render_svg={render_convergence_svg} />
+        </div>
+    }
+}
+
+/// The canvas behind `canvas_ref`, sized to its parent container.
+fn fit_canvas(canvas_ref: &NodeRef) -> Option<HtmlCanvasElement> {
+    let canvas = canvas_ref.cast::<HtmlCanvasElement>()?;
+    let width = canvas.parent_element()?.client_width();
+    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+    canvas.set_width(width as u32);
+    canvas.set_height(height as u32);
+    Some(canvas)
+}
+
+/// The exact distribution in red and its approximation in blue.
+fn draw_comparison<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    limit: Limit,
+    parameter: f64,
+    fixed: f64,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let comparison = Comparison::new(limit, parameter, fixed);
+    let (total_variation, _) = comparison.distances();
+    let (lower, upper) = comparison.span(0.001);
+    let discrete = matches!(comparison.exact, Law::Discrete(_));
+    let (x_min, x_max) = if discrete {
+        (lower - 1.0, upper + 1.0)
+    } else {
+        let padding = 0.05 * (upper - lower);
+        (lower - padding, upper + padding)
+    };
+    let ks = lower as i64..=upper as i64;
+
+    let heights = |law: &Law| -> Vec<f64> {
+        match law {
+            Law::Discrete(dist) => ks.clone().map(|k| dist.pmf(k)).collect(),
+            Law::Continuous(dist) => curve_points(x_min, x_max).map(|x| dist.pdf(x)).collect(),
+        }
+    };
+    let y_max = heights(&comparison.exact)
+        .into_iter()
+        .chain(heights(&comparison.approximation))
+        .filter(|y| y.is_finite())
+        .fold(0.0, f64::max)
+        * 1.1;
+
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(
+            format!("Total variation distance: {:.4}", total_variation),
+            ("sans-serif", 20),
+        )
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(x_min..x_max, 0.0..y_max.max(f64::EPSILON))
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    let exact_color = RED.mix(0.5);
+    match &comparison.exact {
+        Law::Discrete(dist) => chart
+            .draw_series(ks.clone().map(|k| {
+                let x = k as f64;
+                Rectangle::new(
+                    [(x - 0.5, 0.0), (x + 0.5, dist.pmf(k))],
+                    exact_color.filled(),
+                )
+            }))
+            .unwrap()
+            .label(comparison.exact_label.clone())
+            .legend(move |(x, y)| {
+                Rectangle::new([(x, y - 5), (x + 20, y + 5)], exact_color.filled())
+            }),
+        Law::Continuous(dist) => chart
+            .draw_series(LineSeries::new(
+                curve_points(x_min, x_max).map(|x| (x, dist.pdf(x).min(y_max))),
+                RED.stroke_width(4),
+            ))
+            .unwrap()
+            .label(comparison.exact_label.clone())
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED)),
+    };
+
+    match &comparison.approximation {
+        Law::Discrete(dist) => chart
+            .draw_series(
+                LineSeries::new(ks.map(|k| (k as f64, dist.pmf(k))), BLUE.stroke_width(2))
+                    .point_size(3),
+            )
+            .unwrap()
+            .label(comparison.approximation_label.clone())
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE)),
+        Law::Continuous(dist) => chart
+            .draw_series(LineSeries::new(
+                curve_points(x_min, x_max).map(|x| (x, dist.pdf(x).min(y_max))),
+                BLUE.stroke_width(2),
+            ))
+            .unwrap()
+            .label(comparison.approximation_label.clone())
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE)),
+    };
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
+
+/// Total variation distance along the slider range, with the current setting marked.
+fn draw_convergence<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    limit: Limit,
+    parameter: f64,
+    path: &[(f64, f64)],
+) {
+    root.fill(&WHITE).unwrap();
+
+    let (name, min, max, _, _) = limit.parameter();
+    let y_max = path.iter().map(|&(_, tv)| tv).fold(0.0, f64::max) * 1.1;
+    let current = path
+        .iter()
+        .min_by(|a, b| (a.0 - parameter).abs().total_cmp(&(b.0 - parameter).abs()))
+        .copied();
+
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(
+            format!("Total variation distance as {} grows", name),
+            ("sans-serif", 20),
+        )
+        .x_label_area_size(30)
+        .y_label_area_size(50)
+        .build_cartesian_2d(min..max, 0.0..y_max.max(1e-3))
+        .unwrap();
+
+    chart.configure_mesh().x_desc(name).draw().unwrap();
+
+    chart
+        .draw_series(LineSeries::new(path.iter().copied(), BLUE.stroke_width(2)))
+        .unwrap();
+
+    if let Some(point) = current {
+        chart
+            .draw_series(std::iter::once(Circle::new(point, 5, RED.filled())))
+            .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances_shrink_along_each_limit() {
+        for limit in Limit::ALL {
+            let (_, min, max, _, _) = limit.parameter();
+            let fixed = limit.default_fixed();
+            let (start, _) = Comparison::new(limit, min, fixed).distances();
+            let (end, kolmogorov) = Comparison::new(limit, max, fixed).distances();
+            assert!(end <= start + 1e-6, "{:?}: {} > {}", limit, end, start);
+            assert!(end < 0.02, "{:?}: {}", limit, end);
+            assert!(kolmogorov <= end + 1e-6, "{:?}", limit);
+        }
+
+        let (total_variation, _) = Comparison::new(Limit::GammaChiSquared, 3.0, 0.0).distances();
+        assert!(total_variation < 1e-6);
+    }
+
+    #[test]
+    fn test_distances_are_finite_at_slider_extremes() {
+        for limit in Limit::ALL {
+            let (_, min, max, _, _) = limit.parameter();
+            let fixed = match limit.fixed() {
+                Some((_, low, high, _, _)) => vec![low, high],
+                None => vec![0.0],
+            };
+            for parameter in [min, max] {
+                for &fixed in &fixed {
+                    let (total_variation, kolmogorov) =
+                        Comparison::new(limit, parameter, fixed).distances();
+                    assert!(
+                        (0.0..=1.0).contains(&total_variation) && (0.0..=1.0).contains(&kolmogorov),
+                        "{:?} at {}, {}: {}, {}",
+                        limit,
+                        parameter,
+                        fixed,
+                        total_variation,
+                        kolmogorov
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_limit_round_trips_through_query() {
+        for limit in Limit::ALL {
+            assert_eq!(Limit::from_query(&limit.to_query()), Some(limit));
+        }
+        assert_eq!(Limit::from_query("normal"), None);
+    }
+}
@@ -0,0 +1,168 @@
+//! Chart ranges fitted to the distribution being drawn.
+//!
+//! The x-axis spans the central 99.8% of the mass and the y-axis reaches just
+//! above the highest point of the curve, so neither heavy tails nor tall
+//! peaks push the interesting part out of view.
+
+use crate::stats::{Continuous, Discrete};
+use std::ops::{Range, RangeInclusive};
+
+const LOWER_QUANTILE: f64 = 0.001;
+const UPPER_QUANTILE: f64 = 0.999;
+/// Share of the quantile range added on both sides of a continuous x-axis, so
+/// the jumps at the ends of a bounded support stay visible.
+const PADDING: f64 = 0.05;
+/// Room above the highest point for the legend.
+const HEADROOM: f64 = 1.1;
+/// Continuous curves are evaluated at this many intervals across the x-axis.
+const INTERVALS: usize = 1000;
+/// Most bars on a discrete x-axis.
+pub const MAX_BARS: i32 = 1000;
+
+/// Plotted ranges: x from `x_min` to `x_max`, y from 0 to `y_max`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Axes<X> {
+    pub x_min: X,
+    pub x_max: X,
+    pub y_max: f64,
+}
+
+impl<X> Axes<X> {
+    pub fn y_range(&self) -> Range<f64> {
+        0.0..self.y_max
+    }
+}
+
+impl Axes<f64> {
+    pub fn continuous(dist: &(impl Continuous + ?Sized), show_cdf: bool) -> Self {
+        let (lower, upper) = (dist.quantile(LOWER_QUANTILE), dist.quantile(UPPER_QUANTILE));
+        let (x_min, x_max) = if lower.is_finite() && upper.is_finite() && lower < upper {
+            let padding = PADDING * (upper - lower);
+            (lower - padding, upper + padding)
+        } else {
+            (-1.0, 1.0)
+        };
+        let mut axes = Self {
+            x_min,
+            x_max,
+            y_max: 1.0,
+        };
+        if !show_cdf {
+            axes.y_max = y_max(axes.points().map(|x| dist.pdf(x)));
+        }
+        axes
+    }
+
+    pub fn x_range(&self) -> Range<f64> {
+        self.x_min..self.x_max
+    }
+
+    /// Evenly spaced points where the curve is evaluated, both ends included.
+    pub fn points(&self) -> impl Iterator<Item = f64> {
+        let (x_min, x_max) = (self.x_min, self.x_max);
+        (0..=INTERVALS).map(move |i| x_min + (x_max - x_min) * i as f64 / INTERVALS as f64)
+    }
+}
+
+impl Axes<i32> {
+    pub fn discrete(dist: &(impl Discrete + ?Sized), show_cdf: bool) -> Self {
+        let (lower, upper) = (dist.quantile(LOWER_QUANTILE), dist.quantile(UPPER_QUANTILE));
+        Self::discrete_between(dist, lower, upper, show_cdf)
+    }
+
+    /// Like `discrete`, widened to take in `lower..=upper` as well.
+    pub fn discrete_covering(
+        dist: &(impl Discrete + ?Sized),
+        lower: i64,
+        upper: i64,
+        show_cdf: bool,
+    ) -> Self {
+        let lower = lower.min(dist.quantile(LOWER_QUANTILE));
+        let upper = upper.max(dist.quantile(UPPER_QUANTILE));
+        Self::discrete_between(dist, lower, upper, show_cdf)
+    }
+
+    /// At most `MAX_BARS` bars from `lower`, so a heavy tail cannot stall the chart.
+    fn discrete_between(
+        dist: &(impl Discrete + ?Sized),
+        lower: i64,
+        upper: i64,
+        show_cdf: bool,
+    ) -> Self {
+        let clamp = |k: i64| k.clamp(i32::MIN as i64 / 2, i32::MAX as i64 / 2) as i32;
+        let x_min = clamp(lower);
+        let x_max = clamp(upper).clamp(x_min, x_min + MAX_BARS);
+        let mut axes = Self {
+            x_min,
+            x_max,
+            y_max: 1.0,
+        };
+        if !show_cdf {
+            axes.y_max = y_max(axes.points().map(|k| dist.pmf(k as i64)));
+        }
+        axes
+    }
+
+    /// Bars span [k, k + 1), so the axis ends one past `x_max`.
+    pub fn x_range(&self) -> Range<i32> {
+        self.x_min..self.x_max + 1
+    }
+
+    pub fn points(&self) -> RangeInclusive<i32> {
+        self.x_min..=self.x_max
+    }
+}
+
+/// Largest finite value with some headroom, ignoring the poles of unbounded densities.
+fn y_max(values: impl Iterator<Item = f64>) -> f64 {
+    let max = values.filter(|v| v.is_finite()).fold(0.0, f64::max);
+    if max > 0.0 {
+        max * HEADROOM
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::continuous::{Gamma, Normal, Uniform};
+    use crate::stats::discrete::{Binomial, Poisson};
+
+    #[test]
+    fn test_continuous_axes_follow_the_distribution() {
+        let axes = Axes::continuous(&Normal::new(10.0, 2.0), false);
+        let half_width = 3.09 * 2.0 * (1.0 + 2.0 * PADDING);
+        assert!((axes.x_min - (10.0 - half_width)).abs() < 0.05);
+        assert!((axes.x_max - (10.0 + half_width)).abs() < 0.05);
+        let peak = 1.0 / (2.0 * (2.0 * std::f64::consts::PI).sqrt());
+        assert!((axes.y_max / HEADROOM - peak).abs() < 1e-4);
+        assert_eq!(axes.points().count(), INTERVALS + 1);
+
+        // Both edges of a bounded support are in view
+        let axes = Axes::continuous(&Uniform::new(-1.0, 1.0), true);
+        assert!(axes.x_min < -1.0 && axes.x_max > 1.0);
+        assert_eq!(axes.y_max, 1.0);
+    }
+
+    #[test]
+    fn test_unbounded_density_keeps_a_finite_axis() {
+        let axes = Axes::continuous(&Gamma::new(0.5, 1.0), false);
+        assert!(axes.y_max.is_finite() && axes.y_max > 1.0);
+        assert!(axes.x_max > 5.0);
+    }
+
+    #[test]
+    fn test_discrete_axes_follow_the_distribution() {
+        let axes = Axes::discrete(&Binomial::new(500, 0.5), false);
+        assert!(axes.x_min > 200 && axes.x_max < 300);
+        assert!(axes.y_max < 0.05);
+
+        let axes = Axes::discrete(&Poisson::new(30.0), false);
+        assert!(axes.x_min > 10 && axes.x_max > 40);
+        assert_eq!(axes.x_range(), axes.x_min..axes.x_max + 1);
+
+        let axes = Axes::discrete_covering(&Poisson::new(30.0), 0, 1_000_000_000, false);
+        assert_eq!((axes.x_min, axes.x_max), (0, MAX_BARS));
+    }
+}
@@ -0,0 +1,627 @@
+//! Bayesian updating with conjugate priors.
+//!
+//! Observations are entered one at a time. Each one turns the prior into a
+//! posterior of the same family, drawn with the likelihood of the data so far,
+//! its credible interval and the posterior predictive of the next data.
+
+use crate::distributions::axes::Axes;
+use crate::distributions::export::{draw_svg, ExportButtons};
+use crate::distributions::input::parse_number;
+use crate::distributions::moments::format_moment;
+use crate::distributions::query::{
+    use_query_state, use_query_state_if, use_query_state_in, QueryValue,
+};
+use crate::stats::bayes::{Predictive, Prior, PREDICTIVE_TRIALS};
+use crate::stats::Continuous;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::{HtmlCanvasElement, HtmlInputElement};
+use yew::prelude::*;
+
+/// Observations listed on the page; older ones are summarised by the count.
+const LISTED: usize = 30;
+
+/// Prior family and the data model it is conjugate to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Model {
+    BetaBinomial,
+    GammaPoisson,
+    NormalNormal,
+}
+
+impl Model {
+    const ALL: [Model; 3] = [Self::BetaBinomial, Self::GammaPoisson, Self::NormalNormal];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::BetaBinomial => "Beta prior, Binomial data",
+            Self::GammaPoisson => "Gamma prior, Poisson data",
+            Self::NormalNormal => "Normal prior, Normal data",
+        }
+    }
+
+    fn slug(self) -> &'static str {
+        match self {
+            Self::BetaBinomial => "beta-binomial",
+            Self::GammaPoisson => "gamma-poisson",
+            Self::NormalNormal => "normal-normal",
+        }
+    }
+
+    /// Name of the parameter the prior is over.
+    fn parameter(self) -> &'static str {
+        match self {
+            Self::BetaBinomial => "p",
+            Self::GammaPoisson => "λ",
+            Self::NormalNormal => "μ",
+        }
+    }
+
+    /// Parses a typed observation, which must be a trial outcome for Binomial
+    /// data and a count for Poisson data.
+    fn observation(self, text: &str) -> Result<f64, String> {
+        let value = parse_number(text)?;
+        match self {
+            Self::BetaBinomial if value != 0.0 && value != 1.0 => Err(
+                "Binomial observations are trials: 1 for a success, 0 for a failure".to_string(),
+            ),
+            Self::GammaPoisson if value < 0.0 || value.fract() != 0.0 => {
+                Err("Poisson observations are counts: 0, 1, 2, …".to_string())
+            }
+            _ => Ok(value),
+        }
+    }
+}
+
+impl QueryValue for Model {
+    fn to_query(&self) -> String {
+        self.slug().to_string()
+    }
+
+    fn from_query(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|model| model.slug() == value)
+    }
+}
+
+/// "Beta(α = 2.00, β = 2.00)" and the like.
+fn describe(prior: Prior) -> String {
+    match prior {
+        Prior::Beta { alpha, beta } => format!(
+            "Beta(α = {}, β = {})",
+            format_moment(alpha),
+            format_moment(beta)
+        ),
+        Prior::Gamma { shape, rate } => format!(
+            "Gamma(shape = {}, rate = {})",
+            format_moment(shape),
+            format_moment(rate)
+        ),
+        Prior::Normal { mean, std_dev, .. } => format!(
+            "Normal(μ = {}, σ = {})",
+            format_moment(mean),
+            format_moment(std_dev)
+        ),
+    }
+}
+
+/// Valid prior parameters other than the normal mean.
+fn is_positive(value: &f64) -> bool {
+    *value > 0.0 && value.is_finite()
+}
+
+#[function_component(BayesianUpdating)]
+pub fn bayesian_updating() -> Html {
+    let model = use_query_state("bayes", "model", Model::BetaBinomial);
+    let alpha = use_query_state_if("bayes", "alpha", 2.0, is_positive);
+    let beta = use_query_state_if("bayes", "beta", 2.0, is_positive);
+    let shape = use_query_state_if("bayes", "shape", 2.0, is_positive);
+    let rate = use_query_state_if("bayes", "rate", 1.0, is_positive);
+    let mean = use_query_state_if("bayes", "mean", 0.0, |mean: &f64| mean.is_finite());
+    let std_dev = use_query_state_if("bayes", "std_dev", 2.0, is_positive);
+    let sigma = use_query_state_if("bayes", "sigma", 1.0, is_positive);
+    let level = use_query_state_in("bayes", "level", 0.95, 0.5..=0.99);
+    let observations = use_state(Vec::<f64>::new);
+    let entry = use_state(String::new);
+    let error = use_state(|| None::<String>);
+    let parameter_ref = use_node_ref();
+    let predictive_ref = use_node_ref();
+
+    let prior = match *model {
+        Model::BetaBinomial => Prior::Beta {
+            alpha: *alpha,
+            beta: *beta,
+        },
+        Model::GammaPoisson => Prior::Gamma {
+            shape: *shape,
+            rate: *rate,
+        },
+        Model::NormalNormal => Prior::Normal {
+            mean: *mean,
+            std_dev: *std_dev,
+            sigma: *sigma,
+        },
+    };
+    let posterior = prior.update(&observations);
+
+    {
+        let parameter_ref = parameter_ref.clone();
+        let predictive_ref = predictive_ref.clone();
+        let level = *level;
+        let model = *model;
+        let drawn = (*observations).clone();
+        use_effect_with_deps(
+            move |_| {
+                for (canvas_ref, predictive) in [(parameter_ref, false), (predictive_ref, true)] {
+                    if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                        // Set the canvas size to match its parent container
+                        let parent = canvas.parent_element().unwrap();
+                        let width = parent.client_width();
+                        let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                        canvas.set_width(width as u32);
+                        canvas.set_height(height as u32);
+
+                        let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                        let root = backend.into_drawing_area();
+                        if predictive {
+                            draw_predictive(&root, model, prior, &drawn);
+                        } else {
+                            draw_parameter(&root, model, prior, &drawn, level);
+                        }
+                    }
+                }
+                || ()
+            },
+            (prior, (*observations).clone(), level),
+        );
+    }
+
+    let onchange_model = |option: Model| {
+        let model = model.clone();
+        let observations = observations.clone();
+        let error = error.clone();
+        Callback::from(move |_: Event| {
+            model.set(option);
+            // Observations of one data model mean nothing to another
+            observations.set(Vec::new());
+            error.set(None);
+        })
+    };
+
+    let oninput_number = |state: UseStateHandle<f64>, positive: bool| {
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                if let Ok(value) = parse_number(&input.value()) {
+                    if !positive || is_positive(&value) {
+                        state.set(value);
+                    }
+                }
+            }
+        })
+    };
+
+    let push = {
+        let observations = observations.clone();
+        move |observation: f64| {
+            let mut next = (*observations).clone();
+            next.push(observation);
+            observations.set(next);
+        }
+    };
+
+    let onclick_trial = |success: bool| {
+        let push = push.clone();
+        Callback::from(move |_: MouseEvent| push(if success { 1.0 } else { 0.0 }))
+    };
+
+    let onclick_add = {
+        let entry = entry.clone();
+        let error = error.clone();
+        let model = *model;
+        let push = push.clone();
+        Callback::from(move |_: MouseEvent| match model.observation(&entry) {
+            Ok(value) => {
+                push(value);
+                entry.set(String::new());
+                error.set(None);
+            }
+            Err(message) => error.set(Some(message)),
+        })
+    };
+
+    let oninput_entry = {
+        let entry = entry.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                entry.set(input.value());
+            }
+        })
+    };
+
+    let onclick_undo = {
+        let observations = observations.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*observations).clone();
+            next.pop();
+            observations.set(next);
+        })
+    };
+
+    let onclick_reset = {
+        let observations = observations.clone();
+        Callback::from(move |_: MouseEvent| observations.set(Vec::new()))
+    };
+
+    let render_parameter = {
+        let model = *model;
+        let level = *level;
+        let drawn = (*observations).clone();
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_parameter(root, model, prior, &drawn, level)
+            })
+        })
+    };
+
+    let render_predictive = {
+        let model = *model;
+        let drawn = (*observations).clone();
+        Callback::from(move |size| {
+            draw_svg(size, |root| draw_predictive(root, model, prior, &drawn))
+        })
+    };
+
+    let number_input = |label: &'static str, state: &UseStateHandle<f64>, positive: bool| {
+        html! {
+            <>
+                <label>{ format!(" {}: ", label) }</label>
+                <input type="number" step="any" value={state.to_string()}
+                oninput={oninput_number(state.clone(), positive)} style="width: 6em;" />
+            </>
+        }
+    };
+
+    let prior_inputs = match *model {
+        Model::BetaBinomial => html! {
+            <>{ number_input("α", &alpha, true) }{ number_input("β", &beta, true) }</>
+        },
+        Model::GammaPoisson => html! {
+            <>{ number_input("shape", &shape, true) }{ number_input("rate", &rate, true) }</>
+        },
+        Model::NormalNormal => html! {
+            <>
+                { number_input("μ₀", &mean, false) }
+                { number_input("τ₀", &std_dev, true) }
+                { number_input("known σ of the data", &sigma, true) }
+            </>
+        },
+    };
+
+    let n = observations.len();
+    let listed = observations
+        .iter()
+        .skip(n.saturating_sub(LISTED))
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let (lower, upper) = posterior.credible_interval(*level);
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h1>{ "Bayesian Updating" }</h1>
+            <div>
+                { for Model::ALL.into_iter().map(|option| html! {
+                    <label>
+                        <input type="radio" name="bayes-model" checked={*model == option}
+                        onchange={onchange_model(option)} />
+                        { format!(" {} ", option.label()) }
+                    </label>
+                }) }
+            </div>
+            <div>
+                <label>{ format!("Prior on {}:", model.parameter()) }</label>
+                { prior_inputs }
+            </div>
+            <div>
+                <label>{ "Observe: " }</label>
+                if *model == Model::BetaBinomial {
+                    <button onclick={onclick_trial(true)}>{ "Success" }</button>
+                    <button onclick={onclick_trial(false)}>{ "Failure" }</button>
+                } else {
+                    <input type="number" step="any" value={(*entry).clone()}
+                    oninput={oninput_entry} style="width: 6em;" />
+                    <button onclick={onclick_add}>{ "Add" }</button>
+                }
+                <button onclick={onclick_undo} disabled={n == 0}>{ "Undo" }</button>
+                <button onclick={onclick_reset} disabled={n == 0}>{ "Reset" }</button>
+                if let Some(message) = &*error {
+                    <span style="color: red;">{ format!(" {}", message) }</span>
+                }
+            </div>
+            <p>
+                { format!("{} observations", n) }
+                if n > 0 {
+                    { format!(": {}{}", if n > LISTED { "…, " } else { "" }, listed) }
+                }
+            </p>
+            <div>
+                <label>{ "Credible level: " }</label>
+                <input type="range" min="0.5" max="0.99" step="0.01" value={level.to_string()}
+                oninput={oninput_number(level.clone(), true)} style="width: 50%; " />
+                <span>{ *level }</span>
+            </div>
+            <p>
+                { format!(
+                    "Posterior {}: mean {}, {:.0}% credible interval [{:.4}, {:.4}]",
+                    describe(posterior),
+                    format_moment(posterior.dist().mean()),
+                    100.0 * *level,
+                    lower,
+                    upper,
+                ) }
+            </p>
+            <canvas ref={parameter_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="bayes-parameter" canvas_ref={parameter_ref} render_svg={render_parameter} />
+            <canvas ref={predictive_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="bayes-predictive" canvas_ref={predictive_ref} render_svg={render_predictive} />
+        </div>
+    }
+}
+
+/// Prior, likelihood and posterior over the parameter, with the credible
+/// interval shaded under the posterior.
+fn draw_parameter<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    model: Model,
+    prior: Prior,
+    observations: &[f64],
+    level: f64,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let posterior = prior.update(observations);
+    let likelihood = prior.likelihood(observations);
+    let mut curves: Vec<(String, Box<dyn Continuous>, RGBColor)> = vec![
+        (format!("Prior {}", describe(prior)), prior.dist(), BLUE),
+        (
+            format!("Posterior {}", describe(posterior)),
+            posterior.dist(),
+            RED,
+        ),
+    ];
+    if let Some(likelihood) = likelihood {
+        curves.push(("Likelihood (scaled)".to_string(), likelihood, GREEN));
+    }
+    let axes = curves
+        .iter()
+        .map(|(_, dist, _)| Axes::continuous(dist.as_ref(), false))
+        .reduce(|a, b| Axes {
+            x_min: a.x_min.min(b.x_min),
+            x_max: a.x_max.max(b.x_max),
+            y_max: a.y_max.max(b.y_max),
+        })
+        .unwrap();
+
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(
+            format!(
+                "{} after {} observations",
+                model.parameter(),
+                observations.len()
+            ),
+            ("sans-serif", 20),
+        )
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart
+        .configure_mesh()
+        .x_desc(model.parameter())
+        .draw()
+        .unwrap();
+
+    let (lower, upper) = posterior.credible_interval(level);
+    let (lower, upper) = (lower.max(axes.x_min), upper.min(axes.x_max));
+    let color = RED.mix(0.2);
+    let dist = posterior.dist();
+    chart
+        .draw_series(AreaSeries::new(
+            axes.points()
+                .filter(|&x| lower <= x && x <= upper)
+                .map(|x| (x, dist.pdf(x).min(axes.y_max))),
+            0.0,
+            color,
+        ))
+        .unwrap()
+        .label(format!("{:.0}% credible interval", 100.0 * level))
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], color.filled()));
+
+    for (label, dist, color) in curves {
+        chart
+            .draw_series(LineSeries::new(
+                axes.points().map(|x| (x, dist.pdf(x).min(axes.y_max))),
+                color.stroke_width(2),
+            ))
+            .unwrap()
+            .label(label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
+
+/// Prior and posterior predictive distributions of the next data.
+fn draw_predictive<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    model: Model,
+    prior: Prior,
+    observations: &[f64],
+) {
+    root.fill(&WHITE).unwrap();
+
+    let caption = match model {
+        Model::BetaBinomial => format!("Successes in the next {} trials", PREDICTIVE_TRIALS),
+        Model::GammaPoisson => "Next count".to_string(),
+        Model::NormalNormal => "Next observation".to_string(),
+    };
+    let series = [
+        ("Prior predictive", prior.predictive(), BLUE),
+        (
+            "Posterior predictive",
+            prior.update(observations).predictive(),
+            RED,
+        ),
+    ];
+
+    match &series {
+        [(_, Predictive::Discrete(first), _), (_, Predictive::Discrete(second), _)] => {
+            let (a, b) = (
+                Axes::discrete(first.as_ref(), false),
+                Axes::discrete(second.as_ref(), false),
+            );
+            let axes = Axes {
+                x_min: a.x_min.min(b.x_min),
+                x_max: a.x_max.max(b.x_max),
+                y_max: a.y_max.max(b.y_max),
+            };
+            let mut chart = ChartBuilder::on(root)
+                .margin(10)
+                .caption(caption, ("sans-serif", 20))
+                .x_label_area_size(30)
+                .y_label_area_size(40)
+                .build_cartesian_2d(axes.x_range(), axes.y_range())
+                .unwrap();
+
+            chart.configure_mesh().draw().unwrap();
+
+            // Translucent bars so the prior shows through the posterior
+            for (label, dist, color) in [(series[0].0, first, BLUE), (series[1].0, second, RED)] {
+                let style = color.mix(0.5).filled();
+                chart
+                    .draw_series(
+                        axes.points().map(|k| {
+                            Rectangle::new([(k, 0.0), (k + 1, dist.pmf(k as i64))], style)
+                        }),
+                    )
+                    .unwrap()
+                    .label(label)
+                    .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], style));
+            }
+
+            chart
+                .configure_series_labels()
+                .border_style(BLACK)
+                .draw()
+                .unwrap();
+        }
+        _ => {
+            let dists: Vec<(&str, &dyn Continuous, RGBColor)> = series
+                .iter()
+                .filter_map(|(label, predictive, color)| match predictive {
+                    Predictive::Continuous(dist) => Some((*label, dist.as_ref(), *color)),
+                    Predictive::Discrete(_) => None,
+                })
+                .collect();
+            let axes = dists
+                .iter()
+                .map(|(_, dist, _)| Axes::continuous(*dist, false))
+                .reduce(|a, b| Axes {
+                    x_min: a.x_min.min(b.x_min),
+                    x_max: a.x_max.max(b.x_max),
+                    y_max: a.y_max.max(b.y_max),
+                })
+                .unwrap();
+            let mut chart = ChartBuilder::on(root)
+                .margin(10)
+                .caption(caption, ("sans-serif", 20))
+                .x_label_area_size(30)
+                .y_label_area_size(40)
+                .build_cartesian_2d(axes.x_range(), axes.y_range())
+                .unwrap();
+
+            chart.configure_mesh().draw().unwrap();
+
+            for (label, dist, color) in dists {
+                chart
+                    .draw_series(LineSeries::new(
+                        axes.points().map(|x| (x, dist.pdf(x))),
+                        color.stroke_width(2),
+                    ))
+                    .unwrap()
+                    .label(label)
+                    .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+            }
+
+            chart
+                .configure_series_labels()
+                .border_style(BLACK)
+                .draw()
+                .unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_observations_and_charts() {
+        assert_eq!(Model::GammaPoisson.observation(" 3 "), Ok(3.0));
+        assert!(Model::GammaPoisson.observation("2.5").is_err());
+        assert!(Model::GammaPoisson.observation("-1").is_err());
+        assert_eq!(Model::NormalNormal.observation("-2.5"), Ok(-2.5));
+        for text in ["NaN", "inf", "-inf"] {
+            assert!(Model::NormalNormal.observation(text).is_err());
+            assert!(Model::GammaPoisson.observation(text).is_err());
+        }
+        assert_eq!(Model::BetaBinomial.observation("1"), Ok(1.0));
+        assert!(Model::BetaBinomial.observation("2").is_err());
+        assert!(Model::BetaBinomial.observation("0.5").is_err());
+
+        for (model, prior, observations) in [
+            (
+                Model::BetaBinomial,
+                Prior::Beta {
+                    alpha: 0.5,
+                    beta: 0.5,
+                },
+                vec![1.0, 0.0, 1.0],
+            ),
+            (
+                Model::GammaPoisson,
+                Prior::Gamma {
+                    shape: 2.0,
+                    rate: 1.0,
+                },
+                vec![],
+            ),
+            (
+                Model::NormalNormal,
+                Prior::Normal {
+                    mean: 0.0,
+                    std_dev: 2.0,
+                    sigma: 1.0,
+                },
+                vec![1.5, 0.5],
+            ),
+        ] {
+            assert_eq!(Model::from_query(&model.to_query()), Some(model));
+            let svg = draw_svg((400, 300), |root| {
+                draw_parameter(root, model, prior, &observations, 0.9)
+            });
+            assert!(svg.contains("90% credible interval"));
+            let svg = draw_svg((400, 300), |root| {
+                draw_predictive(root, model, prior, &observations)
+            });
+            assert!(svg.contains("Posterior predictive"));
+        }
+    }
+}
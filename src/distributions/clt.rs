@@ -0,0 +1,418 @@
+//! Central limit theorem simulator.
+//!
+//! Draws many samples of size n from a source distribution and compares the
+//! histogram of their means with Normal(μ, σ/√n). Everything is regenerated
+//! from the seed, so the picture follows the controls reproducibly.
+
+use crate::distributions::export::{draw_svg, ExportButtons};
+use crate::distributions::moments::format_moment;
+use crate::distributions::query::{use_query_state, use_query_state_in, QueryValue};
+use crate::stats::continuous::{Beta, Cauchy, Exponential, LogNormal, Normal, Uniform};
+use crate::stats::descriptive::{histogram, mean, variance};
+use crate::stats::discrete::{Bernoulli, Geometric, Poisson};
+use crate::stats::{Continuous, Distribution, Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use std::ops::RangeInclusive;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+const HISTOGRAM_BINS: usize = 50;
+const CURVE_POINTS: usize = 500;
+/// Half-width of the chart in predicted standard errors.
+const STANDARD_ERRORS: f64 = 4.0;
+/// Slider ranges, which also bound the values read from the query.
+const SAMPLE_SIZES: RangeInclusive<usize> = 1..=200;
+const REPLICATIONS: RangeInclusive<usize> = 100..=10000;
+
+/// Distribution the samples are drawn from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Source {
+    Exponential,
+    Uniform,
+    Beta,
+    LogNormal,
+    Cauchy,
+    Bernoulli,
+    Poisson,
+    Geometric,
+}
+
+impl Source {
+    const ALL: [Source; 8] = [
+        Self::Exponential,
+        Self::Uniform,
+        Self::Beta,
+        Self::LogNormal,
+        Self::Cauchy,
+        Self::Bernoulli,
+        Self::Poisson,
+        Self::Geometric,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Exponential => "Exponential(λ = 1)",
+            Self::Uniform => "Uniform(0, 1)",
+            Self::Beta => "Beta(0.5, 0.5)",
+            Self::LogNormal => "Log-normal(0, 1)",
+            Self::Cauchy => "Cauchy(0, 1)",
+            Self::Bernoulli => "Bernoulli(0.1)",
+            Self::Poisson => "Poisson(3)",
+            Self::Geometric => "Geometric(0.3)",
+        }
+    }
+
+    fn slug(self) -> &'static str {
+        match self {
+            Self::Exponential => "exponential",
+            Self::Uniform => "uniform",
+            Self::Beta => "beta",
+            Self::LogNormal => "log-normal",
+            Self::Cauchy => "cauchy",
+            Self::Bernoulli => "bernoulli",
+            Self::Poisson => "poisson",
+            Self::Geometric => "geometric",
+        }
+    }
+
+    /// Calls `f` with the source distribution and a sampler returning its
+    /// draws as `f64`.
+    fn with_population<R>(
+        self,
+        f: impl FnOnce(&dyn Distribution, &dyn Fn(&mut Rng) -> f64) -> R,
+    ) -> R {
+        match self {
+            Self::Exponential => {
+                let dist = Exponential::new(1.0);
+                f(&dist, &|rng| dist.draw(rng))
+            }
+            Self::Uniform => {
+                let dist = Uniform::new(0.0, 1.0);
+                f(&dist, &|rng| dist.draw(rng))
+            }
+            Self::Beta => {
+                let dist = Beta::new(0.5, 0.5);
+                f(&dist, &|rng| dist.draw(rng))
+            }
+            Self::LogNormal => {
+                let dist = LogNormal::new(0.0, 1.0);
+                f(&dist, &|rng| dist.draw(rng))
+            }
+            Self::Cauchy => {
+                let dist = Cauchy::new(0.0, 1.0);
+                f(&dist, &|rng| dist.draw(rng))
+            }
+            Self::Bernoulli => {
+                let dist = Bernoulli::new(0.1);
+                f(&dist, &|rng| dist.draw(rng) as f64)
+            }
+            Self::Poisson => {
+                let dist = Poisson::new(3.0);
+                f(&dist, &|rng| dist.draw(rng) as f64)
+            }
+            Self::Geometric => {
+                let dist = Geometric::new(0.3);
+                f(&dist, &|rng| dist.draw(rng) as f64)
+            }
+        }
+    }
+
+    /// Mean and variance of the source.
+    fn moments(self) -> (f64, f64) {
+        self.with_population(|dist, _| (dist.mean(), dist.variance()))
+    }
+
+    /// Means of `replications` samples of size `n`.
+    fn sample_means(self, n: usize, replications: usize, seed: u64) -> Vec<f64> {
+        self.with_population(|_, draw| {
+            let mut rng = Rng::new(seed);
+            (0..replications)
+                .map(|_| (0..n).map(|_| draw(&mut rng)).sum::<f64>() / n as f64)
+                .collect()
+        })
+    }
+}
+
+impl QueryValue for Source {
+    fn to_query(&self) -> String {
+        self.slug().to_string()
+    }
+
+    fn from_query(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|source| source.slug() == value)
+    }
+}
+
+/// Normal(μ, σ/√n) predicted by the CLT, if the source has a finite variance.
+fn predicted(source: Source, n: usize) -> Option<Normal> {
+    let (mean, variance) = source.moments();
+    (mean.is_finite() && variance.is_finite())
+        .then(|| Normal::new(mean, (variance / n as f64).sqrt()))
+}
+
+/// The value below which a share `p` of the sorted, non-empty `data` lies.
+fn sample_quantile(sorted: &[f64], p: f64) -> f64 {
+    sorted[((sorted.len() - 1) as f64 * p).round() as usize]
+}
+
+#[function_component(CentralLimitTheorem)]
+pub fn central_limit_theorem() -> Html {
+    let source = use_query_state("clt", "source", Source::Exponential);
+    let n = use_query_state_in("clt", "n", 5_usize, SAMPLE_SIZES);
+    let replications = use_query_state_in("clt", "replications", 2000_usize, REPLICATIONS);
+    let seed = use_query_state("clt", "seed", 1_u64);
+    let canvas_ref = use_node_ref();
+
+    let means = use_memo(
+        |&(source, n, replications, seed)| source.sample_means(n, replications, seed),
+        (*source, *n, *replications, *seed),
+    );
+
+    {
+        let source = *source;
+        let n = *n;
+        let drawn = means.clone();
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(&backend.into_drawing_area(), source, n, &drawn);
+                }
+                || ()
+            },
+            means.clone(),
+        );
+    }
+
+    let onchange_source = |option: Source| {
+        let source = source.clone();
+        Callback::from(move |_: Event| source.set(option))
+    };
+
+    let oninput_n = {
+        let n = n.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<usize>() {
+                    n.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_replications = {
+        let replications = replications.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<usize>() {
+                    replications.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_seed = {
+        let seed = seed.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<u64>() {
+                    seed.set(value);
+                }
+            }
+        })
+    };
+
+    let render_svg = {
+        let source = *source;
+        let n = *n;
+        let means = means.clone();
+        Callback::from(move |size| draw_svg(size, |root| draw_chart(root, source, n, &means)))
+    };
+
+    let (source_mean, source_variance) = source.moments();
+    let standard_error = (source_variance / *n as f64).sqrt();
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h1>{ "Central Limit Theorem" }</h1>
+            <p>
+                { "The mean of n independent draws from a distribution with mean μ and finite variance σ² is approximately Normal(μ, σ/√n) for large n, whatever the shape of the distribution." }
+            </p>
+            <div>
+                <label>{ "Source: " }</label>
+                { for Source::ALL.into_iter().map(|option| html! {
+                    <label>
+                        <input type="radio" name="clt-source" checked={*source == option}
+                        onchange={onchange_source(option)} />
+                        { format!(" {} ", option.label()) }
+                    </label>
+                }) }
+            </div>
+            <div>
+                <label>{ "Sample size n: " }</label>
+                <input type="range" min={SAMPLE_SIZES.start().to_string()} max={SAMPLE_SIZES.end().to_string()}
+                step="1" value={(*n).to_string()}
+                oninput={oninput_n} style="width: 70%; " />
+                <span>{ *n }</span>
+            </div>
+            <div>
+                <label>{ "Replications: " }</label>
+                <input type="range" min={REPLICATIONS.start().to_string()} max={REPLICATIONS.end().to_string()}
+                step="100" value={(*replications).to_string()}
+                oninput={oninput_replications} style="width: 70%; " />
+                <span>{ *replications }</span>
+            </div>
+            <div>
+                <label>{ "Seed: " }</label>
+                <input type="number" min="0" value={(*seed).to_string()}
+                oninput={oninput_seed} style="width: 6em;" />
+            </div>
+            <p>
+                { format!("Predicted: μ = {}, σ/√n = {}", format_moment(source_mean), format_moment(standard_error)) }
+                { " | " }
+                { format!("Observed: mean = {:.4}, sd = {:.4}", mean(&means), variance(&means).sqrt()) }
+            </p>
+            if source_variance.is_nan() {
+                <p>{ "The Cauchy distribution has no mean, so its sample means never settle: they are Cauchy(0, 1) again for every n." }</p>
+            }
+            <canvas ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="clt" {canvas_ref} {render_svg} />
+        </div>
+    }
+}
+
+/// Density histogram of the sample means under the predicted normal curve.
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    source: Source,
+    n: usize,
+    means: &[f64],
+) {
+    root.fill(&WHITE).unwrap();
+
+    let normal = predicted(source, n);
+    let (x_min, x_max) = match &normal {
+        Some(normal) => (
+            normal.mean() - STANDARD_ERRORS * normal.std_dev(),
+            normal.mean() + STANDARD_ERRORS * normal.std_dev(),
+        ),
+        // Without a variance only the observed spread can frame the chart
+        None => {
+            let mut sorted = means.to_vec();
+            sorted.sort_by(f64::total_cmp);
+            (
+                sample_quantile(&sorted, 0.01),
+                sample_quantile(&sorted, 0.99),
+            )
+        }
+    };
+    let bins = histogram(means, x_min, x_max, HISTOGRAM_BINS);
+    let curve: Vec<(f64, f64)> = match &normal {
+        Some(normal) => (0..=CURVE_POINTS)
+            .map(|i| {
+                let x = x_min + (x_max - x_min) * i as f64 / CURVE_POINTS as f64;
+                (x, normal.pdf(x))
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    let y_max = bins
+        .iter()
+        .map(|bin| bin.density)
+        .chain(curve.iter().map(|&(_, y)| y))
+        .fold(0.0, f64::max)
+        * 1.1;
+
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(
+            format!("Means of {} samples of size {}", means.len(), n),
+            ("sans-serif", 20),
+        )
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(x_min..x_max, 0.0..y_max.max(f64::EPSILON))
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    let color = BLACK.mix(0.3);
+    chart
+        .draw_series(bins.iter().map(|bin| {
+            Rectangle::new([(bin.lower, 0.0), (bin.upper, bin.density)], color.filled())
+        }))
+        .unwrap()
+        .label("Sample means")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], color.filled()));
+
+    if let Some(normal) = normal {
+        chart
+            .draw_series(LineSeries::new(curve, RED.stroke_width(2)))
+            .unwrap()
+            .label(format!(
+                "Normal(μ = {:.3}, σ/√n = {:.3})",
+                normal.mean(),
+                normal.std_dev()
+            ))
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+    }
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_means_match_prediction() {
+        for source in Source::ALL {
+            let means = source.sample_means(30, 4000, 7);
+            assert_eq!(means.len(), 4000);
+            let Some(normal) = predicted(source, 30) else {
+                assert_eq!(source, Source::Cauchy);
+                continue;
+            };
+            let standard_error = normal.std_dev() / (4000.0_f64).sqrt();
+            assert!(
+                (mean(&means) - normal.mean()).abs() < 5.0 * standard_error,
+                "{:?}",
+                source
+            );
+            let sd = variance(&means).sqrt();
+            assert!((sd / normal.std_dev() - 1.0).abs() < 0.1, "{:?}", source);
+        }
+    }
+
+    #[test]
+    fn test_smallest_simulation_draws() {
+        let (n, replications) = (*SAMPLE_SIZES.start(), *REPLICATIONS.start());
+        for source in Source::ALL {
+            let means = source.sample_means(n, replications, 1);
+            assert!(means.iter().all(|x| x.is_finite()), "{:?}", source);
+            let svg = draw_svg((400, 300), |root| draw_chart(root, source, n, &means));
+            assert!(svg.contains("<svg"), "{:?}", source);
+        }
+    }
+
+    #[test]
+    fn test_source_round_trips_through_query() {
+        for source in Source::ALL {
+            assert_eq!(Source::from_query(&source.to_query()), Some(source));
+        }
+    }
+}
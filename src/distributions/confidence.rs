@@ -0,0 +1,558 @@
+//! Confidence interval visualizer.
+//!
+//! Repeatedly samples from a source distribution, builds an interval from each
+//! sample and stacks them against the true parameter. The share that covers it
+//! is the empirical coverage, to compare with the nominal confidence level.
+
+use crate::distributions::export::{draw_svg, ExportButtons};
+use crate::distributions::fit::FamilyPicker;
+use crate::distributions::input::use_debounced;
+use crate::distributions::query::{
+    use_query_state, use_query_state_if, use_query_state_in, QueryValue,
+};
+use crate::stats::confidence::{
+    bootstrap_interval, clopper_pearson_interval, t_interval, wald_interval, wilson_interval,
+    z_interval, ConfidenceInterval,
+};
+use crate::stats::discrete::Bernoulli;
+use crate::stats::fit::Family;
+use crate::stats::{Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+/// Intervals drawn on the chart; coverage counts all replications.
+const SHOWN: usize = 100;
+const BOOTSTRAP_RESAMPLES: usize = 500;
+/// Most values the bootstrap draws over all replications and resamples, a
+/// fraction of a second of work.
+const BOOTSTRAP_DRAWS: usize = 10_000_000;
+
+/// How each interval is built from its sample.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Method {
+    Z,
+    T,
+    Bootstrap,
+    Wald,
+    Wilson,
+    ClopperPearson,
+}
+
+impl Method {
+    const ALL: [Method; 6] = [
+        Self::Z,
+        Self::T,
+        Self::Bootstrap,
+        Self::Wald,
+        Self::Wilson,
+        Self::ClopperPearson,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Z => "z (known σ)",
+            Self::T => "t",
+            Self::Bootstrap => "Percentile bootstrap",
+            Self::Wald => "Wald",
+            Self::Wilson => "Wilson",
+            Self::ClopperPearson => "Clopper–Pearson",
+        }
+    }
+
+    fn slug(self) -> &'static str {
+        match self {
+            Self::Z => "z",
+            Self::T => "t",
+            Self::Bootstrap => "bootstrap",
+            Self::Wald => "wald",
+            Self::Wilson => "wilson",
+            Self::ClopperPearson => "clopper-pearson",
+        }
+    }
+
+    fn is_proportion(self) -> bool {
+        matches!(self, Self::Wald | Self::Wilson | Self::ClopperPearson)
+    }
+}
+
+impl QueryValue for Method {
+    fn to_query(&self) -> String {
+        self.slug().to_string()
+    }
+
+    fn from_query(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|method| method.slug() == value)
+    }
+}
+
+/// Mean and standard deviation of `family` with `parameters`.
+fn moments(family: Family, parameters: &[f64]) -> (f64, f64) {
+    match family.continuous(parameters) {
+        Some(dist) => (dist.mean(), dist.std_dev()),
+        None => {
+            let dist = family.discrete(parameters).unwrap();
+            (dist.mean(), dist.std_dev())
+        }
+    }
+}
+
+/// Families with a mean for the intervals to cover.
+fn mean_families() -> impl Iterator<Item = Family> {
+    Family::FITTED
+        .into_iter()
+        .filter(|family| moments(*family, &family.default_parameters()).0.is_finite())
+}
+
+/// Everything the simulated intervals depend on.
+#[derive(Clone, Debug, PartialEq)]
+struct Experiment {
+    method: Method,
+    /// Population of the mean intervals.
+    family: Family,
+    parameters: Vec<f64>,
+    /// Success probability of the proportion intervals.
+    p: f64,
+    n: usize,
+    level: f64,
+    replications: usize,
+    seed: u64,
+}
+
+impl Experiment {
+    /// The mean of the source or the success probability.
+    fn parameter(&self) -> f64 {
+        if self.method.is_proportion() {
+            self.p
+        } else {
+            moments(self.family, &self.parameters).0
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        if self.method.is_proportion() {
+            "p"
+        } else {
+            "μ"
+        }
+    }
+
+    /// Replications actually run. Each bootstrap interval resamples its
+    /// sample [`BOOTSTRAP_RESAMPLES`] times, so the bootstrap runs only as many
+    /// as [`BOOTSTRAP_DRAWS`] allows, though never fewer than are shown.
+    fn replications_run(&self) -> usize {
+        if self.method == Method::Bootstrap {
+            let affordable = BOOTSTRAP_DRAWS / (BOOTSTRAP_RESAMPLES * self.n);
+            self.replications.min(affordable.max(SHOWN))
+        } else {
+            self.replications
+        }
+    }
+
+    fn intervals(&self) -> Vec<ConfidenceInterval> {
+        let mut rng = Rng::new(self.seed);
+        if self.method.is_proportion() {
+            let dist = Bernoulli::new(self.p);
+            let n = self.n as u64;
+            return (0..self.replications)
+                .map(|_| {
+                    let successes = (0..n).map(|_| dist.draw(&mut rng) as u64).sum();
+                    match self.method {
+                        Method::Wald => wald_interval(successes, n, self.level),
+                        Method::Wilson => wilson_interval(successes, n, self.level),
+                        _ => clopper_pearson_interval(successes, n, self.level),
+                    }
+                })
+                .collect();
+        }
+        let (_, std_dev) = moments(self.family, &self.parameters);
+        (0..self.replications_run())
+            .map(|_| {
+                let data = self.family.sample(&self.parameters, &mut rng, self.n);
+                match self.method {
+                    Method::Z => z_interval(&data, std_dev, self.level),
+                    Method::T => t_interval(&data, self.level),
+                    _ => bootstrap_interval(&data, self.level, BOOTSTRAP_RESAMPLES, &mut rng),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Share of the intervals that cover `parameter`.
+fn coverage(intervals: &[ConfidenceInterval], parameter: f64) -> f64 {
+    let covering = intervals
+        .iter()
+        .filter(|interval| interval.covers(parameter))
+        .count();
+    covering as f64 / intervals.len() as f64
+}
+
+#[function_component(ConfidenceIntervals)]
+pub fn confidence_intervals() -> Html {
+    let method = use_query_state("ci", "method", Method::T);
+    let family = use_query_state_if("ci", "family", Family::Exponential, |family| {
+        mean_families().any(|option| option == *family)
+    });
+    let parameters = {
+        let family = *family;
+        use_query_state_if(
+            "ci",
+            "parameters",
+            family.default_parameters(),
+            move |parameters| family.accepts(parameters),
+        )
+    };
+    let p = use_query_state_in("ci", "p", 0.1, 0.01..=0.99);
+    let n = use_query_state_in("ci", "n", 20_usize, 2..=200);
+    let level = use_query_state_in("ci", "level", 0.95, 0.5..=0.99);
+    let replications = use_query_state_in("ci", "replications", 1000_usize, 100..=5000);
+    let seed = use_query_state("ci", "seed", 1_u64);
+    let canvas_ref = use_node_ref();
+
+    let experiment = Experiment {
+        method: *method,
+        family: *family,
+        parameters: (*parameters).clone(),
+        p: *p,
+        n: *n,
+        level: *level,
+        replications: *replications,
+        seed: *seed,
+    };
+    // Simulated once the sliders settle, not on every step
+    let simulated = use_debounced(experiment);
+    let intervals = use_memo(|experiment| experiment.intervals(), simulated.clone());
+
+    {
+        let experiment = simulated.clone();
+        let drawn = intervals.clone();
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(&backend.into_drawing_area(), &experiment, &drawn);
+                }
+                || ()
+            },
+            intervals.clone(),
+        );
+    }
+
+    let onchange_method = |option: Method| {
+        let method = method.clone();
+        Callback::from(move |_: Event| method.set(option))
+    };
+
+    let oninput_p = {
+        let p = p.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    p.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_n = {
+        let n = n.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<usize>() {
+                    n.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_level = {
+        let level = level.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    level.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_replications = {
+        let replications = replications.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<usize>() {
+                    replications.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_seed = {
+        let seed = seed.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<u64>() {
+                    seed.set(value);
+                }
+            }
+        })
+    };
+
+    let render_svg = {
+        let experiment = simulated.clone();
+        let intervals = intervals.clone();
+        Callback::from(move |size| draw_svg(size, |root| draw_chart(root, &experiment, &intervals)))
+    };
+
+    let method_radio = |option: Method| {
+        html! {
+            <label>
+                <input type="radio" name="ci-method" checked={*method == option}
+                onchange={onchange_method(option)} />
+                { format!(" {} ", option.label()) }
+            </label>
+        }
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h1>{ "Confidence Intervals" }</h1>
+            <p>
+                { "A 95% confidence interval procedure covers the true parameter in 95% of repeated samples. Any single interval either covers it or not." }
+            </p>
+            <div>
+                <label>{ "Mean: " }</label>
+                { for Method::ALL.into_iter().filter(|option| !option.is_proportion()).map(method_radio) }
+            </div>
+            <div>
+                <label>{ "Proportion: " }</label>
+                { for Method::ALL.into_iter().filter(|option| option.is_proportion()).map(method_radio) }
+            </div>
+            if method.is_proportion() {
+                <div>
+                    <label>{ "Success probability p: " }</label>
+                    <input type="range" min="0.01" max="0.99" step="0.01" value={p.to_string()}
+                    oninput={oninput_p} style="width: 70%; " />
+                    <span>{ *p }</span>
+                </div>
+            } else {
+                <div>
+                    <label>{ "Population: " }</label>
+                    <FamilyPicker name="ci-family" families={mean_families().collect::<Vec<_>>()}
+                    family={family.clone()} parameters={parameters.clone()} />
+                </div>
+            }
+            <div>
+                <label>{ "Sample size n: " }</label>
+                <input type="range" min="2" max="200" step="1" value={(*n).to_string()}
+                oninput={oninput_n} style="width: 70%; " />
+                <span>{ *n }</span>
+            </div>
+            <div>
+                <label>{ "Confidence level: " }</label>
+                <input type="range" min="0.5" max="0.99" step="0.01" value={level.to_string()}
+                oninput={oninput_level} style="width: 70%; " />
+                <span>{ *level }</span>
+            </div>
+            <div>
+                <label>{ "Replications: " }</label>
+                <input type="range" min="100" max="5000" step="100" value={(*replications).to_string()}
+                oninput={oninput_replications} style="width: 70%; " />
+                <span>{ *replications }</span>
+            </div>
+            <div>
+                <label>{ "Seed: " }</label>
+                <input type="number" min="0" value={(*seed).to_string()}
+                oninput={oninput_seed} style="width: 6em;" />
+            </div>
+            <p>
+                { format!(
+                    "Empirical coverage: {:.1}% of {} intervals cover {} = {:.4}, nominal {:.0}%",
+                    100.0 * coverage(&intervals, simulated.parameter()),
+                    intervals.len(),
+                    simulated.symbol(),
+                    simulated.parameter(),
+                    100.0 * simulated.level,
+                ) }
+            </p>
+            if simulated.replications_run() < simulated.replications {
+                <p>
+                    { format!(
+                        "The bootstrap resamples every sample {} times, so only {} replications are run at n = {}.",
+                        BOOTSTRAP_RESAMPLES,
+                        simulated.replications_run(),
+                        simulated.n,
+                    ) }
+                </p>
+            }
+            <canvas ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="confidence-intervals" {canvas_ref} {render_svg} />
+        </div>
+    }
+}
+
+/// The first intervals stacked bottom to top, coloured by whether they cover
+/// the parameter.
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    experiment: &Experiment,
+    intervals: &[ConfidenceInterval],
+) {
+    root.fill(&WHITE).unwrap();
+
+    let parameter = experiment.parameter();
+    let shown = &intervals[..intervals.len().min(SHOWN)];
+    let (x_min, x_max) = shown
+        .iter()
+        .fold((parameter, parameter), |(lower, upper), interval| {
+            (lower.min(interval.lower), upper.max(interval.upper))
+        });
+    let padding = 0.05 * (x_max - x_min).max(f64::EPSILON);
+
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(
+            format!(
+                "{} of {} {:.0}% {} intervals cover {}",
+                shown
+                    .iter()
+                    .filter(|interval| interval.covers(parameter))
+                    .count(),
+                shown.len(),
+                100.0 * experiment.level,
+                experiment.method.label(),
+                experiment.symbol(),
+            ),
+            ("sans-serif", 20),
+        )
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(
+            (x_min - padding)..(x_max + padding),
+            0.0..shown.len() as f64,
+        )
+        .unwrap();
+
+    chart.configure_mesh().y_desc("Replication").draw().unwrap();
+
+    for (covers, color, label) in [(true, BLUE, "Covers"), (false, RED, "Misses")] {
+        chart
+            .draw_series(
+                shown
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, interval)| interval.covers(parameter) == covers)
+                    .map(|(i, interval)| {
+                        let y = i as f64 + 0.5;
+                        PathElement::new(
+                            vec![(interval.lower, y), (interval.upper, y)],
+                            color.stroke_width(2),
+                        )
+                    }),
+            )
+            .unwrap()
+            .label(label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            [(parameter, 0.0), (parameter, shown.len() as f64)],
+            BLACK.stroke_width(2),
+        ))
+        .unwrap()
+        .label(format!("{} = {:.4}", experiment.symbol(), parameter))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK));
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coverage_is_near_nominal() {
+        let mut experiment = Experiment {
+            method: Method::T,
+            family: Family::Beta,
+            parameters: vec![2.0, 2.0],
+            p: 0.3,
+            n: 30,
+            level: 0.9,
+            replications: 2000,
+            seed: 5,
+        };
+        for method in [Method::Z, Method::T, Method::Wilson, Method::ClopperPearson] {
+            experiment.method = method;
+            let rate = coverage(&experiment.intervals(), experiment.parameter());
+            assert!((rate - 0.9).abs() < 0.03, "{:?} covers {}", method, rate);
+        }
+
+        // Wald intervals collapse when no success is seen, so they undercover
+        // a small p
+        experiment.p = 0.02;
+        experiment.method = Method::Wald;
+        let wald = coverage(&experiment.intervals(), experiment.parameter());
+        experiment.method = Method::ClopperPearson;
+        let exact = coverage(&experiment.intervals(), experiment.parameter());
+        assert!(wald < 0.7 && exact >= 0.9, "{} {}", wald, exact);
+    }
+
+    #[test]
+    fn test_bootstrap_work_is_bounded() {
+        let mut experiment = Experiment {
+            method: Method::Bootstrap,
+            family: Family::Exponential,
+            parameters: vec![1.0],
+            p: 0.5,
+            n: 200,
+            level: 0.95,
+            replications: 5000,
+            seed: 1,
+        };
+        for n in [2, 20, 200] {
+            experiment.n = n;
+            let run = experiment.replications_run();
+            assert!(
+                run * BOOTSTRAP_RESAMPLES * n <= BOOTSTRAP_DRAWS,
+                "n = {}",
+                n
+            );
+            assert!((SHOWN..=experiment.replications).contains(&run));
+        }
+        experiment.n = 10;
+        experiment.replications = 300;
+        assert_eq!(experiment.replications_run(), 300);
+
+        // Only the bootstrap is cut
+        experiment.method = Method::T;
+        experiment.n = 200;
+        experiment.replications = 5000;
+        assert_eq!(experiment.replications_run(), 5000);
+    }
+
+    #[test]
+    fn test_method_round_trips_through_query() {
+        for method in Method::ALL {
+            assert_eq!(Method::from_query(&method.to_query()), Some(method));
+        }
+        assert!(mean_families().all(|family| family != Family::Cauchy));
+        assert!(mean_families().any(|family| family == Family::NegativeBinomial));
+    }
+}
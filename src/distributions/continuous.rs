@@ -1,19 +1,35 @@
 pub mod beta;
 pub mod bivariate_normal;
+pub mod cauchy;
 pub mod chi_squared;
 pub mod exponential;
 pub mod f;
 pub mod gamma;
+pub mod gumbel;
+pub mod laplace;
 pub mod log_normal;
+pub mod logistic;
+pub mod multivariate_normal;
+pub mod normal;
+pub mod pareto;
 pub mod student;
+pub mod uniform;
 pub mod weibull;
 
 pub use beta::BetaDistribution;
 pub use bivariate_normal::BivariateNormalDistribution;
+pub use cauchy::CauchyDistribution;
 pub use chi_squared::ChiSquaredDistribution;
 pub use exponential::ExponentialDistribution;
 pub use f::FDistribution;
 pub use gamma::GammaDistribution;
+pub use gumbel::GumbelDistribution;
+pub use laplace::LaplaceDistribution;
 pub use log_normal::LogNormalDistribution;
+pub use logistic::LogisticDistribution;
+pub use multivariate_normal::MultivariateNormalDistribution;
+pub use normal::NormalDistribution;
+pub use pareto::ParetoDistribution;
 pub use student::TDistribution;
+pub use uniform::UniformDistribution;
 pub use weibull::WeibullDistribution;
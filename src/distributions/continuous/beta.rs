@@ -1,3 +1,16 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_continuous, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_continuous_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    draw_histogram, draw_labelled_histogram, sample_caption, Simulation, SimulationControls,
+};
+use crate::stats::continuous::Beta;
+use crate::stats::{Continuous, Rng, Sample};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
 use web_sys::HtmlCanvasElement;
@@ -5,13 +18,25 @@ use yew::prelude::*;
 
 #[function_component(BetaDistribution)]
 pub fn beta_distribution() -> Html {
-    let alpha = use_state(|| 1.0);
-    let beta = use_state(|| 1.0);
+    let alpha = use_query_state_in("beta", "alpha", 1.0, 0.01..=10.0);
+    let beta = use_query_state_in("beta", "beta", 1.0, 0.01..=10.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<f64>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<f64>>);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref = use_node_ref();
 
+    let axes =
+        locked_axes.unwrap_or_else(|| Axes::continuous(&Beta::new(*alpha, *beta), *show_cdf));
+
     {
         let alpha: f64 = *alpha;
         let beta: f64 = *beta;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
         let canvas_ref = canvas_ref.clone();
         use_effect_with_deps(
             move |_| {
@@ -24,53 +49,29 @@ pub fn beta_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let caption = format!(
-                        "Mean: {:.2}, Variance: {:.2}",
-                        alpha / (alpha + beta),
-                        (alpha * beta) / ((alpha + beta).powi(2) * (alpha + beta + 1.0))
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        alpha,
+                        beta,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                        None,
                     );
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", 20))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(0.0..1.0, 0.0..2.0)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    let beta_pdf = |x: f64, alpha: f64, beta: f64| -> f64 {
-                        if !(0.0..1.0).contains(&x) {
-                            0.0
-                        } else {
-                            (x.powf(alpha - 1.0) * (1.0 - x).powf(beta - 1.0))
-                                / (gamma(alpha) * gamma(beta) / gamma(alpha + beta))
-                        }
-                    };
-
-                    chart
-                        .draw_series(LineSeries::new(
-                            (0..1000)
-                                .map(|x| x as f64 / 1000.0)
-                                .map(|x| (x, beta_pdf(x, alpha, beta))),
-                            BLUE,
-                        ))
-                        .unwrap()
-                        .label(format!("α = {}, β = {}", alpha, beta))
-                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
-
-                    chart
-                        .configure_series_labels()
-                        .border_style(BLACK)
-                        .draw()
-                        .unwrap();
                 }
                 || ()
             },
-            (alpha, beta),
+            (
+                axes,
+                alpha,
+                beta,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
         );
     }
 
@@ -96,6 +97,39 @@ pub fn beta_distribution() -> Html {
         })
     };
 
+    let probability = interval.map(|interval| interval.probability(&Beta::new(*alpha, *beta)));
+
+    let render_svg = {
+        let alpha = *alpha;
+        let beta = *beta;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root, axes, alpha, beta, show_cdf, simulation, &pinned, interval, None,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let alpha = *alpha;
+        let beta = *beta;
+        Callback::from(move |_| {
+            let dist = Beta::new(alpha, beta);
+            Grid::continuous(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let alpha = *alpha;
+        let beta = *beta;
+        format!("Beta: α = {}, β = {}", alpha, beta)
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto;">
             <h2>{ "Beta Distribution" }</h2>
@@ -115,12 +149,80 @@ pub fn beta_distribution() -> Html {
             </div>
             <p> {"Mean: α / (α + β), Variance: (αβ) / ((α + β)²(α + β + 1))"} </p>
             <p> {"Beta Function: B(α, β) = Γ(α)Γ(β) / Γ(α + β)"} </p>
-            <canvas id="plot" ref={canvas_ref} style="width: 100%; height: auto;"></canvas>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<f64> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<f64> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="beta" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::continuous(Beta::new(*alpha, *beta)) />
         </div>
     }
 }
 
-// Helper function to compute the gamma function
-fn gamma(x: f64) -> f64 {
-    libm::tgamma(x)
+/// Density or CDF chart, also drawn by the fitting page over the histogram
+/// of its `data`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<f64>,
+    alpha: f64,
+    beta: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<f64>>,
+    data: Option<&[f64]>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Beta::new(alpha, beta);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    if let Some(draws) = &draws {
+        draw_histogram(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(data) = data {
+        let label = format!("Data (n = {})", data.len());
+        draw_labelled_histogram(&mut chart, data, show_cdf, label);
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            axes.points()
+                .map(|x| (x, if show_cdf { dist.cdf(x) } else { dist.pdf(x) })),
+            BLUE,
+        ))
+        .unwrap()
+        .label(format!("α = {}, β = {}", alpha, beta))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+    if let Some(interval) = interval {
+        shade_continuous(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_continuous_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
 }
@@ -1,28 +1,167 @@
+pub mod surface;
+
+use crate::distributions::controls::CdfToggle;
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::input::parse_numbers;
+use crate::distributions::query::{use_query_state_if, use_query_state_in};
+use crate::distributions::simulation::{Simulation, SimulationControls};
+use crate::stats::continuous::{BivariateNormal, ChiSquared, Normal};
+use crate::stats::descriptive::{correlation as sample_correlation, mean};
+use crate::stats::{Continuous, Distribution, Rng, Sample};
+use gloo_timers::callback::Timeout;
+use gloo_worker::{Spawnable, WorkerBridge};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
+use std::ops::Range;
+use surface::{
+    SurfaceFrame, SurfaceRequest, SurfaceWorker, PREVIEW_RESOLUTION, RANGE, WORKER_PATH,
+};
 use web_sys::HtmlCanvasElement;
 use yew::prelude::*;
 
-// TODO
-// - Make 3D Graph more understandable
-// - Improve Client Side Performance
-
 const FONT_SIZE_THRESHOLD: i32 = 300;
 const DESKTOP_FONT_SIZE: i32 = 20;
 const MOBILE_FONT_SIZE: i32 = 10;
+/// Quiet period after the last slider input before the worker is asked for a surface.
+const DEBOUNCE_MS: u32 = 150;
+const HEATMAP_CELLS: usize = 120;
+const DEFAULT_CONTOUR_LEVELS: [f64; 3] = [0.5, 0.9, 0.95];
+/// Cycled through when there are more levels than colours.
+const CONTOUR_COLORS: [RGBColor; 5] = [RED, MAGENTA, BLUE, GREEN, CYAN];
+/// Smallest Mahalanobis radius shown by the planar views, so that the view
+/// never shrinks below ±3 standard deviations.
+const MIN_PLANAR_RADIUS: f64 = 3.0;
+
+/// How the joint density is drawn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BivariateView {
+    Surface,
+    Contour,
+    Heatmap,
+}
+
+impl BivariateView {
+    const ALL: [BivariateView; 3] = [Self::Surface, Self::Contour, Self::Heatmap];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Surface => "3-D surface",
+            Self::Contour => "Contours",
+            Self::Heatmap => "Heatmap",
+        }
+    }
+}
+
+/// Contour probabilities typed as a comma-separated list, each in (0, 1).
+fn parse_levels(text: &str) -> Result<Vec<f64>, String> {
+    let levels = parse_numbers(text)?;
+    if levels.is_empty() {
+        return Err("Enter at least one contour level".to_string());
+    }
+    if let Some(level) = levels.iter().find(|&&p| !(p > 0.0 && p < 1.0)) {
+        return Err(format!(
+            "Contour level {} is not strictly between 0 and 1",
+            level
+        ));
+    }
+    Ok(levels)
+}
+
+/// Axis ranges of the planar views: wide enough for the largest contour
+/// ellipse, whose extent along each axis is its Mahalanobis radius times the
+/// standard deviation of that coordinate, with a tenth to spare.
+fn planar_ranges(dist: &BivariateNormal, levels: &[f64]) -> (Range<f64>, Range<f64>) {
+    let chi_squared = ChiSquared::new(2.0);
+    let radius = levels
+        .iter()
+        .map(|&p| chi_squared.quantile(p).sqrt())
+        .fold(MIN_PLANAR_RADIUS, f64::max)
+        * 1.1;
+    let (x, y) = (dist.marginal_x(), dist.marginal_y());
+    let half_x = radius * x.std_dev();
+    let half_y = radius * y.std_dev();
+    (
+        x.mean() - half_x..x.mean() + half_x,
+        y.mean() - half_y..y.mean() + half_y,
+    )
+}
 
 #[function_component(BivariateNormalDistribution)]
 pub fn bivariate_normal_distribution() -> Html {
-    let mean_x = use_state(|| 1.0);
-    let mean_y = use_state(|| -1.0);
-    let variance_x = use_state(|| 1.0);
-    let variance_y = use_state(|| 1.0);
-    let correlation = use_state(|| 0.2);
-    let conditional_x = use_state(|| 0.0);
+    let mean_x = use_query_state_in("bivariate-normal", "mean_x", 1.0, -3.0..=3.0);
+    let mean_y = use_query_state_in("bivariate-normal", "mean_y", -1.0, -3.0..=3.0);
+    let variance_x = use_query_state_in("bivariate-normal", "variance_x", 1.0, 0.1..=3.0);
+    let variance_y = use_query_state_in("bivariate-normal", "variance_y", 1.0, 0.1..=3.0);
+    let correlation = use_query_state_in("bivariate-normal", "rho", 0.2, -0.99..=0.99);
+    let conditional_x = use_query_state_in("bivariate-normal", "given_x", 0.0, -3.0..=3.0);
+    let levels = use_query_state_if(
+        "bivariate-normal",
+        "levels",
+        DEFAULT_CONTOUR_LEVELS.to_vec(),
+        |levels| !levels.is_empty() && levels.iter().all(|&p| p > 0.0 && p < 1.0),
+    );
+    let levels_text = use_state(|| {
+        levels
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    });
+    let levels_error = use_state(|| None::<String>);
+    let view = use_state(|| BivariateView::Surface);
+    let show_cdf = use_state(|| false);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref_contour = use_node_ref();
     let canvas_ref_conditional = use_node_ref();
     let conditional_mean_y = use_state(|| 0.0);
     let conditional_variance_y = use_state(|| 0.0);
+    let surface = use_state(|| None::<SurfaceFrame>);
+    let latest_frame = use_mut_ref(|| 0_u64);
+    let bridge = use_mut_ref(|| None::<WorkerBridge<SurfaceWorker>>);
+
+    {
+        let surface = surface.clone();
+        let latest_frame = latest_frame.clone();
+        let bridge = bridge.clone();
+        use_effect_with_deps(
+            move |_| {
+                let spawned = SurfaceWorker::spawner()
+                    .callback(move |frame: SurfaceFrame| {
+                        // Frames for superseded parameters are dropped
+                        if frame.frame == *latest_frame.borrow() {
+                            surface.set(Some(frame));
+                        }
+                    })
+                    .spawn(WORKER_PATH);
+                *bridge.borrow_mut() = Some(spawned);
+                move || {
+                    bridge.borrow_mut().take();
+                }
+            },
+            (),
+        );
+    }
+
+    {
+        let dist = BivariateNormal::new(*mean_x, *mean_y, *variance_x, *variance_y, *correlation);
+        let latest_frame = latest_frame.clone();
+        let bridge = bridge.clone();
+        use_effect_with_deps(
+            move |_| {
+                let request = Timeout::new(DEBOUNCE_MS, move || {
+                    let frame = *latest_frame.borrow() + 1;
+                    *latest_frame.borrow_mut() = frame;
+                    if let Some(bridge) = bridge.borrow().as_ref() {
+                        bridge.send(SurfaceRequest { frame, dist });
+                    }
+                });
+                // Dropping the timeout cancels it when the sliders move again
+                move || drop(request)
+            },
+            dist,
+        );
+    }
 
     {
         let mean_x = *mean_x;
@@ -31,13 +170,23 @@ pub fn bivariate_normal_distribution() -> Html {
         let variance_y: f64 = *variance_y;
         let correlation: f64 = *correlation;
         let conditional_x: f64 = *conditional_x;
+        let view = *view;
+        let levels = (*levels).clone();
+        let contour_levels = levels.clone();
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
         let canvas_ref_contour = canvas_ref_contour.clone();
         let canvas_ref_conditional = canvas_ref_conditional.clone();
         let conditional_mean_y = conditional_mean_y.clone();
         let conditional_variance_y = conditional_variance_y.clone();
+        let surface_frame = surface.as_ref().map(|frame| frame.frame);
+        let surface = surface.clone();
 
         use_effect_with_deps(
             move |_| {
+                let dist =
+                    BivariateNormal::new(mean_x, mean_y, variance_x, variance_y, correlation);
+
                 if let Some(canvas) = canvas_ref_contour.cast::<HtmlCanvasElement>() {
                     let parent = canvas.parent_element().unwrap();
                     let width = parent.client_width();
@@ -46,89 +195,21 @@ pub fn bivariate_normal_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let fontsize = if width > FONT_SIZE_THRESHOLD {
-                        DESKTOP_FONT_SIZE
-                    } else {
-                        MOBILE_FONT_SIZE
-                    };
-
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption("Bivariate Normal Distribution", ("sans-serif", fontsize))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_3d(-3.0..3.0, 0.0..0.5, -3.0..3.0)
-                        .unwrap();
-
-                    chart
-                        .configure_axes()
-                        .light_grid_style(WHITE.mix(0.8))
-                        .max_light_lines(3)
-                        .x_formatter(&|x| {
-                            if *x < 0.0 {
-                                format!("x=一{:.1}", -x) // Emphasize negative x values
-                            } else {
-                                format!("x={:.1}", x)
-                            }
-                        })
-                        .z_formatter(&|z| {
-                            if *z < 0.0 {
-                                format!("y=一{:.1}", -z) // Emphasize negative z values
-                            } else {
-                                format!("y={:.1}", z)
-                            }
-                        })
-                        .x_labels(3)
-                        .z_labels(3)
-                        .draw()
-                        .unwrap();
-
-                    let pdf = |x: f64, y: f64| -> f64 {
-                        let std_dev_x = variance_x.sqrt();
-                        let std_dev_y = variance_y.sqrt();
-                        let rho = correlation;
-
-                        let z = (x - mean_x) / std_dev_x;
-                        let w = (y - mean_y) / std_dev_y;
-
-                        let rho2 = rho.powi(2);
-                        let z2 = z.powi(2);
-                        let w2 = w.powi(2);
-
-                        let exp = (-0.5 / (1.0 - rho2) * (z2 - 2.0 * rho * z * w + w2)).exp();
-                        let norm = 1.0
-                            / (2.0
-                                * std_dev_x
-                                * std_dev_y
-                                * (1.0 - rho2).sqrt()
-                                * std::f64::consts::PI);
-
-                        norm * exp
-                    };
-
-                    chart
-                        .draw_series(
-                            SurfaceSeries::xoz(
-                                (-300..=300).map(|i| -3.0 + 6.0 * i as f64 / 300.0),
-                                (-300..=300).map(|i| -3.0 + 6.0 * i as f64 / 300.0),
-                                pdf,
-                            )
-                            .style_func(&|&v| (VulcanoHSL::get_color(v / 0.1).into())),
-                        )
-                        .unwrap();
-
-                    let y_lines_data = (-300..=300).map(|i| -3.0 + 6.0 * i as f64 / 300.0);
-                    chart
-                        .draw_series(y_lines_data.clone().map(|y| {
-                            let x = conditional_x;
-                            PathElement::new(vec![(x, pdf(x, y), y), (x, 0.5, y)], BLACK.mix(0.2))
-                        }))
-                        .unwrap();
+                    draw_joint(
+                        &backend.into_drawing_area(),
+                        &dist,
+                        view,
+                        &contour_levels,
+                        surface.as_ref(),
+                        conditional_x,
+                        simulation,
+                    );
                 }
 
+                let conditional = dist.conditional_y(conditional_x);
+                conditional_mean_y.set(conditional.mean());
+                conditional_variance_y.set(conditional.variance());
+
                 if let Some(canvas) = canvas_ref_conditional.cast::<HtmlCanvasElement>() {
                     let parent = canvas.parent_element().unwrap();
                     let width = parent.client_width();
@@ -137,52 +218,12 @@ pub fn bivariate_normal_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let fontsize = if width > FONT_SIZE_THRESHOLD {
-                        DESKTOP_FONT_SIZE - 1
-                    } else {
-                        MOBILE_FONT_SIZE - 1
-                    };
-
-                    let caption = format!(
-                        "Y given X = {:.1} (Mean: {:.2}, Variance: {:.2})",
-                        conditional_x, *conditional_mean_y, *conditional_variance_y
+                    draw_conditional(
+                        &backend.into_drawing_area(),
+                        conditional_x,
+                        &conditional,
+                        show_cdf,
                     );
-
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", fontsize))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(-3.0..3.0, 0.0..1.0)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    let new_conditional_mean_y = mean_y
-                        + correlation * (variance_y / variance_x).sqrt() * (conditional_x - mean_x);
-                    conditional_mean_y.set(new_conditional_mean_y);
-
-                    let new_conditional_variance_y = variance_y * (1.0 - correlation.powi(2));
-                    conditional_variance_y.set(new_conditional_variance_y);
-
-                    let normal_pdf = |x: f64, mean: f64, variance: f64| -> f64 {
-                        let sigma = variance.sqrt();
-                        let z = (x - mean) / sigma;
-                        (-0.5 * z.powi(2)).exp() / (sigma * (2.0 * std::f64::consts::PI).sqrt())
-                    };
-
-                    chart
-                        .draw_series((0..=100).map(|i| {
-                            let y = -3.0 + 6.0 * i as f64 / 100.0;
-                            let mean = new_conditional_mean_y;
-                            let variance = new_conditional_variance_y;
-                            let pdf = normal_pdf(y, mean, variance);
-                            Circle::new((y, pdf), 1, RED)
-                        }))
-                        .unwrap();
                 }
 
                 || ()
@@ -194,6 +235,11 @@ pub fn bivariate_normal_distribution() -> Html {
                 variance_y,
                 correlation,
                 conditional_x,
+                view,
+                levels,
+                show_cdf,
+                simulation,
+                surface_frame,
             ),
         );
     }
@@ -264,6 +310,71 @@ pub fn bivariate_normal_distribution() -> Html {
         })
     };
 
+    let oninput_levels = {
+        let levels = levels.clone();
+        let levels_text = levels_text.clone();
+        let levels_error = levels_error.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                let text = input.value();
+                match parse_levels(&text) {
+                    Ok(parsed) => {
+                        levels.set(parsed);
+                        levels_error.set(None);
+                    }
+                    Err(message) => levels_error.set(Some(message)),
+                }
+                levels_text.set(text);
+            }
+        })
+    };
+
+    let onchange_view = |option: BivariateView| {
+        let view = view.clone();
+        Callback::from(move |_: Event| view.set(option))
+    };
+
+    let dist = BivariateNormal::new(*mean_x, *mean_y, *variance_x, *variance_y, *correlation);
+
+    let render_joint_svg = {
+        let view = *view;
+        let levels = (*levels).clone();
+        let conditional_x = *conditional_x;
+        let simulation = *simulation;
+        let surface = surface.clone();
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_joint(
+                    root,
+                    &dist,
+                    view,
+                    &levels,
+                    surface.as_ref(),
+                    conditional_x,
+                    simulation,
+                )
+            })
+        })
+    };
+
+    let conditional_grid = {
+        let conditional_x = *conditional_x;
+        Callback::from(move |_| {
+            Grid::continuous(&dist.conditional_y(conditional_x), conditional_points())
+        })
+    };
+
+    let render_conditional_svg = {
+        let conditional_x = *conditional_x;
+        let show_cdf = *show_cdf;
+        Callback::from(move |size| {
+            let conditional = dist.conditional_y(conditional_x);
+            draw_svg(size, |root| {
+                draw_conditional(root, conditional_x, &conditional, show_cdf)
+            })
+        })
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto; display: flex; flex-wrap: wrap;">
             <div style="flex: 1 1 100%; margin-bottom: 20px;">
@@ -315,14 +426,329 @@ pub fn bivariate_normal_distribution() -> Html {
                     </p>
                     <p> {"Mean of Y given X = μ_Y + ρ * (σ_Y / σ_X) * (X - μ_X)"} </p>
                     <p> {"Variance of Y given X = σ_Y^2 * (1 - ρ^2)"} </p>
+                    <p> {"Contours: the ellipse holding probability p lies along the eigenvectors of Σ, with squared Mahalanobis radius equal to the χ²(2) quantile -2 ln(1 - p)"} </p>
+                    <div>
+                        <label>{ "View: " }</label>
+                        { for BivariateView::ALL.into_iter().map(|option| html! {
+                            <label>
+                                <input type="radio" name="bivariate-view" checked={*view == option}
+                                onchange={onchange_view(option)} />
+                                { option.label() }
+                            </label>
+                        }) }
+                    </div>
+                    if *view == BivariateView::Contour {
+                        <div>
+                            <label>{ "Contour levels: " }</label>
+                            <input type="text" value={(*levels_text).clone()}
+                            oninput={oninput_levels} style="width: 40%;" />
+                            if let Some(message) = &*levels_error {
+                                <p style="color: red;">{ message }</p>
+                            }
+                        </div>
+                    }
+                    <CdfToggle show_cdf={show_cdf.clone()} />
+                    <SimulationControls simulation={simulation.clone()} />
                  </div>
             </div>
             <div style="flex: 1 1 50%; padding: 10px;">
-                <canvas id="contour-plot" ref={canvas_ref_contour} style="width: 100%; height: auto;"></canvas>
+                <canvas id="contour-plot" ref={canvas_ref_contour.clone()} style="width: 100%; height: auto;"></canvas>
+                <ExportButtons name="bivariate-normal" canvas_ref={canvas_ref_contour} render_svg={render_joint_svg} />
             </div>
             <div style="flex: 1 1 50%; padding: 10px;">
-                <canvas id="conditional-plot" ref={canvas_ref_conditional} style="width: 100%; height: auto;"></canvas>
+                <canvas id="conditional-plot" ref={canvas_ref_conditional.clone()} style="width: 100%; height: auto;"></canvas>
+                <ExportButtons name="bivariate-normal-conditional" canvas_ref={canvas_ref_conditional} render_svg={render_conditional_svg} grid={conditional_grid} />
             </div>
         </div>
     }
 }
+
+/// Joint density in the selected view, captioned with sample statistics.
+fn draw_joint<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    dist: &BivariateNormal,
+    view: BivariateView,
+    levels: &[f64],
+    surface: Option<&SurfaceFrame>,
+    conditional_x: f64,
+    simulation: Option<Simulation>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let fontsize = if root.dim_in_pixel().0 as i32 > FONT_SIZE_THRESHOLD {
+        DESKTOP_FONT_SIZE
+    } else {
+        MOBILE_FONT_SIZE
+    };
+
+    let draws = simulation.map(|simulation| {
+        let pairs = dist.sample(&mut Rng::new(simulation.seed), simulation.size);
+        pairs.into_iter().unzip::<f64, f64, Vec<f64>, Vec<f64>>()
+    });
+    let caption = match &draws {
+        Some((xs, ys)) => format!(
+            "Sample Mean: ({:.2}, {:.2}), Sample Correlation: {:.2}",
+            mean(xs),
+            mean(ys),
+            sample_correlation(xs, ys)
+        ),
+        None => "Bivariate Normal Distribution".to_string(),
+    };
+
+    match view {
+        BivariateView::Surface => {
+            // Coarse preview until the worker delivers the full grid
+            let preview;
+            let frame = match surface {
+                Some(frame) if frame.dist == *dist => frame,
+                _ => {
+                    preview = SurfaceFrame::compute(0, *dist, PREVIEW_RESOLUTION);
+                    &preview
+                }
+            };
+            draw_surface(root, caption, fontsize, frame, conditional_x, &draws)
+        }
+        BivariateView::Contour | BivariateView::Heatmap => draw_planar(
+            root,
+            caption,
+            fontsize,
+            dist,
+            levels,
+            conditional_x,
+            &draws,
+            view,
+        ),
+    }
+}
+
+/// Density (or CDF) of Y given X = `conditional_x`.
+fn draw_conditional<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    conditional_x: f64,
+    conditional: &Normal,
+    show_cdf: bool,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let fontsize = if root.dim_in_pixel().0 as i32 > FONT_SIZE_THRESHOLD {
+        DESKTOP_FONT_SIZE - 1
+    } else {
+        MOBILE_FONT_SIZE - 1
+    };
+
+    let caption = format!(
+        "Y given X = {:.1} (Mean: {:.2}, Variance: {:.2})",
+        conditional_x,
+        conditional.mean(),
+        conditional.variance()
+    );
+
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", fontsize))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(-3.0..3.0, 0.0..1.0)
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    chart
+        .draw_series(conditional_points().map(|y| {
+            let value = if show_cdf {
+                conditional.cdf(y)
+            } else {
+                conditional.pdf(y)
+            };
+            Circle::new((y, value), 1, RED)
+        }))
+        .unwrap();
+}
+
+fn conditional_points() -> impl Iterator<Item = f64> {
+    (0..=100).map(|i| -3.0 + 6.0 * i as f64 / 100.0)
+}
+
+/// Density surface in 3-D, with the conditioning slice and simulated pairs.
+fn draw_surface<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    caption: String,
+    fontsize: i32,
+    surface: &SurfaceFrame,
+    conditional_x: f64,
+    draws: &Option<(Vec<f64>, Vec<f64>)>,
+) {
+    let dist = &surface.dist;
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", fontsize))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_3d(-3.0..3.0, 0.0..0.5, -3.0..3.0)
+        .unwrap();
+
+    chart
+        .configure_axes()
+        .light_grid_style(WHITE.mix(0.8))
+        .max_light_lines(3)
+        .x_formatter(&|x| {
+            if *x < 0.0 {
+                format!("x=一{:.1}", -x) // Emphasize negative x values
+            } else {
+                format!("x={:.1}", x)
+            }
+        })
+        .z_formatter(&|z| {
+            if *z < 0.0 {
+                format!("y=一{:.1}", -z) // Emphasize negative z values
+            } else {
+                format!("y={:.1}", z)
+            }
+        })
+        .x_labels(3)
+        .z_labels(3)
+        .draw()
+        .unwrap();
+
+    chart
+        .draw_series(
+            SurfaceSeries::xoz(surface.coordinates(), surface.coordinates(), |x, y| {
+                surface.density(x, y)
+            })
+            .style_func(&|&v| VulcanoHSL::get_color(v / 0.1).into()),
+        )
+        .unwrap();
+
+    let y_lines_data = (-300..=300).map(|i| -RANGE + 2.0 * RANGE * i as f64 / 600.0);
+    chart
+        .draw_series(y_lines_data.map(|y| {
+            let x = conditional_x;
+            PathElement::new(vec![(x, dist.pdf(x, y), y), (x, 0.5, y)], BLACK.mix(0.2))
+        }))
+        .unwrap();
+
+    // Simulated pairs are scattered on the floor of the surface plot
+    if let Some((xs, ys)) = draws {
+        let inside = |v: &f64| (-3.0..=3.0).contains(v);
+        chart
+            .draw_series(
+                xs.iter()
+                    .zip(ys)
+                    .filter(|(x, y)| inside(x) && inside(y))
+                    .map(|(&x, &y)| Circle::new((x, 0.0, y), 1, BLACK.mix(0.5).filled())),
+            )
+            .unwrap();
+    }
+}
+
+/// Top-down view: probability ellipses at `levels` or a density heatmap.
+#[allow(clippy::too_many_arguments)]
+fn draw_planar<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    caption: String,
+    fontsize: i32,
+    dist: &BivariateNormal,
+    levels: &[f64],
+    conditional_x: f64,
+    draws: &Option<(Vec<f64>, Vec<f64>)>,
+    view: BivariateView,
+) {
+    let (x_range, y_range) = planar_ranges(dist, levels);
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", fontsize))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(x_range.clone(), y_range.clone())
+        .unwrap();
+
+    chart
+        .configure_mesh()
+        .x_desc("x")
+        .y_desc("y")
+        .draw()
+        .unwrap();
+
+    if view == BivariateView::Heatmap {
+        let step_x = (x_range.end - x_range.start) / HEATMAP_CELLS as f64;
+        let step_y = (y_range.end - y_range.start) / HEATMAP_CELLS as f64;
+        // Relative to the peak, so the colours span the same scale whatever σ and ρ
+        let peak = dist.peak_density();
+        chart
+            .draw_series((0..HEATMAP_CELLS * HEATMAP_CELLS).map(|cell| {
+                let x = x_range.start + (cell % HEATMAP_CELLS) as f64 * step_x;
+                let y = y_range.start + (cell / HEATMAP_CELLS) as f64 * step_y;
+                let density = dist.pdf(x + step_x / 2.0, y + step_y / 2.0);
+                let color = VulcanoHSL::get_color(density / peak);
+                Rectangle::new([(x, y), (x + step_x, y + step_y)], color.filled())
+            }))
+            .unwrap();
+    }
+
+    if let Some((xs, ys)) = draws {
+        chart
+            .draw_series(
+                xs.iter()
+                    .zip(ys)
+                    .filter(|(x, y)| x_range.contains(x) && y_range.contains(y))
+                    .map(|(&x, &y)| Circle::new((x, y), 1, BLACK.mix(0.3).filled())),
+            )
+            .unwrap();
+    }
+
+    if view == BivariateView::Contour {
+        for (&probability, &color) in levels.iter().zip(CONTOUR_COLORS.iter().cycle()) {
+            chart
+                .draw_series(LineSeries::new(
+                    dist.ellipse(probability, 200),
+                    color.stroke_width(2),
+                ))
+                .unwrap()
+                .label(format!("{:.0}%", probability * 100.0))
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+        }
+
+        chart
+            .configure_series_labels()
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.8))
+            .draw()
+            .unwrap();
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            vec![(conditional_x, y_range.start), (conditional_x, y_range.end)],
+            BLACK.mix(0.4),
+        ))
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contour_levels_and_ranges() {
+        assert_eq!(parse_levels("0.5, 0.9,0.99"), Ok(vec![0.5, 0.9, 0.99]));
+        assert!(parse_levels("").is_err());
+        assert!(parse_levels("0.5, 1").is_err());
+        assert!(parse_levels("0").is_err());
+        assert!(parse_levels("0.5, x").is_err());
+
+        // A standard normal keeps the familiar ±3 view
+        let standard = BivariateNormal::new(0.0, 0.0, 1.0, 1.0, 0.0);
+        let (x, y) = planar_ranges(&standard, &DEFAULT_CONTOUR_LEVELS);
+        assert!((x.end - 3.3).abs() < 1e-12 && (y.start + 3.3).abs() < 1e-12);
+
+        // Every ellipse fits inside the view, however wide or high it is
+        let wide = BivariateNormal::new(1.0, -2.0, 25.0, 0.1, 0.8);
+        let levels = [0.5, 0.999];
+        let (x, y) = planar_ranges(&wide, &levels);
+        for &p in &levels {
+            for (ex, ey) in wide.ellipse(p, 200) {
+                assert!(x.contains(&ex) && y.contains(&ey), "{} ({}, {})", p, ex, ey);
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Density surface of the bivariate normal, computed in a web worker.
+//!
+//! Every parameter change is a new frame. While it is being computed the
+//! chart shows a coarse preview evaluated on the main thread. The worker fills
+//! in the full grid a few rows at a time, yielding between chunks so that a
+//! newer frame can cancel it.
+
+use crate::stats::continuous::BivariateNormal;
+use gloo_timers::callback::Timeout;
+use gloo_worker::{HandlerId, Worker, WorkerScope};
+use serde::{Deserialize, Serialize};
+
+/// The surface covers [-RANGE, RANGE] on both axes.
+pub const RANGE: f64 = 3.0;
+pub const PREVIEW_RESOLUTION: usize = 40;
+pub const FULL_RESOLUTION: usize = 600;
+const ROWS_PER_CHUNK: usize = 20;
+
+/// Where the worker script is served from, see `index.html`.
+pub const WORKER_PATH: &str = "/rust-wasm-github/surface_worker.js";
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SurfaceRequest {
+    pub frame: u64,
+    pub dist: BivariateNormal,
+}
+
+/// pdf values of `dist` on a (resolution + 1)² grid, row-major with y as the row.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SurfaceFrame {
+    pub frame: u64,
+    pub dist: BivariateNormal,
+    pub resolution: usize,
+    pub values: Vec<f64>,
+}
+
+impl SurfaceFrame {
+    pub fn compute(frame: u64, dist: BivariateNormal, resolution: usize) -> Self {
+        let values = (0..=resolution)
+            .flat_map(|row| density_row(&dist, row, resolution))
+            .collect();
+        Self {
+            frame,
+            dist,
+            resolution,
+            values,
+        }
+    }
+
+    /// Grid coordinates along either axis.
+    pub fn coordinates(&self) -> impl Iterator<Item = f64> + Clone {
+        let resolution = self.resolution;
+        (0..=resolution).map(move |i| coordinate(i, resolution))
+    }
+
+    /// Density at the grid point nearest to (x, y).
+    pub fn density(&self, x: f64, y: f64) -> f64 {
+        let index = |v: f64| {
+            let position = (v + RANGE) / (2.0 * RANGE) * self.resolution as f64;
+            (position.round().max(0.0) as usize).min(self.resolution)
+        };
+        self.values[index(y) * (self.resolution + 1) + index(x)]
+    }
+}
+
+fn coordinate(i: usize, resolution: usize) -> f64 {
+    -RANGE + 2.0 * RANGE * i as f64 / resolution as f64
+}
+
+fn density_row(dist: &BivariateNormal, row: usize, resolution: usize) -> Vec<f64> {
+    let y = coordinate(row, resolution);
+    (0..=resolution)
+        .map(|i| dist.pdf(coordinate(i, resolution), y))
+        .collect()
+}
+
+/// Full-resolution frame being filled in.
+struct Job {
+    request: SurfaceRequest,
+    handler: HandlerId,
+    values: Vec<f64>,
+    next_row: usize,
+}
+
+pub struct SurfaceWorker {
+    job: Option<Job>,
+}
+
+pub enum SurfaceMessage {
+    Continue,
+}
+
+impl SurfaceWorker {
+    /// Runs the next chunk from a timer rather than a microtask, so requests
+    /// that arrived in the meantime are received first.
+    fn schedule(scope: &WorkerScope<Self>) {
+        let resume = scope.callback(|_: ()| SurfaceMessage::Continue);
+        Timeout::new(0, move || resume(())).forget();
+    }
+}
+
+impl Worker for SurfaceWorker {
+    type Message = SurfaceMessage;
+    type Input = SurfaceRequest;
+    type Output = SurfaceFrame;
+
+    fn create(_scope: &WorkerScope<Self>) -> Self {
+        Self { job: None }
+    }
+
+    fn update(&mut self, scope: &WorkerScope<Self>, _msg: Self::Message) {
+        let Some(job) = &mut self.job else {
+            return;
+        };
+        let end = (job.next_row + ROWS_PER_CHUNK).min(FULL_RESOLUTION + 1);
+        for row in job.next_row..end {
+            job.values
+                .extend(density_row(&job.request.dist, row, FULL_RESOLUTION));
+        }
+        job.next_row = end;
+
+        if end > FULL_RESOLUTION {
+            let job = self.job.take().unwrap();
+            let frame = SurfaceFrame {
+                frame: job.request.frame,
+                dist: job.request.dist,
+                resolution: FULL_RESOLUTION,
+                values: job.values,
+            };
+            scope.respond(job.handler, frame);
+        } else {
+            Self::schedule(scope);
+        }
+    }
+
+    fn received(&mut self, scope: &WorkerScope<Self>, request: Self::Input, id: HandlerId) {
+        // Replacing the job cancels whatever stale frame was in progress
+        let idle = self.job.is_none();
+        self.job = Some(Job {
+            request,
+            handler: id,
+            values: Vec::with_capacity((FULL_RESOLUTION + 1).pow(2)),
+            next_row: 0,
+        });
+        if idle {
+            Self::schedule(scope);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_matches_pdf() {
+        let dist = BivariateNormal::new(0.5, -0.5, 1.0, 2.0, 0.3);
+        let frame = SurfaceFrame::compute(1, dist, 60);
+        assert_eq!(frame.values.len(), 61 * 61);
+        assert_eq!(frame.coordinates().count(), 61);
+        for (x, y) in [(-3.0, -3.0), (0.5, -0.5), (1.2, 2.9), (3.0, 0.0)] {
+            assert!((frame.density(x, y) - dist.pdf(x, y)).abs() < 1e-12);
+        }
+    }
+}
@@ -1,3 +1,18 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{
+    shade_continuous, shade_critical_region, CriticalRegion, Interval, IntervalControls,
+};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_continuous_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    draw_histogram, sample_caption, Simulation, SimulationControls,
+};
+use crate::stats::continuous::ChiSquared;
+use crate::stats::{Continuous, Rng, Sample};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
 use web_sys::HtmlCanvasElement;
@@ -5,11 +20,23 @@ use yew::prelude::*;
 
 #[function_component(ChiSquaredDistribution)]
 pub fn chi_squared_distribution() -> Html {
-    let degrees_of_freedom = use_state(|| 1.0);
+    let degrees_of_freedom = use_query_state_in("chi-squared", "n", 1.0, 1.0..=20.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<f64>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<f64>>);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref = use_node_ref();
 
+    let axes = locked_axes
+        .unwrap_or_else(|| Axes::continuous(&ChiSquared::new(*degrees_of_freedom), *show_cdf));
+
     {
         let degrees_of_freedom = *degrees_of_freedom;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
         let canvas_ref = canvas_ref.clone();
         use_effect_with_deps(
             move |_| {
@@ -22,52 +49,27 @@ pub fn chi_squared_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let mean = degrees_of_freedom;
-                    let variance = 2.0 * degrees_of_freedom;
-                    let caption = format!("Mean: {:.2}, Variance: {:.2}", mean, variance);
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", 20))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(0.0..20.0, 0.0..0.5)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    let chi_squared_pdf = |x: f64, n: f64| -> f64 {
-                        if x < 0.0 {
-                            0.0
-                        } else {
-                            let k = n / 2.0;
-                            let lambda: f64 = 0.5;
-                            (lambda.powf(k) * x.powf(k - 1.0) * (-lambda * x).exp()) / gamma(k)
-                        }
-                    };
-
-                    chart
-                        .draw_series(LineSeries::new(
-                            (0..2000)
-                                .map(|x| x as f64 / 100.0)
-                                .map(|x| (x, chi_squared_pdf(x, degrees_of_freedom))),
-                            RED,
-                        ))
-                        .unwrap()
-                        .label(format!("n = {}", degrees_of_freedom))
-                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
-
-                    chart
-                        .configure_series_labels()
-                        .border_style(BLACK)
-                        .draw()
-                        .unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        degrees_of_freedom,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                        None,
+                    );
                 }
                 || ()
             },
-            (degrees_of_freedom,),
+            (
+                axes,
+                degrees_of_freedom,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
         );
     }
 
@@ -82,6 +84,44 @@ pub fn chi_squared_distribution() -> Html {
         })
     };
 
+    let probability =
+        interval.map(|interval| interval.probability(&ChiSquared::new(*degrees_of_freedom)));
+
+    let render_svg = {
+        let degrees_of_freedom = *degrees_of_freedom;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root,
+                    axes,
+                    degrees_of_freedom,
+                    show_cdf,
+                    simulation,
+                    &pinned,
+                    interval,
+                    None,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let degrees_of_freedom = *degrees_of_freedom;
+        Callback::from(move |_| {
+            let dist = ChiSquared::new(degrees_of_freedom);
+            Grid::continuous(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let degrees_of_freedom = *degrees_of_freedom;
+        format!("Chi-Squared: n = {}", degrees_of_freedom)
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto;">
             <h2>{ "Chi-Squared Distribution" }</h2>
@@ -94,12 +134,78 @@ pub fn chi_squared_distribution() -> Html {
                 <span>{ format!("{:.2}", *degrees_of_freedom) }</span>
             </div>
             <p> {"Mean: n, Variance: 2n"} </p>
-            <canvas id="plot" ref={canvas_ref} style="width: 100%; height: auto;"></canvas>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<f64> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<f64> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="chi-squared" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::continuous(ChiSquared::new(*degrees_of_freedom)) />
         </div>
     }
 }
 
-// Helper function to compute the gamma function
-fn gamma(x: f64) -> f64 {
-    libm::tgamma(x)
+/// Density or CDF chart, also drawn by the hypothesis testing page with the
+/// `critical` region of its test.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<f64>,
+    degrees_of_freedom: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<f64>>,
+    critical: Option<CriticalRegion>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = ChiSquared::new(degrees_of_freedom);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    if let Some(draws) = &draws {
+        draw_histogram(&mut chart, draws, show_cdf);
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            axes.points()
+                .map(|x| (x, if show_cdf { dist.cdf(x) } else { dist.pdf(x) })),
+            RED,
+        ))
+        .unwrap()
+        .label(format!("n = {}", degrees_of_freedom))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    if let Some(interval) = interval {
+        shade_continuous(&mut chart, &dist, interval, show_cdf);
+    }
+
+    if let Some(critical) = critical {
+        shade_critical_region(&mut chart, &dist, critical, show_cdf);
+    }
+
+    draw_continuous_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
 }
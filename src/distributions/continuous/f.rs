@@ -1,3 +1,18 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{
+    shade_continuous, shade_critical_region, CriticalRegion, Interval, IntervalControls,
+};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_continuous_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    draw_histogram, sample_caption, Simulation, SimulationControls,
+};
+use crate::stats::continuous::F;
+use crate::stats::{Continuous, Rng, Sample};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
 use web_sys::HtmlCanvasElement;
@@ -5,13 +20,24 @@ use yew::prelude::*;
 
 #[function_component(FDistribution)]
 pub fn f_distribution() -> Html {
-    let df1 = use_state(|| 1.0);
-    let df2 = use_state(|| 1.0);
+    let df1 = use_query_state_in("f", "df1", 1.0, 1.0..=30.0);
+    let df2 = use_query_state_in("f", "df2", 1.0, 1.0..=30.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<f64>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<f64>>);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref = use_node_ref();
 
+    let axes = locked_axes.unwrap_or_else(|| Axes::continuous(&F::new(*df1, *df2), *show_cdf));
+
     {
         let df1: f64 = *df1;
         let df2: f64 = *df2;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
         let canvas_ref = canvas_ref.clone();
         use_effect_with_deps(
             move |_| {
@@ -24,63 +50,29 @@ pub fn f_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let caption = if df2 > 4.0 {
-                        format!(
-                            "Mean: {:.2}, Variance: {:.2}",
-                            df2 / (df2 - 2.0),
-                            (2.0 * df2.powf(2.0) * (df1 + df2 - 2.0))
-                                / (df1 * (df2 - 2.0).powf(2.0) * (df2 - 4.0))
-                        )
-                    } else if df2 > 2.0 {
-                        format!(
-                            "Mean: {:.2}, Variance: {:.2}",
-                            df2 / (df2 - 2.0),
-                            f64::INFINITY
-                        )
-                    } else {
-                        "Mean: ∞, Variance: ∞".to_string()
-                    };
-
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", 20))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(0.0..5.0, 0.0..1.0)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    let f_distribution_pdf = |x: f64, df1: f64, df2: f64| -> f64 {
-                        let numerator = (df1 * x).powf(df1) * df2.powf(df2);
-                        let denominator = (df1 * x + df2).powf(df1 + df2);
-                        let beta = gamma(df1 / 2.0) * gamma(df2 / 2.0) / gamma((df1 + df2) / 2.0);
-                        (numerator / denominator).sqrt() / (x * beta)
-                    };
-
-                    chart
-                        .draw_series(LineSeries::new(
-                            (1..500)
-                                .map(|x| x as f64 / 100.0)
-                                .map(|x| (x, f_distribution_pdf(x, df1, df2))),
-                            RED,
-                        ))
-                        .unwrap()
-                        .label(format!("df1 = {}, df2 = {}", df1, df2))
-                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
-
-                    chart
-                        .configure_series_labels()
-                        .border_style(BLACK)
-                        .draw()
-                        .unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        df1,
+                        df2,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                        None,
+                    );
                 }
                 || ()
             },
-            (df1, df2),
+            (
+                axes,
+                df1,
+                df2,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
         );
     }
 
@@ -106,6 +98,39 @@ pub fn f_distribution() -> Html {
         })
     };
 
+    let probability = interval.map(|interval| interval.probability(&F::new(*df1, *df2)));
+
+    let render_svg = {
+        let df1 = *df1;
+        let df2 = *df2;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root, axes, df1, df2, show_cdf, simulation, &pinned, interval, None,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let df1 = *df1;
+        let df2 = *df2;
+        Callback::from(move |_| {
+            let dist = F::new(df1, df2);
+            Grid::continuous(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let df1 = *df1;
+        let df2 = *df2;
+        format!("F: df1 = {}, df2 = {}", df1, df2)
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto;">
             <h2>{ "F-Distribution" }</h2>
@@ -124,12 +149,80 @@ pub fn f_distribution() -> Html {
                 oninput={on_df2_input} style="width: 70%;" />
                 <span>{ format!("{:.2}", *df2) }</span>
             </div>
-            <canvas id="plot" ref={canvas_ref} style="width: 100%; height: auto;"></canvas>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<f64> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<f64> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="f" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::continuous(F::new(*df1, *df2)) />
         </div>
     }
 }
 
-// Helper function to compute the gamma function
-fn gamma(x: f64) -> f64 {
-    libm::tgamma(x)
+/// Density or CDF chart, also drawn by the hypothesis testing page with the
+/// `critical` region of its test.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<f64>,
+    df1: f64,
+    df2: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<f64>>,
+    critical: Option<CriticalRegion>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = F::new(df1, df2);
+    let caption = moments_caption(&dist);
+
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    if let Some(draws) = &draws {
+        draw_histogram(&mut chart, draws, show_cdf);
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            axes.points()
+                .map(|x| (x, if show_cdf { dist.cdf(x) } else { dist.pdf(x) })),
+            RED,
+        ))
+        .unwrap()
+        .label(format!("df1 = {}, df2 = {}", df1, df2))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    if let Some(interval) = interval {
+        shade_continuous(&mut chart, &dist, interval, show_cdf);
+    }
+
+    if let Some(critical) = critical {
+        shade_critical_region(&mut chart, &dist, critical, show_cdf);
+    }
+
+    draw_continuous_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
 }
@@ -1,3 +1,16 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_continuous, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_continuous_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    draw_histogram, draw_labelled_histogram, sample_caption, Simulation, SimulationControls,
+};
+use crate::stats::continuous::Gamma;
+use crate::stats::{Continuous, Rng, Sample};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
 use web_sys::HtmlCanvasElement;
@@ -5,13 +18,25 @@ use yew::prelude::*;
 
 #[function_component(GammaDistribution)]
 pub fn gamma_distribution() -> Html {
-    let shape = use_state(|| 1.0);
-    let rate = use_state(|| 1.0);
+    let shape = use_query_state_in("gamma", "alpha", 1.0, 0.01..=10.0);
+    let rate = use_query_state_in("gamma", "beta", 1.0, 0.01..=10.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<f64>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<f64>>);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref = use_node_ref();
 
+    let axes =
+        locked_axes.unwrap_or_else(|| Axes::continuous(&Gamma::new(*shape, *rate), *show_cdf));
+
     {
         let shape = *shape;
         let rate = *rate;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
         let canvas_ref = canvas_ref.clone();
         use_effect_with_deps(
             move |_| {
@@ -24,53 +49,29 @@ pub fn gamma_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let caption = format!(
-                        "Mean: {:.2}, Variance: {:.2}",
-                        shape / rate,
-                        shape / (rate * rate)
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        shape,
+                        rate,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                        None,
                     );
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", 20))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(0.0..20.0, 0.0..1.0)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    let gamma_pdf = |x: f64, alpha: f64, beta: f64| -> f64 {
-                        if x < 0.0 {
-                            0.0
-                        } else {
-                            (beta.powf(alpha) * x.powf(alpha - 1.0) * (-beta * x).exp())
-                                / gamma(alpha)
-                        }
-                    };
-
-                    chart
-                        .draw_series(LineSeries::new(
-                            (0..2000)
-                                .map(|x| x as f64 / 100.0)
-                                .map(|x| (x, gamma_pdf(x, shape, rate))),
-                            RED,
-                        ))
-                        .unwrap()
-                        .label(format!("α = {}, β = {}", shape, rate))
-                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
-
-                    chart
-                        .configure_series_labels()
-                        .border_style(BLACK)
-                        .draw()
-                        .unwrap();
                 }
                 || ()
             },
-            (shape, rate),
+            (
+                axes,
+                shape,
+                rate,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
         );
     }
 
@@ -96,6 +97,39 @@ pub fn gamma_distribution() -> Html {
         })
     };
 
+    let probability = interval.map(|interval| interval.probability(&Gamma::new(*shape, *rate)));
+
+    let render_svg = {
+        let shape = *shape;
+        let rate = *rate;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root, axes, shape, rate, show_cdf, simulation, &pinned, interval, None,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let shape = *shape;
+        let rate = *rate;
+        Callback::from(move |_| {
+            let dist = Gamma::new(shape, rate);
+            Grid::continuous(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let shape = *shape;
+        let rate = *rate;
+        format!("Gamma: α = {}, β = {}", shape, rate)
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto;">
             <h2>{ "Gamma Distribution" }</h2>
@@ -115,12 +149,80 @@ pub fn gamma_distribution() -> Html {
             </div>
             <p> {"Mean: α / β, Variance: α / β²"} </p>
             <p> {" Gamma Function: Γ(x) = ∫^∞_{0} t^{x-1}exp(-t) dt " } </p>
-            <canvas id="plot" ref={canvas_ref} style="width: 100%; height: auto;"></canvas>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<f64> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<f64> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="gamma" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::continuous(Gamma::new(*shape, *rate)) />
         </div>
     }
 }
 
-// Helper function to compute the gamma function
-fn gamma(x: f64) -> f64 {
-    libm::tgamma(x)
+/// Density or CDF chart, also drawn by the fitting page over the histogram
+/// of its `data`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<f64>,
+    shape: f64,
+    rate: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<f64>>,
+    data: Option<&[f64]>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Gamma::new(shape, rate);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    if let Some(draws) = &draws {
+        draw_histogram(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(data) = data {
+        let label = format!("Data (n = {})", data.len());
+        draw_labelled_histogram(&mut chart, data, show_cdf, label);
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            axes.points()
+                .map(|x| (x, if show_cdf { dist.cdf(x) } else { dist.pdf(x) })),
+            RED,
+        ))
+        .unwrap()
+        .label(format!("α = {}, β = {}", shape, rate))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    if let Some(interval) = interval {
+        shade_continuous(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_continuous_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
 }
@@ -1,3 +1,16 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_continuous, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_continuous_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    draw_histogram, draw_labelled_histogram, sample_caption, Simulation, SimulationControls,
+};
+use crate::stats::continuous::LogNormal;
+use crate::stats::{Continuous, Rng, Sample};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
 use web_sys::HtmlCanvasElement;
@@ -5,13 +18,25 @@ use yew::prelude::*;
 
 #[function_component(LogNormalDistribution)]
 pub fn log_normal_distribution() -> Html {
-    let mean = use_state(|| 0.0);
-    let std_dev = use_state(|| 1.0);
+    let mean = use_query_state_in("log-normal", "mu", 0.0, -3.0..=3.0);
+    let std_dev = use_query_state_in("log-normal", "sigma", 1.0, 0.1..=3.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<f64>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<f64>>);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref = use_node_ref();
 
+    let axes = locked_axes
+        .unwrap_or_else(|| Axes::continuous(&LogNormal::new(*mean, *std_dev), *show_cdf));
+
     {
         let mean: f64 = *mean;
         let std_dev: f64 = *std_dev;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
         let canvas_ref = canvas_ref.clone();
         use_effect_with_deps(
             move |_| {
@@ -24,53 +49,29 @@ pub fn log_normal_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let caption = format!(
-                        "Mean: {:.2}, Std Dev: {:.2}",
-                        (mean.exp() * (std_dev.powi(2) / 2.0).exp()),
-                        ((std_dev.exp().powi(2) - 1.0) * (2.0 * mean + std_dev.exp().powi(2)))
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        mean,
+                        std_dev,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                        None,
                     );
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", 20))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(0.0..5.0, 0.0..2.0)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    let log_normal_pdf = |x: f64, mean: f64, std_dev: f64| -> f64 {
-                        if x <= 0.0 {
-                            0.0
-                        } else {
-                            (1.0 / (x * std_dev * (2.0 * std::f64::consts::PI).sqrt()))
-                                * (-((x.ln() - mean).powi(2)) / (2.0 * std_dev.powi(2))).exp()
-                        }
-                    };
-
-                    chart
-                        .draw_series(LineSeries::new(
-                            (1..1000)
-                                .map(|x| x as f64 / 200.0)
-                                .map(|x| (x, log_normal_pdf(x, mean, std_dev))),
-                            BLUE,
-                        ))
-                        .unwrap()
-                        .label(format!("μ = {}, σ = {}", mean, std_dev))
-                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
-
-                    chart
-                        .configure_series_labels()
-                        .border_style(BLACK)
-                        .draw()
-                        .unwrap();
                 }
                 || ()
             },
-            (mean, std_dev),
+            (
+                axes,
+                mean,
+                std_dev,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
         );
     }
 
@@ -96,6 +97,40 @@ pub fn log_normal_distribution() -> Html {
         })
     };
 
+    let probability =
+        interval.map(|interval| interval.probability(&LogNormal::new(*mean, *std_dev)));
+
+    let render_svg = {
+        let mean = *mean;
+        let std_dev = *std_dev;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root, axes, mean, std_dev, show_cdf, simulation, &pinned, interval, None,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let mean = *mean;
+        let std_dev = *std_dev;
+        Callback::from(move |_| {
+            let dist = LogNormal::new(mean, std_dev);
+            Grid::continuous(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let mean = *mean;
+        let std_dev = *std_dev;
+        format!("Log-Normal: μ = {}, σ = {}", mean, std_dev)
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto;">
             <h2>{ "Log-Normal Distribution" }</h2>
@@ -114,7 +149,80 @@ pub fn log_normal_distribution() -> Html {
                 <span>{ format!("{:.2}", *std_dev) }</span>
             </div>
             <p> {"Mean: exp(μ + σ²/2), Variance: (exp(σ²) - 1) * exp(2μ + σ²)"} </p>
-            <canvas id="plot" ref={canvas_ref} style="width: 100%; height: auto;"></canvas>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<f64> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<f64> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="log-normal" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::continuous(LogNormal::new(*mean, *std_dev)) />
         </div>
     }
 }
+
+/// Density or CDF chart, also drawn by the fitting page over the histogram
+/// of its `data`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<f64>,
+    mean: f64,
+    std_dev: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<f64>>,
+    data: Option<&[f64]>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = LogNormal::new(mean, std_dev);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    if let Some(draws) = &draws {
+        draw_histogram(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(data) = data {
+        let label = format!("Data (n = {})", data.len());
+        draw_labelled_histogram(&mut chart, data, show_cdf, label);
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            axes.points()
+                .map(|x| (x, if show_cdf { dist.cdf(x) } else { dist.pdf(x) })),
+            BLUE,
+        ))
+        .unwrap()
+        .label(format!("μ = {}, σ = {}", mean, std_dev))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+    if let Some(interval) = interval {
+        shade_continuous(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_continuous_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
@@ -0,0 +1,213 @@
+use crate::distributions::export::{draw_svg, ExportButtons};
+use crate::distributions::query::use_query_state_if;
+use crate::distributions::simulation::{Simulation, SimulationControls};
+use crate::stats::continuous::MultivariateNormal;
+use crate::stats::descriptive::correlation as sample_correlation;
+use crate::stats::{Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+const DIMENSION: usize = 3;
+const GRID: usize = 40;
+const PAIRS: [(usize, usize); 3] = [(0, 1), (0, 2), (1, 2)];
+
+#[function_component(MultivariateNormalDistribution)]
+pub fn multivariate_normal_distribution() -> Html {
+    let mean = use_query_state_if("multivariate-normal", "mu", [0.0; DIMENSION], |mean| {
+        mean.iter().all(|x| x.is_finite())
+    });
+    // Finite but not positive definite matrices are reported below the sliders
+    let covariance = use_query_state_if(
+        "multivariate-normal",
+        "sigma",
+        [[1.0, 0.5, 0.2], [0.5, 1.0, -0.3], [0.2, -0.3, 1.0]],
+        |covariance| covariance.iter().flatten().all(|x| x.is_finite()),
+    );
+    let simulation = use_state(|| None::<Simulation>);
+    let canvas_ref = use_node_ref();
+
+    let dist = MultivariateNormal::new(
+        mean.to_vec(),
+        covariance.iter().map(|row| row.to_vec()).collect(),
+    );
+
+    {
+        let dist = dist.clone();
+        let simulation = *simulation;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let (Some(canvas), Some(dist)) = (canvas_ref.cast::<HtmlCanvasElement>(), dist) {
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.4) as i32;
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(&backend.into_drawing_area(), &dist, simulation);
+                }
+                || ()
+            },
+            (*mean, *covariance, simulation),
+        );
+    }
+
+    let oninput_mean = |i: usize| {
+        let mean = mean.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    let mut updated = *mean;
+                    updated[i] = value;
+                    mean.set(updated);
+                }
+            }
+        })
+    };
+
+    // Entries are edited in pairs so the matrix stays symmetric
+    let oninput_covariance = |i: usize, j: usize| {
+        let covariance = covariance.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    let mut updated = *covariance;
+                    updated[i][j] = value;
+                    updated[j][i] = value;
+                    covariance.set(updated);
+                }
+            }
+        })
+    };
+
+    let render_svg = {
+        let dist = dist.clone();
+        let simulation = *simulation;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                if let Some(dist) = &dist {
+                    draw_chart(root, dist, simulation)
+                }
+            })
+        })
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h2>{ "Multivariate Normal Distribution" }</h2>
+            <p> {"This distribution models several jointly normal random variables. Every marginal and conditional distribution is again normal."} </p>
+            <p> {"pdf: f(x) = (2π)^(-d/2) * |Σ|^(-1/2) * exp(-1/2 * (x - μ)^T * Σ^-1 * (x - μ))"} </p>
+            <div>
+                <label>{ "Mean (μ): " }</label>
+                { for (0..DIMENSION).map(|i| html! {
+                    <input type="number" step="0.1" value={mean[i].to_string()}
+                    oninput={oninput_mean(i)} style="width: 5em;" />
+                }) }
+            </div>
+            <div>
+                <label>{ "Covariance (Σ): " }</label>
+                <table>
+                    { for (0..DIMENSION).map(|i| html! {
+                        <tr>
+                            { for (0..DIMENSION).map(|j| html! {
+                                <td>
+                                    <input type="number" step="0.1" value={covariance[i][j].to_string()}
+                                    oninput={oninput_covariance(i, j)} style="width: 5em;" />
+                                </td>
+                            }) }
+                        </tr>
+                    }) }
+                </table>
+            </div>
+            if dist.is_none() {
+                <p style="color: red;">
+                    { "Σ must be positive definite: every variance positive and the correlations mutually consistent." }
+                </p>
+            }
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="multivariate-normal" {canvas_ref} {render_svg} />
+        </div>
+    }
+}
+
+/// Pairwise marginal densities side by side, with the simulated draws.
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    dist: &MultivariateNormal,
+    simulation: Option<Simulation>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+
+    for (area, &(i, j)) in root.split_evenly((1, PAIRS.len())).iter().zip(&PAIRS) {
+        let marginal = dist.marginal(&[i, j]);
+        // Mean ± 3.5 standard deviations of each coordinate
+        let range = |k: usize| {
+            let half_width = 3.5 * marginal.covariance()[k][k].sqrt();
+            let center = marginal.mean()[k];
+            (center - half_width)..(center + half_width)
+        };
+        let (x_range, y_range) = (range(0), range(1));
+
+        let caption = match &draws {
+            Some(draws) => {
+                let xs: Vec<f64> = draws.iter().map(|x| x[i]).collect();
+                let ys: Vec<f64> = draws.iter().map(|x| x[j]).collect();
+                format!(
+                    "X{} vs X{} (r = {:.2})",
+                    i + 1,
+                    j + 1,
+                    sample_correlation(&xs, &ys)
+                )
+            }
+            None => format!("X{} vs X{}", i + 1, j + 1),
+        };
+
+        let mut chart = ChartBuilder::on(area)
+            .margin(10)
+            .caption(caption, ("sans-serif", 16))
+            .x_label_area_size(25)
+            .y_label_area_size(30)
+            .build_cartesian_2d(x_range.clone(), y_range.clone())
+            .unwrap();
+
+        chart
+            .configure_mesh()
+            .x_labels(5)
+            .y_labels(5)
+            .draw()
+            .unwrap();
+
+        // Density heatmap, shaded relative to the peak at the mean
+        let peak = marginal.pdf(marginal.mean());
+        let dx = (x_range.end - x_range.start) / GRID as f64;
+        let dy = (y_range.end - y_range.start) / GRID as f64;
+        chart
+            .draw_series((0..GRID * GRID).map(|cell| {
+                let x = x_range.start + (cell % GRID) as f64 * dx;
+                let y = y_range.start + (cell / GRID) as f64 * dy;
+                let density = marginal.pdf(&[x + dx / 2.0, y + dy / 2.0]) / peak;
+                Rectangle::new([(x, y), (x + dx, y + dy)], BLUE.mix(density).filled())
+            }))
+            .unwrap();
+
+        if let Some(draws) = &draws {
+            chart
+                .draw_series(
+                    draws
+                        .iter()
+                        .map(|x| (x[i], x[j]))
+                        .filter(|(x, y)| x_range.contains(x) && y_range.contains(y))
+                        .map(|point| Circle::new(point, 1, BLACK.mix(0.4).filled())),
+                )
+                .unwrap();
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_continuous, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_continuous_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    draw_histogram, draw_labelled_histogram, sample_caption, Simulation, SimulationControls,
+};
+use crate::stats::continuous::Normal;
+use crate::stats::{Continuous, Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+#[function_component(NormalDistribution)]
+pub fn normal_distribution() -> Html {
+    let mean = use_query_state_in("normal", "mu", 0.0, -5.0..=5.0);
+    let std_dev = use_query_state_in("normal", "sigma", 1.0, 0.4..=5.0);
+    let z_score = use_query_state_in("normal", "z", 1.0, 0.0..=4.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<f64>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<f64>>);
+    let simulation = use_state(|| None::<Simulation>);
+    let canvas_ref = use_node_ref();
+
+    let axes =
+        locked_axes.unwrap_or_else(|| Axes::continuous(&Normal::new(*mean, *std_dev), *show_cdf));
+
+    {
+        let mean: f64 = *mean;
+        let std_dev: f64 = *std_dev;
+        let z_score: f64 = *z_score;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        mean,
+                        std_dev,
+                        Some(z_score),
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                        None,
+                    );
+                }
+                || ()
+            },
+            (
+                axes,
+                mean,
+                std_dev,
+                z_score,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
+        );
+    }
+
+    let on_mean_input = {
+        let mean = mean.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    mean.set(value);
+                }
+            }
+        })
+    };
+
+    let on_std_dev_input = {
+        let std_dev = std_dev.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    std_dev.set(value);
+                }
+            }
+        })
+    };
+
+    let on_z_score_input = {
+        let z_score = z_score.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    z_score.set(value);
+                }
+            }
+        })
+    };
+
+    let probability = {
+        let dist = Normal::standard();
+        dist.cdf(*z_score) - dist.cdf(-*z_score)
+    };
+
+    let interval_probability =
+        interval.map(|interval| interval.probability(&Normal::new(*mean, *std_dev)));
+
+    let render_svg = {
+        let mean = *mean;
+        let std_dev = *std_dev;
+        let z_score = *z_score;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root,
+                    axes,
+                    mean,
+                    std_dev,
+                    Some(z_score),
+                    show_cdf,
+                    simulation,
+                    &pinned,
+                    interval,
+                    None,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let mean = *mean;
+        let std_dev = *std_dev;
+        Callback::from(move |_| {
+            let dist = Normal::new(mean, std_dev);
+            Grid::continuous(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let mean = *mean;
+        let std_dev = *std_dev;
+        format!("Normal: μ = {}, σ = {}", mean, std_dev)
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h2>{ "Normal Distribution" }</h2>
+            <p> {"This distribution describes sums of many small independent effects (central limit theorem)."} </p>
+            <p> {"Probability Density Function: f(x; μ, σ) = (1 / (σ√(2π))) * exp(-(x - μ)² / (2σ²))"} </p>
+            <p> {"Cumulative Distribution Function: F(x) = (1 + erf((x - μ) / (σ√2))) / 2"} </p>
+            <div>
+                <label>{ "Mean (μ): " }</label>
+                <input type="range" min="-5" max="5" step="0.01" value={(*mean).to_string()}
+                oninput={on_mean_input} style="width: 70%;" />
+                <span>{ format!("{:.2}", *mean) }</span>
+            </div>
+            <div>
+                <label>{ "Standard Deviation (σ): " }</label>
+                <input type="range" min="0.4" max="5" step="0.01" value={(*std_dev).to_string()}
+                oninput={on_std_dev_input} style="width: 70%;" />
+                <span>{ format!("{:.2}", *std_dev) }</span>
+            </div>
+            <div>
+                <label>{ "z-score (z): " }</label>
+                <input type="range" min="0" max="4" step="0.01" value={(*z_score).to_string()}
+                oninput={on_z_score_input} style="width: 70%;" />
+                <span>{ format!("{:.2}", *z_score) }</span>
+            </div>
+            <p> {"Mean: μ, Variance: σ²"} </p>
+            <p> { format!("P(μ - zσ < X < μ + zσ) = erf(z/√2) = {:.4}", probability) } </p>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<f64> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<f64> interval={interval.clone()} {axes} probability={interval_probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="normal" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::continuous(Normal::new(*mean, *std_dev)) />
+        </div>
+    }
+}
+
+/// Density or CDF chart, also drawn by the fitting page over the histogram
+/// of its `data`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<f64>,
+    mean: f64,
+    std_dev: f64,
+    z_score: Option<f64>,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<f64>>,
+    data: Option<&[f64]>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Normal::new(mean, std_dev);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    if let Some(draws) = &draws {
+        draw_histogram(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(data) = data {
+        let label = format!("Data (n = {})", data.len());
+        draw_labelled_histogram(&mut chart, data, show_cdf, label);
+    }
+
+    // Shade μ ± zσ under the density
+    if let (Some(z_score), false) = (z_score, show_cdf) {
+        let lower = mean - z_score * std_dev;
+        let upper = mean + z_score * std_dev;
+        chart
+            .draw_series(AreaSeries::new(
+                (0..=200)
+                    .map(|i| lower + (upper - lower) * i as f64 / 200.0)
+                    .map(|x| (x, dist.pdf(x))),
+                0.0,
+                BLUE.mix(0.2),
+            ))
+            .unwrap()
+            .label(format!(
+                "P(|Z| < {:.2}) = {:.4}",
+                z_score,
+                dist.cdf(upper) - dist.cdf(lower)
+            ))
+            .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], BLUE.mix(0.2).filled()));
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            axes.points()
+                .map(|x| (x, if show_cdf { dist.cdf(x) } else { dist.pdf(x) })),
+            RED,
+        ))
+        .unwrap()
+        .label(format!("μ = {}, σ = {}", mean, std_dev))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    if let Some(interval) = interval {
+        shade_continuous(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_continuous_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
@@ -0,0 +1,218 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_continuous, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_continuous_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    draw_histogram, sample_caption, Simulation, SimulationControls,
+};
+use crate::stats::continuous::Pareto;
+use crate::stats::{Continuous, Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+#[function_component(ParetoDistribution)]
+pub fn pareto_distribution() -> Html {
+    let scale = use_query_state_in("pareto", "xm", 1.0, 0.5..=5.0);
+    let shape = use_query_state_in("pareto", "alpha", 3.0, 0.5..=5.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<f64>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<f64>>);
+    let simulation = use_state(|| None::<Simulation>);
+    let canvas_ref = use_node_ref();
+
+    let axes =
+        locked_axes.unwrap_or_else(|| Axes::continuous(&Pareto::new(*scale, *shape), *show_cdf));
+
+    {
+        let scale = *scale;
+        let shape = *shape;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        scale,
+                        shape,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                    );
+                }
+                || ()
+            },
+            (
+                axes,
+                scale,
+                shape,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
+        );
+    }
+
+    let on_scale_input = {
+        let scale = scale.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    scale.set(value);
+                }
+            }
+        })
+    };
+
+    let on_shape_input = {
+        let shape = shape.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    shape.set(value);
+                }
+            }
+        })
+    };
+
+    let probability = interval.map(|interval| interval.probability(&Pareto::new(*scale, *shape)));
+
+    let render_svg = {
+        let scale = *scale;
+        let shape = *shape;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root, axes, scale, shape, show_cdf, simulation, &pinned, interval,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let scale = *scale;
+        let shape = *shape;
+        Callback::from(move |_| {
+            let dist = Pareto::new(scale, shape);
+            Grid::continuous(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let scale = *scale;
+        let shape = *shape;
+        format!("Pareto: xₘ = {}, α = {}", scale, shape)
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h2>{ "Pareto Distribution" }</h2>
+            <p> {"This distribution models power-law quantities such as incomes and city sizes."} </p>
+            <p> {"Probability Density Function: f(x; xₘ, α) = α * xₘ^α / x^(α+1) for x ≥ xₘ"} </p>
+            <div>
+                <label>{ "Scale (xₘ): " }</label>
+                <input type="range" min="0.5" max="5" step="0.01" value={(*scale).to_string()}
+                oninput={on_scale_input} style="width: 70%;" />
+                <span>{ format!("{:.2}", *scale) }</span>
+            </div>
+            <div>
+                <label>{ "Shape (α): " }</label>
+                <input type="range" min="0.5" max="5" step="0.01" value={(*shape).to_string()}
+                oninput={on_shape_input} style="width: 70%;" />
+                <span>{ format!("{:.2}", *shape) }</span>
+            </div>
+            <p> {"Mean: α * xₘ / (α - 1) for α > 1 (∞ otherwise), Variance: xₘ² * α / ((α - 1)² * (α - 2)) for α > 2 (∞ otherwise)"} </p>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<f64> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<f64> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="pareto" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::continuous(Pareto::new(*scale, *shape)) />
+        </div>
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<f64>,
+    scale: f64,
+    shape: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<f64>>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Pareto::new(scale, shape);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    if let Some(draws) = &draws {
+        draw_histogram(&mut chart, draws, show_cdf);
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            axes.points()
+                .map(|x| (x, if show_cdf { dist.cdf(x) } else { dist.pdf(x) })),
+            RED,
+        ))
+        .unwrap()
+        .label(format!("xₘ = {}, α = {}", scale, shape))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    if let Some(interval) = interval {
+        shade_continuous(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_continuous_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
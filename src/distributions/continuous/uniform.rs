@@ -0,0 +1,218 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_continuous, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_continuous_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    draw_histogram, sample_caption, Simulation, SimulationControls,
+};
+use crate::stats::continuous::Uniform;
+use crate::stats::{Continuous, Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+#[function_component(UniformDistribution)]
+pub fn uniform_distribution() -> Html {
+    let lower = use_query_state_in("uniform", "a", -1.0, -5.0..=0.0);
+    let upper = use_query_state_in("uniform", "b", 1.0, 0.1..=5.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<f64>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<f64>>);
+    let simulation = use_state(|| None::<Simulation>);
+    let canvas_ref = use_node_ref();
+
+    let axes =
+        locked_axes.unwrap_or_else(|| Axes::continuous(&Uniform::new(*lower, *upper), *show_cdf));
+
+    {
+        let lower = *lower;
+        let upper = *upper;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        lower,
+                        upper,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                    );
+                }
+                || ()
+            },
+            (
+                axes,
+                lower,
+                upper,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
+        );
+    }
+
+    let on_lower_input = {
+        let lower = lower.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    lower.set(value);
+                }
+            }
+        })
+    };
+
+    let on_upper_input = {
+        let upper = upper.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    upper.set(value);
+                }
+            }
+        })
+    };
+
+    let probability = interval.map(|interval| interval.probability(&Uniform::new(*lower, *upper)));
+
+    let render_svg = {
+        let lower = *lower;
+        let upper = *upper;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root, axes, lower, upper, show_cdf, simulation, &pinned, interval,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let lower = *lower;
+        let upper = *upper;
+        Callback::from(move |_| {
+            let dist = Uniform::new(lower, upper);
+            Grid::continuous(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let lower = *lower;
+        let upper = *upper;
+        format!("Uniform: a = {}, b = {}", lower, upper)
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h2>{ "Uniform Distribution" }</h2>
+            <p> {"This distribution gives every value in an interval the same density."} </p>
+            <p> {"Probability Density Function: f(x; a, b) = 1 / (b - a) for a ≤ x ≤ b, 0 otherwise"} </p>
+            <div>
+                <label>{ "Lower Bound (a): " }</label>
+                <input type="range" min="-5" max="0" step="0.01" value={(*lower).to_string()}
+                oninput={on_lower_input} style="width: 70%;" />
+                <span>{ format!("{:.2}", *lower) }</span>
+            </div>
+            <div>
+                <label>{ "Upper Bound (b): " }</label>
+                <input type="range" min="0.1" max="5" step="0.01" value={(*upper).to_string()}
+                oninput={on_upper_input} style="width: 70%;" />
+                <span>{ format!("{:.2}", *upper) }</span>
+            </div>
+            <p> {"Mean: (a + b) / 2, Variance: (b - a)² / 12"} </p>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<f64> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<f64> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="uniform" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::continuous(Uniform::new(*lower, *upper)) />
+        </div>
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<f64>,
+    lower: f64,
+    upper: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<f64>>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Uniform::new(lower, upper);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    if let Some(draws) = &draws {
+        draw_histogram(&mut chart, draws, show_cdf);
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            axes.points()
+                .map(|x| (x, if show_cdf { dist.cdf(x) } else { dist.pdf(x) })),
+            RED,
+        ))
+        .unwrap()
+        .label(format!("a = {}, b = {}", lower, upper))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    if let Some(interval) = interval {
+        shade_continuous(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_continuous_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
@@ -0,0 +1,57 @@
+use crate::distributions::axes::Axes;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct CdfToggleProps {
+    pub show_cdf: UseStateHandle<bool>,
+}
+
+/// Checkbox switching a chart between the density and the cumulative distribution.
+#[function_component(CdfToggle)]
+pub fn cdf_toggle(props: &CdfToggleProps) -> Html {
+    let onchange = {
+        let show_cdf = props.show_cdf.clone();
+        Callback::from(move |_: Event| show_cdf.set(!*show_cdf))
+    };
+
+    html! {
+        <div>
+            <label>
+                <input type="checkbox" checked={*props.show_cdf} {onchange} />
+                { " Plot cumulative distribution function (CDF)" }
+            </label>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct AxesLockProps<X: PartialEq + 'static> {
+    /// The frozen ranges, or `None` while the axes follow the distribution.
+    pub locked: UseStateHandle<Option<Axes<X>>>,
+    /// Ranges currently in use, frozen when the lock is switched on.
+    pub axes: Axes<X>,
+}
+
+/// Checkbox keeping the chart ranges fixed while the parameters change.
+#[function_component(AxesLock)]
+pub fn axes_lock<X: Copy + PartialEq + 'static>(props: &AxesLockProps<X>) -> Html {
+    let onchange = {
+        let locked = props.locked.clone();
+        let axes = props.axes;
+        Callback::from(move |_: Event| {
+            locked.set(match *locked {
+                Some(_) => None,
+                None => Some(axes),
+            })
+        })
+    };
+
+    html! {
+        <div>
+            <label>
+                <input type="checkbox" checked={props.locked.is_some()} {onchange} />
+                { " Lock axes to compare parameter settings" }
+            </label>
+        </div>
+    }
+}
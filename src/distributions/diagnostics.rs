@@ -0,0 +1,415 @@
+//! Goodness-of-fit diagnostics of data against a fitted family, or against
+//! any family with parameters chosen by hand.
+//!
+//! A Q–Q plot sets the sample quantiles against the quantiles of the model and
+//! a P–P plot the empirical probabilities against its cdf; both follow the
+//! diagonal when the model is good. The tests put a p-value on the departure.
+
+use crate::distributions::export::{draw_svg, ExportButtons};
+use crate::distributions::fit::FamilyPicker;
+use crate::stats::fit::{are_integers, Family, Fit};
+use crate::stats::goodness_of_fit::{
+    anderson_darling, continuous_chi_squared, discrete_chi_squared, kolmogorov_smirnov, TestResult,
+};
+use crate::stats::hypothesis::Alternative;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+/// The distribution the data are checked against.
+#[derive(Clone, Debug, PartialEq)]
+struct Model {
+    family: Family,
+    parameters: Vec<f64>,
+    /// How many of the parameters were estimated from the data, zero when
+    /// they were chosen by hand.
+    estimated: usize,
+}
+
+impl From<&Fit> for Model {
+    fn from(fit: &Fit) -> Self {
+        Self {
+            family: fit.family,
+            parameters: fit.parameters.clone(),
+            estimated: fit.parameters.len(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Plot {
+    QuantileQuantile,
+    ProbabilityProbability,
+}
+
+impl Plot {
+    const ALL: [Plot; 2] = [Self::QuantileQuantile, Self::ProbabilityProbability];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::QuantileQuantile => "Q–Q plot",
+            Self::ProbabilityProbability => "P–P plot",
+        }
+    }
+
+    fn axis_descriptions(self) -> (&'static str, &'static str) {
+        match self {
+            Self::QuantileQuantile => ("Theoretical quantiles", "Sample quantiles"),
+            Self::ProbabilityProbability => {
+                ("Theoretical probabilities", "Empirical probabilities")
+            }
+        }
+    }
+
+    /// One point per observation at plotting position (i - 0.5) / n.
+    fn points(self, data: &[f64], model: &Model) -> Vec<(f64, f64)> {
+        let mut sorted = data.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let n = sorted.len() as f64;
+        let positions = (0..sorted.len()).map(|i| (i as f64 + 0.5) / n);
+        // (quantile, cdf) of the model at each plotting position and observation
+        let theoretical: Vec<(f64, f64)> = match model.family.continuous(&model.parameters) {
+            Some(dist) => positions
+                .zip(&sorted)
+                .map(|(p, &x)| (dist.quantile(p), dist.cdf(x)))
+                .collect(),
+            None => {
+                let dist = model.family.discrete(&model.parameters).unwrap();
+                positions
+                    .zip(&sorted)
+                    .map(|(p, &x)| (dist.quantile(p) as f64, dist.cdf(x as i64)))
+                    .collect()
+            }
+        };
+        theoretical
+            .into_iter()
+            .zip(&sorted)
+            .enumerate()
+            .map(|(i, ((quantile, cdf), &x))| match self {
+                Self::QuantileQuantile => (quantile, x),
+                Self::ProbabilityProbability => (cdf, (i as f64 + 0.5) / n),
+            })
+            .collect()
+    }
+}
+
+/// Kolmogorov–Smirnov, Anderson–Darling and χ² tests of the model, in that
+/// order; `None` where a test does not apply.
+fn tests(data: &[f64], model: &Model) -> [(&'static str, Option<TestResult>); 3] {
+    let estimated = model.estimated;
+    let chi_squared = match model.family.continuous(&model.parameters) {
+        Some(dist) => continuous_chi_squared(data, dist.as_ref(), estimated),
+        None => discrete_chi_squared(
+            data,
+            model.family.discrete(&model.parameters).unwrap().as_ref(),
+            estimated,
+        ),
+    }
+    .map(|test| TestResult {
+        statistic: test.statistic,
+        p_value: test.p_value(Alternative::Greater),
+    });
+    let continuous = model.family.continuous(&model.parameters);
+    [
+        (
+            "Kolmogorov–Smirnov D",
+            continuous
+                .as_ref()
+                .map(|dist| kolmogorov_smirnov(data, dist.as_ref())),
+        ),
+        (
+            "Anderson–Darling A²",
+            continuous
+                .as_ref()
+                .map(|dist| anderson_darling(data, dist.as_ref())),
+        ),
+        ("Pearson χ²", chi_squared),
+    ]
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DiagnosticsProps {
+    pub data: Vec<f64>,
+    pub fit: Fit,
+}
+
+/// Q–Q or P–P plot of `data` against `fit`, or against a family and
+/// parameters picked by hand, with goodness-of-fit tests.
+#[function_component(Diagnostics)]
+pub fn diagnostics(props: &DiagnosticsProps) -> Html {
+    let plot = use_state(|| Plot::QuantileQuantile);
+    let by_hand = use_state(|| false);
+    let family = use_state(|| props.fit.family);
+    let parameters = use_state(|| props.fit.parameters.clone());
+    let canvas_ref = use_node_ref();
+
+    let integers = are_integers(&props.data);
+    let families: Vec<Family> = Family::ALL
+        .into_iter()
+        .filter(|family| integers || !family.is_discrete())
+        .collect();
+    // A discrete family picked earlier no longer applies once the data stop
+    // being integers
+    let model = if *by_hand && families.contains(&family) {
+        Model {
+            family: *family,
+            parameters: (*parameters).clone(),
+            estimated: 0,
+        }
+    } else {
+        Model::from(&props.fit)
+    };
+
+    let results = use_memo(
+        |(data, model)| tests(data, model),
+        (props.data.clone(), model.clone()),
+    );
+
+    {
+        let plot = *plot;
+        let data = props.data.clone();
+        let model = model.clone();
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |(plot, data, model)| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(&backend.into_drawing_area(), *plot, data, model);
+                }
+                || ()
+            },
+            (plot, data, model),
+        );
+    }
+
+    let onchange_plot = |option: Plot| {
+        let plot = plot.clone();
+        Callback::from(move |_: Event| plot.set(option))
+    };
+
+    let onchange_by_hand = |option: bool| {
+        let by_hand = by_hand.clone();
+        let family = family.clone();
+        let parameters = parameters.clone();
+        let fit = props.fit.clone();
+        Callback::from(move |_: Event| {
+            // Hand-picked parameters start from the fit
+            if option && !*by_hand {
+                family.set(fit.family);
+                parameters.set(fit.parameters.clone());
+            }
+            by_hand.set(option);
+        })
+    };
+
+    let render_svg = {
+        let plot = *plot;
+        let data = props.data.clone();
+        let model = model.clone();
+        Callback::from(move |size| draw_svg(size, |root| draw_chart(root, plot, &data, &model)))
+    };
+
+    html! {
+        <div>
+            <h2>{ format!("Goodness of fit: {}", model.family.name()) }</h2>
+            <div>
+                <label>
+                    <input type="radio" name="diagnostic-model" checked={!*by_hand}
+                    onchange={onchange_by_hand(false)} />
+                    { format!(" Fitted {} ", props.fit.family.name()) }
+                </label>
+                <label>
+                    <input type="radio" name="diagnostic-model" checked={*by_hand}
+                    onchange={onchange_by_hand(true)} />
+                    { " Family and parameters of my choice " }
+                </label>
+            </div>
+            if *by_hand {
+                <FamilyPicker name="diagnostic-family" {families} {family} {parameters} />
+            }
+            <table style="width: 100%;">
+                <tr>
+                    <th>{ "Test" }</th>
+                    <th>{ "Statistic" }</th>
+                    <th>{ "p-value" }</th>
+                </tr>
+                { for results.iter().map(|(name, result)| html! {
+                    <tr>
+                        <td>{ *name }</td>
+                        if let Some(result) = result {
+                            <td>{ format!("{:.4}", result.statistic) }</td>
+                            <td>{ format!("{:.4}", result.p_value) }</td>
+                        } else {
+                            <td colspan="2">{ "Not applicable" }</td>
+                        }
+                    </tr>
+                }) }
+            </table>
+            <p>
+                { "Kolmogorov–Smirnov and Anderson–Darling need a continuous family, and their p-values are conservative when the parameters were estimated from the same data. The χ² test bins the data so that each bin expects at least five values and drops a degree of freedom per estimated parameter, none for parameters of your choice." }
+            </p>
+            <div>
+                { for Plot::ALL.into_iter().map(|option| html! {
+                    <label>
+                        <input type="radio" name="diagnostic-plot" checked={*plot == option}
+                        onchange={onchange_plot(option)} />
+                        { format!(" {} ", option.label()) }
+                    </label>
+                }) }
+            </div>
+            <canvas ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="diagnostics" {canvas_ref} {render_svg} />
+        </div>
+    }
+}
+
+/// The plot points with the diagonal they follow under a perfect fit.
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    plot: Plot,
+    data: &[f64],
+    model: &Model,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let points = plot.points(data, model);
+    let (min, max) = points
+        .iter()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &(x, y)| {
+            (min.min(x).min(y), max.max(x).max(y))
+        });
+    let padding = 0.05 * (max - min).max(f64::EPSILON);
+    let range = (min - padding)..(max + padding);
+    let (x_desc, y_desc) = plot.axis_descriptions();
+
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(
+            format!("{} against {}", plot.label(), model.family.name()),
+            ("sans-serif", 20),
+        )
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(range.clone(), range.clone())
+        .unwrap();
+
+    chart
+        .configure_mesh()
+        .x_desc(x_desc)
+        .y_desc(y_desc)
+        .draw()
+        .unwrap();
+
+    chart
+        .draw_series(LineSeries::new(
+            [(range.start, range.start), (range.end, range.end)],
+            RED.stroke_width(2),
+        ))
+        .unwrap()
+        .label("Perfect fit")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    chart
+        .draw_series(
+            points
+                .iter()
+                .map(|&point| Circle::new(point, 3, BLUE.mix(0.6).filled())),
+        )
+        .unwrap()
+        .label(format!("Data (n = {})", data.len()))
+        .legend(|(x, y)| Circle::new((x + 10, y), 3, BLUE.filled()));
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::continuous::Normal;
+    use crate::stats::fit::fit;
+    use crate::stats::{Rng, Sample};
+
+    #[test]
+    fn test_plots_follow_the_diagonal_for_a_good_fit() {
+        let data = Normal::new(2.0, 0.5).sample(&mut Rng::new(4), 500);
+        let normal = Model::from(&fit(Family::Normal, &data).unwrap());
+        for plot in Plot::ALL {
+            let points = plot.points(&data, &normal);
+            assert_eq!(points.len(), data.len());
+            // Sampling noise is largest in the tails, so look at the middle 90%
+            let worst = points[25..475]
+                .iter()
+                .map(|(x, y)| (x - y).abs())
+                .fold(0.0, f64::max);
+            assert!(worst < 0.1, "{:?} strays {}", plot, worst);
+            let svg = draw_svg((400, 300), |root| draw_chart(root, plot, &data, &normal));
+            assert!(svg.contains(plot.label()));
+        }
+
+        let counts = [0.0, 1.0, 1.0, 2.0, 3.0, 0.0, 4.0, 2.0];
+        let poisson = Model::from(&fit(Family::Poisson, &counts).unwrap());
+        let [ks, ad, chi_squared] = tests(&counts, &poisson);
+        assert!(ks.1.is_none() && ad.1.is_none());
+        // Eight counts are too few for two bins of five expected values
+        assert!(chi_squared.1.is_none());
+    }
+
+    #[test]
+    fn test_models_chosen_by_hand() {
+        let data = Normal::new(2.0, 0.5).sample(&mut Rng::new(4), 500);
+        let model = |parameters: Vec<f64>| Model {
+            family: Family::Normal,
+            parameters,
+            estimated: 0,
+        };
+
+        let truth = tests(&data, &model(vec![2.0, 0.5]));
+        assert!(truth
+            .iter()
+            .all(|(_, result)| result.unwrap().p_value > 0.01));
+        let standard = tests(&data, &model(vec![0.0, 1.0]));
+        assert!(standard
+            .iter()
+            .all(|(_, result)| result.unwrap().p_value < 1e-6));
+
+        // No degree of freedom is spent on parameters chosen by hand
+        let fitted = Model::from(&fit(Family::Normal, &data).unwrap());
+        let by_hand = Model {
+            estimated: 0,
+            ..fitted.clone()
+        };
+        let (fitted, by_hand) = (tests(&data, &fitted)[2].1, tests(&data, &by_hand)[2].1);
+        let (fitted, by_hand) = (fitted.unwrap(), by_hand.unwrap());
+        assert_eq!(fitted.statistic, by_hand.statistic);
+        assert!(by_hand.p_value > fitted.p_value);
+
+        // Families that are never fitted, here on differences of counts
+        let parameters = vec![3.0, 1.5];
+        let changes = Family::Skellam.sample(&parameters, &mut Rng::new(6), 500);
+        assert!(are_integers(&changes) && changes.iter().any(|&x| x < 0.0));
+        let skellam = Model {
+            family: Family::Skellam,
+            parameters,
+            estimated: 0,
+        };
+        let [ks, _, chi_squared] = tests(&changes, &skellam);
+        assert!(ks.1.is_none());
+        assert!(chi_squared.1.unwrap().p_value > 0.01);
+        assert_eq!(Plot::QuantileQuantile.points(&changes, &skellam).len(), 500);
+    }
+}
@@ -1,11 +1,21 @@
+pub mod bernoulli;
+pub mod beta_binomial;
 pub mod binomial;
+pub mod discrete_uniform;
 pub mod geometric;
 pub mod hypergeometric;
 pub mod negative_binomial;
 pub mod poisson;
+pub mod skellam;
+pub mod zipf;
 
+pub use bernoulli::BernoulliDistribution;
+pub use beta_binomial::BetaBinomialDistribution;
 pub use binomial::BinomialDistribution;
+pub use discrete_uniform::DiscreteUniformDistribution;
 pub use geometric::GeometricDistribution;
 pub use hypergeometric::HypergeometricDistribution;
 pub use negative_binomial::NegativeBinomialDistribution;
 pub use poisson::PoissonDistribution;
+pub use skellam::SkellamDistribution;
+pub use zipf::ZipfDistribution;
@@ -0,0 +1,185 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_discrete, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_discrete_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    discrete_sample_caption, draw_frequencies, Simulation, SimulationControls,
+};
+use crate::stats::discrete::Bernoulli;
+use crate::stats::{Discrete, Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+#[function_component(BernoulliDistribution)]
+pub fn bernoulli_distribution() -> Html {
+    let p = use_query_state_in("bernoulli", "p", 0.5, 0.001..=0.999);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<i32>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<i32>>);
+    let simulation = use_state(|| None::<Simulation>);
+    let canvas_ref = use_node_ref();
+
+    let axes = locked_axes.unwrap_or_else(|| Axes::discrete(&Bernoulli::new(*p), *show_cdf));
+
+    {
+        let p = *p;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        p,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                    );
+                }
+                || ()
+            },
+            (axes, p, show_cdf, simulation, (*pins).clone(), interval),
+        );
+    }
+
+    let oninput_p = {
+        let p = p.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    p.set(value);
+                }
+            }
+        })
+    };
+
+    let probability = interval.map(|interval| interval.probability(&Bernoulli::new(*p)));
+
+    let render_svg = {
+        let p = *p;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(root, axes, p, show_cdf, simulation, &pinned, interval)
+            })
+        })
+    };
+
+    let grid = {
+        let p = *p;
+        Callback::from(move |_| {
+            let dist = Bernoulli::new(p);
+            Grid::discrete(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let p = *p;
+        format!("Bernoulli: p = {:.2}", p)
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h2>{ "Bernoulli Distribution" }</h2>
+            <p>{ "This distribution models a single trial that succeeds (1) with probability p and fails (0) otherwise." }</p>
+            <p>{ "Probability Function: P(X=1) = p, P(X=0) = 1-p" }</p>
+            <div>
+                <label>{ "p (probability of success): " }</label>
+                <input type="range" min="0.001" max="0.999" step="0.001" value={p.to_string()}
+                oninput={oninput_p} style="width: 70%;" />
+                <span>{ format!("{:.2}", *p) }</span>
+            </div>
+            <p>{ "Mean: p, Variance: p(1-p)" }</p>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<i32> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<i32> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="bernoulli" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::discrete(Bernoulli::new(*p)) />
+        </div>
+    }
+}
+
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<i32>,
+    p: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<i32>>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Bernoulli::new(p);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, discrete_sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    chart
+        .draw_series(axes.points().map(|x| {
+            let y = if show_cdf {
+                dist.cdf(x as i64)
+            } else {
+                dist.pmf(x as i64)
+            };
+            Rectangle::new([(x, 0.0), (x + 1, y)], BLUE.filled())
+        }))
+        .unwrap()
+        .label(format!("p = {:.2}", p))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+    if let Some(draws) = &draws {
+        draw_frequencies(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(interval) = interval {
+        shade_discrete(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_discrete_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
@@ -0,0 +1,261 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_discrete, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_discrete_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    discrete_sample_caption, draw_frequencies, Simulation, SimulationControls,
+};
+use crate::stats::discrete::{BetaBinomial, Binomial};
+use crate::stats::{Discrete, Distribution, Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+#[function_component(BetaBinomialDistribution)]
+pub fn beta_binomial_distribution() -> Html {
+    let n = use_query_state_in("beta-binomial", "n", 10, 1..=50);
+    let alpha = use_query_state_in("beta-binomial", "alpha", 2.0, 0.1..=10.0);
+    let beta = use_query_state_in("beta-binomial", "beta", 2.0, 0.1..=10.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<i32>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<i32>>);
+    let simulation = use_state(|| None::<Simulation>);
+    let canvas_ref = use_node_ref();
+
+    let axes = locked_axes
+        .unwrap_or_else(|| Axes::discrete(&BetaBinomial::new(*n as u64, *alpha, *beta), *show_cdf));
+
+    {
+        let n = *n;
+        let alpha = *alpha;
+        let beta = *beta;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        n,
+                        alpha,
+                        beta,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                    );
+                }
+                || ()
+            },
+            (
+                axes,
+                n,
+                alpha,
+                beta,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
+        );
+    }
+
+    let oninput_n = {
+        let n = n.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<i32>() {
+                    n.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_alpha = {
+        let alpha = alpha.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    alpha.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_beta = {
+        let beta = beta.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    beta.set(value);
+                }
+            }
+        })
+    };
+
+    let overdispersion = {
+        let dist = BetaBinomial::new(*n as u64, *alpha, *beta);
+        let binomial = Binomial::new(*n as u64, *alpha / (*alpha + *beta));
+        format!(
+            "Variance: {:.2}, versus {:.2} for a binomial with the same mean",
+            dist.variance(),
+            binomial.variance()
+        )
+    };
+
+    let probability =
+        interval.map(|interval| interval.probability(&BetaBinomial::new(*n as u64, *alpha, *beta)));
+
+    let render_svg = {
+        let n = *n;
+        let alpha = *alpha;
+        let beta = *beta;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root, axes, n, alpha, beta, show_cdf, simulation, &pinned, interval,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let n = *n;
+        let alpha = *alpha;
+        let beta = *beta;
+        Callback::from(move |_| {
+            let dist = BetaBinomial::new(n as u64, alpha, beta);
+            Grid::discrete(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let n = *n;
+        let alpha = *alpha;
+        let beta = *beta;
+        format!(
+            "Beta-Binomial: n = {}, α = {:.2}, β = {:.2}",
+            n, alpha, beta
+        )
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h2>{ "Beta-Binomial Distribution" }</h2>
+            <p>{ "This distribution is a binomial whose success probability varies from experiment to experiment according to Beta(α, β). It models overdispersed counts: the variance exceeds that of a binomial with the same mean." }</p>
+            <p>{ "Probability Function: P(X=k) = C(n,k) * B(k+α, n-k+β) / B(α, β)" }</p>
+            <div>
+                <label>{ "n (number of trials): " }</label>
+                <input type="range" min="1" max="50" step="1" value={n.to_string()}
+                oninput={oninput_n} style="width: 70%;" />
+                <span>{ *n }</span>
+            </div>
+            <div>
+                <label>{ "α: " }</label>
+                <input type="range" min="0.1" max="10" step="0.1" value={alpha.to_string()}
+                oninput={oninput_alpha} style="width: 70%;" />
+                <span>{ format!("{:.2}", *alpha) }</span>
+            </div>
+            <div>
+                <label>{ "β: " }</label>
+                <input type="range" min="0.1" max="10" step="0.1" value={beta.to_string()}
+                oninput={oninput_beta} style="width: 70%;" />
+                <span>{ format!("{:.2}", *beta) }</span>
+            </div>
+            <p>{ "Mean: nα/(α+β), Variance: nαβ(α+β+n) / ((α+β)²(α+β+1))" }</p>
+            <p>{ overdispersion }</p>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<i32> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<i32> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="beta-binomial" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::discrete(BetaBinomial::new(*n as u64, *alpha, *beta)) />
+        </div>
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<i32>,
+    n: i32,
+    alpha: f64,
+    beta: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<i32>>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = BetaBinomial::new(n as u64, alpha, beta);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, discrete_sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    chart
+        .draw_series(axes.points().map(|x| {
+            let y = if show_cdf {
+                dist.cdf(x as i64)
+            } else {
+                dist.pmf(x as i64)
+            };
+            Rectangle::new([(x, 0.0), (x + 1, y)], MAGENTA.filled())
+        }))
+        .unwrap()
+        .label(format!("n = {}, α = {:.2}, β = {:.2}", n, alpha, beta))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], MAGENTA));
+
+    if let Some(draws) = &draws {
+        draw_frequencies(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(interval) = interval {
+        shade_discrete(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_discrete_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
@@ -1,3 +1,16 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_discrete, Interval, IntervalControls};
+use crate::distributions::moments::{MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_discrete_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    discrete_sample_caption, draw_frequencies, Simulation, SimulationControls,
+};
+use crate::stats::discrete::Binomial;
+use crate::stats::{Discrete, Distribution, Rng, Sample};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
 use web_sys::HtmlCanvasElement;
@@ -5,13 +18,25 @@ use yew::prelude::*;
 
 #[function_component(BinomialDistribution)]
 pub fn binomial_distribution() -> Html {
-    let n = use_state(|| 10);
-    let p = use_state(|| 0.5);
+    let n = use_query_state_in("binomial", "n", 10, 1..=500);
+    let p = use_query_state_in("binomial", "p", 0.5, 0.001..=0.999);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<i32>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<i32>>);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref = use_node_ref();
 
+    let axes =
+        locked_axes.unwrap_or_else(|| Axes::discrete(&Binomial::new(*n as u64, *p), *show_cdf));
+
     {
         let n = *n;
         let p = *p;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
         let canvas_ref = canvas_ref.clone();
         use_effect_with_deps(
             move |_| {
@@ -24,54 +49,20 @@ pub fn binomial_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let mean = n as f64 * p;
-                    let variance = n as f64 * p * (1.0 - p);
-                    let caption = format!("Mean(np): {:.2}, Variance: {:.2}", mean, variance);
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", 20))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(0..n + 1, 0.0..1.0)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    let binomial = |k: i32, n: i32, p: f64| -> f64 {
-                        let mut log_coeff = 0.0;
-                        for i in 1..=n {
-                            log_coeff += (i as f64).ln();
-                        }
-                        for i in 1..=k {
-                            log_coeff -= (i as f64).ln();
-                        }
-                        for i in 1..=(n - k) {
-                            log_coeff -= (i as f64).ln();
-                        }
-                        (log_coeff + k as f64 * p.ln() + (n - k) as f64 * (1.0 - p).ln()).exp()
-                    };
-
-                    chart
-                        .draw_series((0..=n).map(|x| {
-                            let y = binomial(x, n, p);
-                            Rectangle::new([(x, 0.0), (x + 1, y)], BLUE.filled())
-                        }))
-                        .unwrap()
-                        .label(format!("n = {}, p = {:.2}", n, p))
-                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
-
-                    chart
-                        .configure_series_labels()
-                        .border_style(BLACK)
-                        .draw()
-                        .unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        n,
+                        p,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                    );
                 }
                 || ()
             },
-            (n, p),
+            (axes, n, p, show_cdf, simulation, (*pins).clone(), interval),
         );
     }
 
@@ -97,6 +88,37 @@ pub fn binomial_distribution() -> Html {
         })
     };
 
+    let probability = interval.map(|interval| interval.probability(&Binomial::new(*n as u64, *p)));
+
+    let render_svg = {
+        let n = *n;
+        let p = *p;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(root, axes, n, p, show_cdf, simulation, &pinned, interval)
+            })
+        })
+    };
+
+    let grid = {
+        let n = *n;
+        let p = *p;
+        Callback::from(move |_| {
+            let dist = Binomial::new(n as u64, p);
+            Grid::discrete(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let n = *n;
+        let p = *p;
+        format!("Binomial: n = {}, p = {:.2}", n, p)
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto;">
             <h2>{ "Binomial Distribution" }</h2>
@@ -115,7 +137,79 @@ pub fn binomial_distribution() -> Html {
                 <span>{ format!("{:.2}", *p) }</span>
             </div>
             <p>{ "Mean: np, Variance: np(1-p)" }</p>
-            <canvas id="plot" ref={canvas_ref} style="width: 100%; height: auto;"></canvas>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<i32> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<i32> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="binomial" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::discrete(Binomial::new(*n as u64, *p)) />
         </div>
     }
 }
+
+#[allow(clippy::too_many_arguments)]
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<i32>,
+    n: i32,
+    p: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<i32>>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Binomial::new(n as u64, p);
+    let caption = format!(
+        "Mean(np): {:.2}, Variance: {:.2}",
+        dist.mean(),
+        dist.variance()
+    );
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, discrete_sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    chart
+        .draw_series(axes.points().map(|x| {
+            let y = if show_cdf {
+                dist.cdf(x as i64)
+            } else {
+                dist.pmf(x as i64)
+            };
+            Rectangle::new([(x, 0.0), (x + 1, y)], BLUE.filled())
+        }))
+        .unwrap()
+        .label(format!("n = {}, p = {:.2}", n, p))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+    if let Some(draws) = &draws {
+        draw_frequencies(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(interval) = interval {
+        shade_discrete(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_discrete_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
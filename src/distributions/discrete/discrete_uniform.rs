@@ -0,0 +1,220 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_discrete, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_discrete_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    discrete_sample_caption, draw_frequencies, Simulation, SimulationControls,
+};
+use crate::stats::discrete::DiscreteUniform;
+use crate::stats::{Discrete, Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+#[function_component(DiscreteUniformDistribution)]
+pub fn discrete_uniform_distribution() -> Html {
+    let a = use_query_state_in("discrete-uniform", "a", 1, -10..=10);
+    let b = use_query_state_in("discrete-uniform", "b", 6, -10..=10);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<i32>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<i32>>);
+    let simulation = use_state(|| None::<Simulation>);
+    let canvas_ref = use_node_ref();
+
+    let axes = locked_axes.unwrap_or_else(|| {
+        Axes::discrete(
+            &DiscreteUniform::new((*a).min(*b) as i64, (*a).max(*b) as i64),
+            *show_cdf,
+        )
+    });
+
+    {
+        let a = *a;
+        let b = *b;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        a,
+                        b,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                    );
+                }
+                || ()
+            },
+            (axes, a, b, show_cdf, simulation, (*pins).clone(), interval),
+        );
+    }
+
+    let oninput_a = {
+        let a = a.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<i32>() {
+                    a.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_b = {
+        let b = b.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<i32>() {
+                    b.set(value);
+                }
+            }
+        })
+    };
+
+    let probability = interval.map(|interval| {
+        interval.probability(&DiscreteUniform::new(
+            (*a).min(*b) as i64,
+            (*a).max(*b) as i64,
+        ))
+    });
+
+    let render_svg = {
+        let a = *a;
+        let b = *b;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(root, axes, a, b, show_cdf, simulation, &pinned, interval)
+            })
+        })
+    };
+
+    let grid = {
+        let a = *a;
+        let b = *b;
+        Callback::from(move |_| {
+            let dist = DiscreteUniform::new(a.min(b) as i64, a.max(b) as i64);
+            Grid::discrete(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let a = *a;
+        let b = *b;
+        format!("Discrete Uniform: a = {}, b = {}", a.min(b), a.max(b))
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h2>{ "Discrete Uniform Distribution" }</h2>
+            <p>{ "This distribution gives each of the integers a, a+1, ..., b the same probability, like the roll of a fair die." }</p>
+            <p>{ "Probability Function: P(X=k) = 1 / (b - a + 1) for a ≤ k ≤ b" }</p>
+            <div>
+                <label>{ "a (lower bound): " }</label>
+                <input type="range" min="-10" max="10" step="1" value={a.to_string()}
+                oninput={oninput_a} style="width: 70%;" />
+                <span>{ *a }</span>
+            </div>
+            <div>
+                <label>{ "b (upper bound): " }</label>
+                <input type="range" min="-10" max="10" step="1" value={b.to_string()}
+                oninput={oninput_b} style="width: 70%;" />
+                <span>{ *b }</span>
+            </div>
+            <p>{ "Mean: (a+b)/2, Variance: ((b - a + 1)² - 1) / 12" }</p>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<i32> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<i32> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="discrete-uniform" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::discrete(DiscreteUniform::new((*a).min(*b) as i64, (*a).max(*b) as i64)) />
+        </div>
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<i32>,
+    a: i32,
+    b: i32,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<i32>>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = DiscreteUniform::new(a.min(b) as i64, a.max(b) as i64);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, discrete_sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    chart
+        .draw_series(axes.points().map(|x| {
+            let y = if show_cdf {
+                dist.cdf(x as i64)
+            } else {
+                dist.pmf(x as i64)
+            };
+            Rectangle::new([(x, 0.0), (x + 1, y)], GREEN.filled())
+        }))
+        .unwrap()
+        .label(format!("a = {}, b = {}", a.min(b), a.max(b)))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], GREEN));
+
+    if let Some(draws) = &draws {
+        draw_frequencies(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(interval) = interval {
+        shade_discrete(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_discrete_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
@@ -1,3 +1,17 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_discrete, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_discrete_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    discrete_sample_caption, draw_frequencies, draw_labelled_frequencies, Simulation,
+    SimulationControls,
+};
+use crate::stats::discrete::Geometric;
+use crate::stats::{Discrete, Rng, Sample};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
 use web_sys::HtmlCanvasElement;
@@ -5,11 +19,22 @@ use yew::prelude::*;
 
 #[function_component(GeometricDistribution)]
 pub fn geometric_distribution() -> Html {
-    let p = use_state(|| 0.5);
+    let p = use_query_state_in("geometric", "p", 0.5, 0.01..=1.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<i32>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<i32>>);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref = use_node_ref();
 
+    let axes = locked_axes.unwrap_or_else(|| Axes::discrete(&Geometric::new(*p), *show_cdf));
+
     {
         let p: f64 = *p;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
         let canvas_ref = canvas_ref.clone();
         use_effect_with_deps(
             move |_| {
@@ -21,45 +46,20 @@ pub fn geometric_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let caption = format!(
-                        "Mean: {:.2}, Variance: {:.2}",
-                        1.0 / p,
-                        (1.0 - p) / p.powi(2)
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        p,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                        None,
                     );
-
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", 20))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(1..20, 0.0..1.0)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    let geometric = |k: i32, p: f64| -> f64 { (1.0 - p).powi(k - 1) * p };
-
-                    chart
-                        .draw_series((1..20).map(|x| {
-                            let y = geometric(x, p);
-                            Rectangle::new([(x, 0.0), (x + 1, y)], BLUE.filled())
-                        }))
-                        .unwrap()
-                        .label(format!("p = {:.2}", p))
-                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
-
-                    chart
-                        .configure_series_labels()
-                        .border_style(BLACK)
-                        .draw()
-                        .unwrap();
                 }
                 || ()
             },
-            p,
+            (axes, p, show_cdf, simulation, (*pins).clone(), interval),
         );
     }
 
@@ -74,6 +74,34 @@ pub fn geometric_distribution() -> Html {
         })
     };
 
+    let probability = interval.map(|interval| interval.probability(&Geometric::new(*p)));
+
+    let render_svg = {
+        let p = *p;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(root, axes, p, show_cdf, simulation, &pinned, interval, None)
+            })
+        })
+    };
+
+    let grid = {
+        let p = *p;
+        Callback::from(move |_| {
+            let dist = Geometric::new(p);
+            Grid::discrete(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let p = *p;
+        format!("Geometric: p = {:.2}", p)
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto;">
             <h2>{ "Geometric Distribution" }</h2>
@@ -86,7 +114,83 @@ pub fn geometric_distribution() -> Html {
                 <span>{ format!("{:.2}", *p) }</span>
             </div>
             <p> {"Mean: 1/p, Variance: (1-p)/p²"} </p>
-            <canvas id="plot" ref={canvas_ref} style="width: 100%; height: auto;"></canvas>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<i32> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<i32> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="geometric" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::discrete(Geometric::new(*p)) />
         </div>
     }
 }
+
+/// Probabilities or CDF chart, also drawn by the fitting page over the
+/// frequencies of its `data`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<i32>,
+    p: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<i32>>,
+    data: Option<&[i64]>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Geometric::new(p);
+    let caption = moments_caption(&dist);
+
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, discrete_sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    chart
+        .draw_series(axes.points().map(|x| {
+            let y = if show_cdf {
+                dist.cdf(x as i64)
+            } else {
+                dist.pmf(x as i64)
+            };
+            Rectangle::new([(x, 0.0), (x + 1, y)], BLUE.filled())
+        }))
+        .unwrap()
+        .label(format!("p = {:.2}", p))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+    if let Some(draws) = &draws {
+        draw_frequencies(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(data) = data {
+        let label = format!("Data (n = {})", data.len());
+        draw_labelled_frequencies(&mut chart, data, show_cdf, label);
+    }
+
+    if let Some(interval) = interval {
+        shade_discrete(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_discrete_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
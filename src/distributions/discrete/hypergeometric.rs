@@ -1,3 +1,16 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_discrete, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_discrete_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    discrete_sample_caption, draw_frequencies, Simulation, SimulationControls,
+};
+use crate::stats::discrete::Hypergeometric;
+use crate::stats::{Discrete, Rng, Sample};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
 use web_sys::HtmlCanvasElement;
@@ -9,15 +22,33 @@ const MOBILE_FONT_SIZE: i32 = 10;
 
 #[function_component(HypergeometricDistribution)]
 pub fn hypergeometric_distribution() -> Html {
-    let n = use_state(|| 50); // Total population size
-    let m = use_state(|| 20); // Number of success states in the population
-    let k = use_state(|| 10); // Number of draws
+    // The ranges of M and K are only applied to the query on the first render,
+    // by which point N and M are already clamped
+    let n = use_query_state_in("hypergeometric", "N", 50, 1..=100); // Total population size
+    let m = use_query_state_in("hypergeometric", "M", 20, 0..=*n); // Number of success states in the population
+    let k = use_query_state_in("hypergeometric", "K", 10, 0..=(*n).min(*m)); // Number of draws
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<i32>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<i32>>);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref = use_node_ref();
 
+    let axes = locked_axes.unwrap_or_else(|| {
+        Axes::discrete(
+            &Hypergeometric::new(*n as u64, *m as u64, *k as u64),
+            *show_cdf,
+        )
+    });
+
     {
         let n: i32 = *n;
         let m: i32 = *m;
         let k: i32 = *k;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
         let canvas_ref = canvas_ref.clone();
         use_effect_with_deps(
             move |_| {
@@ -29,49 +60,30 @@ pub fn hypergeometric_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let caption = format!(
-                        "Mean: {:.2}, Variance: {:.2}",
-                        (k * m) as f64 / n as f64,
-                        (k * m * (n - m) * (n - k)) as f64 / (n * n * (n - 1)) as f64
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        n,
+                        m,
+                        k,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
                     );
-
-                    let fontsize = if width > FONT_SIZE_THRESHOLD {
-                        DESKTOP_FONT_SIZE
-                    } else {
-                        MOBILE_FONT_SIZE
-                    };
-
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", fontsize))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(0..k + 1, 0.0..1.0)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    chart
-                        .draw_series((0..=k).map(|x| {
-                            let y = hypergeometric(x, n, m, k);
-                            Rectangle::new([(x, 0.0), (x + 1, y)], GREEN.filled())
-                        }))
-                        .unwrap()
-                        .label(format!("N = {}, M = {}, K = {}", n, m, k))
-                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], GREEN));
-
-                    chart
-                        .configure_series_labels()
-                        .border_style(BLACK)
-                        .draw()
-                        .unwrap();
                 }
                 || ()
             },
-            (n, m, k),
+            (
+                axes,
+                n,
+                m,
+                k,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
         );
     }
 
@@ -128,6 +140,42 @@ pub fn hypergeometric_distribution() -> Html {
         })
     };
 
+    let probability = interval.map(|interval| {
+        interval.probability(&Hypergeometric::new(*n as u64, *m as u64, *k as u64))
+    });
+
+    let render_svg = {
+        let n = *n;
+        let m = *m;
+        let k = *k;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(root, axes, n, m, k, show_cdf, simulation, &pinned, interval)
+            })
+        })
+    };
+
+    let grid = {
+        let n = *n;
+        let m = *m;
+        let k = *k;
+        Callback::from(move |_| {
+            let dist = Hypergeometric::new(n as u64, m as u64, k as u64);
+            Grid::discrete(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let n = *n;
+        let m = *m;
+        let k = *k;
+        format!("Hypergeometric: N = {}, M = {}, K = {}", n, m, k)
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto;">
             <h2>{ "Hypergeometric Distribution" }</h2>
@@ -153,33 +201,84 @@ pub fn hypergeometric_distribution() -> Html {
                 oninput={oninput_k} style="width: 70%; " />
                 <span>{ format!("{}", *k) }</span>
             </div>
-            <canvas id="plot" ref={canvas_ref} style="width: 100%; height: auto;"></canvas>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<i32> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<i32> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="hypergeometric" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::discrete(Hypergeometric::new(*n as u64, *m as u64, *k as u64)) />
         </div>
     }
 }
 
-fn hypergeometric(x: i32, n: i32, m: i32, k: i32) -> f64 {
-    let log_comb = |n: i32, k: i32| -> f64 {
-        if k == 0 || k == n {
-            return 0.0;
-        }
-        let log_numerator = (n - k + 1..=n).fold(0.0, |acc, x| acc + (x as f64).ln());
-        let log_denominator = (1..=k).fold(0.0, |acc, x| acc + (x as f64).ln());
-        log_numerator - log_denominator
+#[allow(clippy::too_many_arguments)]
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<i32>,
+    n: i32,
+    m: i32,
+    k: i32,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<i32>>,
+) {
+    root.fill(&WHITE).unwrap();
+    let width = root.dim_in_pixel().0 as i32;
+
+    let dist = Hypergeometric::new(n as u64, m as u64, k as u64);
+    let caption = moments_caption(&dist);
+
+    let fontsize = if width > FONT_SIZE_THRESHOLD {
+        DESKTOP_FONT_SIZE
+    } else {
+        MOBILE_FONT_SIZE
     };
 
-    let log_prob: f64 = log_comb(m, x) + log_comb(n - m, k - x) - log_comb(n, k);
-    log_prob.exp()
-}
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, discrete_sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", fontsize))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    chart.configure_mesh().draw().unwrap();
 
-    #[test]
-    fn test_hypergeometric() {
-        // Test edge cases
-        assert!((hypergeometric(0, 1, 1, 1) - 0.0).abs() < 1e-6);
-        assert!((hypergeometric(1, 1, 1, 1) - 1.0).abs() < 1e-6);
+    chart
+        .draw_series(axes.points().map(|x| {
+            let y = if show_cdf {
+                dist.cdf(x as i64)
+            } else {
+                dist.pmf(x as i64)
+            };
+            Rectangle::new([(x, 0.0), (x + 1, y)], GREEN.filled())
+        }))
+        .unwrap()
+        .label(format!("N = {}, M = {}, K = {}", n, m, k))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], GREEN));
+
+    if let Some(draws) = &draws {
+        draw_frequencies(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(interval) = interval {
+        shade_discrete(&mut chart, &dist, interval, show_cdf);
     }
+
+    draw_discrete_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
 }
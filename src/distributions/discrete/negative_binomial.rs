@@ -1,3 +1,17 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_discrete, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_discrete_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    discrete_sample_caption, draw_frequencies, draw_labelled_frequencies, Simulation,
+    SimulationControls,
+};
+use crate::stats::discrete::NegativeBinomial;
+use crate::stats::{Discrete, Rng, Sample};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
 use web_sys::HtmlCanvasElement;
@@ -5,13 +19,25 @@ use yew::prelude::*;
 
 #[function_component(NegativeBinomialDistribution)]
 pub fn negative_binomial_distribution() -> Html {
-    let p = use_state(|| 0.5);
-    let r = use_state(|| 3);
+    let p = use_query_state_in("negative-binomial", "p", 0.5, 0.01..=0.99);
+    let r = use_query_state_in("negative-binomial", "r", 3, 1..=10);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<i32>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<i32>>);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref = use_node_ref();
 
+    let axes = locked_axes
+        .unwrap_or_else(|| Axes::discrete(&NegativeBinomial::new(*r as f64, *p), *show_cdf));
+
     {
         let p: f64 = *p;
         let r: i32 = *r;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
         let canvas_ref = canvas_ref.clone();
         use_effect_with_deps(
             move |_| {
@@ -23,43 +49,21 @@ pub fn negative_binomial_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let caption = format!(
-                        "Mean: {:.2}, Variance: {:.2}",
-                        r as f64 / p,
-                        r as f64 * (1.0 - p) / p.powi(2)
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        p,
+                        r as f64,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                        None,
                     );
-
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", 20))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(0..50, 0.0..0.3)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    chart
-                        .draw_series((0..50).map(|k| {
-                            let y = negative_binomial(k, r, p);
-                            Rectangle::new([(k, 0.0), (k + 1, y)], CYAN.filled())
-                        }))
-                        .unwrap()
-                        .label(format!("p = {:.2}, r = {}", p, r))
-                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], CYAN));
-
-                    chart
-                        .configure_series_labels()
-                        .border_style(BLACK)
-                        .draw()
-                        .unwrap();
                 }
                 || ()
             },
-            (p, r),
+            (axes, p, r, show_cdf, simulation, (*pins).clone(), interval),
         );
     }
 
@@ -85,10 +89,44 @@ pub fn negative_binomial_distribution() -> Html {
         })
     };
 
+    let probability =
+        interval.map(|interval| interval.probability(&NegativeBinomial::new(*r as f64, *p)));
+
+    let render_svg = {
+        let p = *p;
+        let r = *r;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root, axes, p, r as f64, show_cdf, simulation, &pinned, interval, None,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let p = *p;
+        let r = *r;
+        Callback::from(move |_| {
+            let dist = NegativeBinomial::new(r as f64, p);
+            Grid::discrete(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let p = *p;
+        let r = *r;
+        format!("Negative Binomial: p = {:.2}, r = {}", p, r)
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto;">
             <h2>{ "Negative Binomial Distribution" }</h2>
-            <p> {"This models the number of trials needed to achieve a specified number of successes."} </p>
+            <p> {"This models the number of failures before a specified number of successes is achieved."} </p>
             <p> {"Probability Function: P(X=k) = C(r+k-1, k) * (1-p)^k * p^r"} </p>
             <div>
                 <label>{ "p: " }</label>
@@ -102,45 +140,85 @@ pub fn negative_binomial_distribution() -> Html {
                 oninput={oninput_r} style="width: 70%; " />
                 <span>{ format!("{}", *r) }</span>
             </div>
-            <p> {"Mean: r/p, Variance: r(1-p)/p²"} </p>
-            <canvas id="plot" ref={canvas_ref} style="width: 100%; height: auto;"></canvas>
+            <p> {"Mean: r(1-p)/p, Variance: r(1-p)/p²"} </p>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<i32> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<i32> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="negative-binomial" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::discrete(NegativeBinomial::new(*r as f64, *p)) />
         </div>
     }
 }
 
-fn negative_binomial(k: i32, r: i32, p: f64) -> f64 {
-    // Calculate the natural logarithm of the combination to prevent overflow
-    let log_comb = |n: i32, k: i32| -> f64 {
-        if k == 0 || k == n {
-            return 0.0;
-        }
-        let log_numerator = (n - k + 1..=n).fold(0.0, |acc, x| acc + (x as f64).ln());
-        let log_denominator = (1..=k).fold(0.0, |acc, x| acc + (x as f64).ln());
-        log_numerator - log_denominator
+/// Probabilities or CDF chart, also drawn by the fitting page over the
+/// frequencies of its `data`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<i32>,
+    p: f64,
+    r: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<i32>>,
+    data: Option<&[i64]>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = NegativeBinomial::new(r, p);
+    let caption = moments_caption(&dist);
+
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, discrete_sample_caption(draws)),
+        None => caption,
     };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    chart
+        .draw_series(axes.points().map(|k| {
+            let y = if show_cdf {
+                dist.cdf(k as i64)
+            } else {
+                dist.pmf(k as i64)
+            };
+            Rectangle::new([(k, 0.0), (k + 1, y)], CYAN.filled())
+        }))
+        .unwrap()
+        .label(format!("p = {:.2}, r = {}", p, r))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], CYAN));
+
+    if let Some(draws) = &draws {
+        draw_frequencies(&mut chart, draws, show_cdf);
+    }
 
-    // Use the log_comb to calculate the probability
-    let log_prob: f64 = log_comb(r + k - 1, k) + (k as f64) * (1.0 - p).ln() + (r as f64) * p.ln();
-
-    log_prob.exp() // Convert back from log space
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+    if let Some(data) = data {
+        let label = format!("Data (n = {})", data.len());
+        draw_labelled_frequencies(&mut chart, data, show_cdf, label);
+    }
 
-    #[test]
-    fn test_negative_binomial() {
-        // Too Simple test
-        assert!((negative_binomial(0, 1, 0.5) < 1.0));
+    if let Some(interval) = interval {
+        shade_discrete(&mut chart, &dist, interval, show_cdf);
+    }
 
-        // Test with known values
-        assert!((negative_binomial(2, 3, 0.5) - (3.0 / 16.0 as f64)).abs() < 1e-6);
-        assert!((negative_binomial(1, 3, 0.5) - 0.1875).abs() < 1e-6);
+    draw_discrete_pins(&mut chart, &axes, pins, show_cdf);
 
-        // Test edge cases
-        assert!((negative_binomial(0, 1, 0.5) - 0.5).abs() < 1e-6);
-        assert!((negative_binomial(0, 0, 0.5) - 1.0).abs() < 1e-6);
-        assert!((negative_binomial(0, 1, 0.99) - 1.0).abs() < 0.1);
-    }
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
 }
@@ -1,3 +1,17 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_discrete, Interval, IntervalControls};
+use crate::distributions::moments::{MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_discrete_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    discrete_sample_caption, draw_frequencies, draw_labelled_frequencies, Simulation,
+    SimulationControls,
+};
+use crate::stats::discrete::Poisson;
+use crate::stats::{Discrete, Rng, Sample};
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
 use web_sys::HtmlCanvasElement;
@@ -5,11 +19,22 @@ use yew::prelude::*;
 
 #[function_component(PoissonDistribution)]
 pub fn poisson_distribution() -> Html {
-    let lambda = use_state(|| 1.0);
+    let lambda = use_query_state_in("poisson", "lambda", 1.0, 0.01..=20.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<i32>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<i32>>);
+    let simulation = use_state(|| None::<Simulation>);
     let canvas_ref = use_node_ref();
 
+    let axes = locked_axes.unwrap_or_else(|| Axes::discrete(&Poisson::new(*lambda), *show_cdf));
+
     {
         let lambda = *lambda;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
         let canvas_ref = canvas_ref.clone();
         use_effect_with_deps(
             move |_| {
@@ -22,46 +47,27 @@ pub fn poisson_distribution() -> Html {
                     canvas.set_height(height as u32);
 
                     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
-                    let root = backend.into_drawing_area();
-                    root.fill(&WHITE).unwrap();
-
-                    let caption = format!("Mean and Variance: {:.2}", lambda);
-                    let mut chart = ChartBuilder::on(&root)
-                        .margin(10)
-                        .caption(caption, ("sans-serif", 20))
-                        .x_label_area_size(30)
-                        .y_label_area_size(30)
-                        .build_cartesian_2d(0..20, 0.0..1.0)
-                        .unwrap();
-
-                    chart.configure_mesh().draw().unwrap();
-
-                    let poisson = |k: i32, lambda: f64| -> f64 {
-                        let mut log_factorial = 0.0;
-                        for i in 1..=k {
-                            log_factorial += (i as f64).ln();
-                        }
-                        (-lambda + k as f64 * lambda.ln() - log_factorial).exp()
-                    };
-
-                    chart
-                        .draw_series((0..20).map(|x| {
-                            let y = poisson(x, lambda);
-                            Rectangle::new([(x, 0.0), (x + 1, y)], RED.filled())
-                        }))
-                        .unwrap()
-                        .label(format!("λ = {}", lambda))
-                        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
-
-                    chart
-                        .configure_series_labels()
-                        .border_style(BLACK)
-                        .draw()
-                        .unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        lambda,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                        None,
+                    );
                 }
                 || ()
             },
-            lambda,
+            (
+                axes,
+                lambda,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
         );
     }
 
@@ -76,6 +82,36 @@ pub fn poisson_distribution() -> Html {
         })
     };
 
+    let probability = interval.map(|interval| interval.probability(&Poisson::new(*lambda)));
+
+    let render_svg = {
+        let lambda = *lambda;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root, axes, lambda, show_cdf, simulation, &pinned, interval, None,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let lambda = *lambda;
+        Callback::from(move |_| {
+            let dist = Poisson::new(lambda);
+            Grid::discrete(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let lambda = *lambda;
+        format!("Poisson: λ = {}", lambda)
+    };
+
     html! {
         <div style="width: 100%; max-width: 800px; margin: auto;">
             <h2>{ "Poisson Distribution" }</h2>
@@ -88,7 +124,82 @@ pub fn poisson_distribution() -> Html {
                 <span>{ format!("{:.2}", *lambda) }</span>
             </div>
             <p> {"Mean and Variance: λ"} </p>
-            <canvas id="plot" ref={canvas_ref} style="width: 100%; height: auto;"></canvas>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<i32> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<i32> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="poisson" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::discrete(Poisson::new(*lambda)) />
         </div>
     }
 }
+
+/// Probabilities or CDF chart, also drawn by the fitting page over the
+/// frequencies of its `data`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<i32>,
+    lambda: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<i32>>,
+    data: Option<&[i64]>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Poisson::new(lambda);
+    let caption = format!("Mean and Variance: {:.2}", lambda);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, discrete_sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    chart
+        .draw_series(axes.points().map(|x| {
+            let y = if show_cdf {
+                dist.cdf(x as i64)
+            } else {
+                dist.pmf(x as i64)
+            };
+            Rectangle::new([(x, 0.0), (x + 1, y)], RED.filled())
+        }))
+        .unwrap()
+        .label(format!("λ = {}", lambda))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    if let Some(draws) = &draws {
+        draw_frequencies(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(data) = data {
+        let label = format!("Data (n = {})", data.len());
+        draw_labelled_frequencies(&mut chart, data, show_cdf, label);
+    }
+
+    if let Some(interval) = interval {
+        shade_discrete(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_discrete_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
@@ -0,0 +1,220 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_discrete, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_discrete_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    discrete_sample_caption, draw_frequencies, Simulation, SimulationControls,
+};
+use crate::stats::discrete::Skellam;
+use crate::stats::{Discrete, Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+#[function_component(SkellamDistribution)]
+pub fn skellam_distribution() -> Html {
+    let mu1 = use_query_state_in("skellam", "mu1", 3.0, 0.01..=10.0);
+    let mu2 = use_query_state_in("skellam", "mu2", 1.0, 0.01..=10.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<i32>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<i32>>);
+    let simulation = use_state(|| None::<Simulation>);
+    let canvas_ref = use_node_ref();
+
+    let axes = locked_axes.unwrap_or_else(|| Axes::discrete(&Skellam::new(*mu1, *mu2), *show_cdf));
+
+    {
+        let mu1 = *mu1;
+        let mu2 = *mu2;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        mu1,
+                        mu2,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                    );
+                }
+                || ()
+            },
+            (
+                axes,
+                mu1,
+                mu2,
+                show_cdf,
+                simulation,
+                (*pins).clone(),
+                interval,
+            ),
+        );
+    }
+
+    let oninput_mu1 = {
+        let mu1 = mu1.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    mu1.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_mu2 = {
+        let mu2 = mu2.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    mu2.set(value);
+                }
+            }
+        })
+    };
+
+    let probability = interval.map(|interval| interval.probability(&Skellam::new(*mu1, *mu2)));
+
+    let render_svg = {
+        let mu1 = *mu1;
+        let mu2 = *mu2;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(
+                    root, axes, mu1, mu2, show_cdf, simulation, &pinned, interval,
+                )
+            })
+        })
+    };
+
+    let grid = {
+        let mu1 = *mu1;
+        let mu2 = *mu2;
+        Callback::from(move |_| {
+            let dist = Skellam::new(mu1, mu2);
+            Grid::discrete(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let mu1 = *mu1;
+        let mu2 = *mu2;
+        format!("Skellam: μ₁ = {:.2}, μ₂ = {:.2}", mu1, mu2)
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h2>{ "Skellam Distribution" }</h2>
+            <p>{ "This distribution models the difference of two independent Poisson counts, such as the goal difference in a football match." }</p>
+            <p>{ "Probability Function: P(X=k) = e^(-(μ₁+μ₂)) * (μ₁/μ₂)^(k/2) * I_|k|(2√(μ₁μ₂))" }</p>
+            <div>
+                <label>{ "μ₁: " }</label>
+                <input type="range" min="0.01" max="10" step="0.01" value={mu1.to_string()}
+                oninput={oninput_mu1} style="width: 70%;" />
+                <span>{ format!("{:.2}", *mu1) }</span>
+            </div>
+            <div>
+                <label>{ "μ₂: " }</label>
+                <input type="range" min="0.01" max="10" step="0.01" value={mu2.to_string()}
+                oninput={oninput_mu2} style="width: 70%;" />
+                <span>{ format!("{:.2}", *mu2) }</span>
+            </div>
+            <p>{ "Mean: μ₁ - μ₂, Variance: μ₁ + μ₂" }</p>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<i32> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<i32> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="skellam" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::discrete(Skellam::new(*mu1, *mu2)) />
+        </div>
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<i32>,
+    mu1: f64,
+    mu2: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<i32>>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Skellam::new(mu1, mu2);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, discrete_sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    chart
+        .draw_series(axes.points().map(|x| {
+            let y = if show_cdf {
+                dist.cdf(x as i64)
+            } else {
+                dist.pmf(x as i64)
+            };
+            Rectangle::new([(x, 0.0), (x + 1, y)], RED.filled())
+        }))
+        .unwrap()
+        .label(format!("μ₁ = {:.2}, μ₂ = {:.2}", mu1, mu2))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    if let Some(draws) = &draws {
+        draw_frequencies(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(interval) = interval {
+        shade_discrete(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_discrete_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
@@ -0,0 +1,210 @@
+use crate::distributions::axes::Axes;
+use crate::distributions::controls::{AxesLock, CdfToggle};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::interval::{shade_discrete, Interval, IntervalControls};
+use crate::distributions::moments::{moments_caption, MomentsPanel, MomentsPanelProps};
+use crate::distributions::pins::{draw_discrete_pins, use_pins, PinControls, PinnedSeries};
+use crate::distributions::query::use_query_state_in;
+use crate::distributions::simulation::{
+    discrete_sample_caption, draw_frequencies, Simulation, SimulationControls,
+};
+use crate::stats::discrete::Zipf;
+use crate::stats::{Discrete, Rng, Sample};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+#[function_component(ZipfDistribution)]
+pub fn zipf_distribution() -> Html {
+    let n = use_query_state_in("zipf", "N", 10, 1..=50);
+    let s = use_query_state_in("zipf", "s", 1.0, 0.0..=4.0);
+    let show_cdf = use_state(|| false);
+    let locked_axes = use_state(|| None::<Axes<i32>>);
+    let pins = use_pins();
+    let interval = use_state(|| None::<Interval<i32>>);
+    let simulation = use_state(|| None::<Simulation>);
+    let canvas_ref = use_node_ref();
+
+    let axes = locked_axes.unwrap_or_else(|| Axes::discrete(&Zipf::new(*n as u64, *s), *show_cdf));
+
+    {
+        let n = *n;
+        let s = *s;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    draw_chart(
+                        &backend.into_drawing_area(),
+                        axes,
+                        n,
+                        s,
+                        show_cdf,
+                        simulation,
+                        &pinned,
+                        interval,
+                    );
+                }
+                || ()
+            },
+            (axes, n, s, show_cdf, simulation, (*pins).clone(), interval),
+        );
+    }
+
+    let oninput_n = {
+        let n = n.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<i32>() {
+                    n.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_s = {
+        let s = s.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    s.set(value);
+                }
+            }
+        })
+    };
+
+    let probability = interval.map(|interval| interval.probability(&Zipf::new(*n as u64, *s)));
+
+    let render_svg = {
+        let n = *n;
+        let s = *s;
+        let show_cdf = *show_cdf;
+        let simulation = *simulation;
+        let pinned = (*pins).clone();
+        let interval = *interval;
+        Callback::from(move |size| {
+            draw_svg(size, |root| {
+                draw_chart(root, axes, n, s, show_cdf, simulation, &pinned, interval)
+            })
+        })
+    };
+
+    let grid = {
+        let n = *n;
+        let s = *s;
+        Callback::from(move |_| {
+            let dist = Zipf::new(n as u64, s);
+            Grid::discrete(&dist, axes.points())
+        })
+    };
+
+    let pin_label = {
+        let n = *n;
+        let s = *s;
+        format!("Zipf: N = {}, s = {:.2}", n, s)
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h2>{ "Zipf Distribution" }</h2>
+            <p>{ "This distribution models ranked data such as word frequencies, where the k-th most common item is proportional to 1/k^s. As N grows with s > 1 it becomes the heavy-tailed zeta distribution." }</p>
+            <p>{ "Probability Function: P(X=k) = (1/k^s) / H(N,s), H(N,s) = Σ_{i=1}^N 1/i^s" }</p>
+            <div>
+                <label>{ "N (number of ranks): " }</label>
+                <input type="range" min="1" max="50" step="1" value={n.to_string()}
+                oninput={oninput_n} style="width: 70%;" />
+                <span>{ *n }</span>
+            </div>
+            <div>
+                <label>{ "s (exponent): " }</label>
+                <input type="range" min="0" max="4" step="0.01" value={s.to_string()}
+                oninput={oninput_s} style="width: 70%;" />
+                <span>{ format!("{:.2}", *s) }</span>
+            </div>
+            <p>{ "Mean: H(N,s-1) / H(N,s), Variance: H(N,s-2) / H(N,s) - Mean²" }</p>
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <AxesLock<i32> locked={locked_axes.clone()} {axes} />
+            <PinControls pins={pins.clone()} label={pin_label} grid={grid.clone()} />
+            <IntervalControls<i32> interval={interval.clone()} {axes} {probability} />
+            <SimulationControls simulation={simulation.clone()} />
+            <canvas id="plot" ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="zipf" {canvas_ref} {render_svg} {grid} />
+            <MomentsPanel ..MomentsPanelProps::discrete(Zipf::new(*n as u64, *s)) />
+        </div>
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    axes: Axes<i32>,
+    n: i32,
+    s: f64,
+    show_cdf: bool,
+    simulation: Option<Simulation>,
+    pins: &[PinnedSeries],
+    interval: Option<Interval<i32>>,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let dist = Zipf::new(n as u64, s);
+    let caption = moments_caption(&dist);
+    let draws =
+        simulation.map(|simulation| dist.sample(&mut Rng::new(simulation.seed), simulation.size));
+    let caption = match &draws {
+        Some(draws) => format!("{} | {}", caption, discrete_sample_caption(draws)),
+        None => caption,
+    };
+    let mut chart = ChartBuilder::on(root)
+        .margin(10)
+        .caption(caption, ("sans-serif", 20))
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(axes.x_range(), axes.y_range())
+        .unwrap();
+
+    chart.configure_mesh().draw().unwrap();
+
+    chart
+        .draw_series(axes.points().map(|x| {
+            let y = if show_cdf {
+                dist.cdf(x as i64)
+            } else {
+                dist.pmf(x as i64)
+            };
+            Rectangle::new([(x, 0.0), (x + 1, y)], BLUE.filled())
+        }))
+        .unwrap()
+        .label(format!("N = {}, s = {:.2}", n, s))
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+    if let Some(draws) = &draws {
+        draw_frequencies(&mut chart, draws, show_cdf);
+    }
+
+    if let Some(interval) = interval {
+        shade_discrete(&mut chart, &dist, interval, show_cdf);
+    }
+
+    draw_discrete_pins(&mut chart, &axes, pins, show_cdf);
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .draw()
+        .unwrap();
+}
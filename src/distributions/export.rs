@@ -0,0 +1,228 @@
+//! Downloading charts as PNG or SVG images, and their data as CSV or JSON.
+//!
+//! PNGs are snapshots of the canvas. SVGs are drawn again from scratch
+//! through plotters' SVG backend, which is why the components keep their
+//! drawing code generic over the backend. The data is the distribution
+//! evaluated at the same points the chart plots.
+
+use crate::stats::{Continuous, Discrete};
+use gloo_timers::callback::Timeout;
+use js_sys::Array;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use serde::Serialize;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlCanvasElement, HtmlElement, Url};
+use yew::prelude::*;
+
+/// Draws a chart of `size` pixels into an SVG document.
+pub fn draw_svg(size: (u32, u32), draw: impl FnOnce(&DrawingArea<SVGBackend, Shift>)) -> String {
+    let mut svg = String::new();
+    // The backend writes the document out when the drawing area is dropped
+    draw(&SVGBackend::with_string(&mut svg, size).into_drawing_area());
+    svg
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ContinuousPoint {
+    pub x: f64,
+    pub pdf: f64,
+    pub cdf: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DiscretePoint {
+    pub x: i64,
+    pub pmf: f64,
+    pub cdf: f64,
+}
+
+/// A distribution evaluated at the points of its chart.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Grid {
+    Continuous(Vec<ContinuousPoint>),
+    Discrete(Vec<DiscretePoint>),
+}
+
+impl Grid {
+    pub fn continuous(
+        dist: &(impl Continuous + ?Sized),
+        points: impl IntoIterator<Item = f64>,
+    ) -> Self {
+        Self::Continuous(
+            points
+                .into_iter()
+                .map(|x| ContinuousPoint {
+                    x,
+                    pdf: dist.pdf(x),
+                    cdf: dist.cdf(x),
+                })
+                .collect(),
+        )
+    }
+
+    pub fn discrete(
+        dist: &(impl Discrete + ?Sized),
+        points: impl IntoIterator<Item = i32>,
+    ) -> Self {
+        Self::Discrete(
+            points
+                .into_iter()
+                .map(|k| DiscretePoint {
+                    x: k as i64,
+                    pmf: dist.pmf(k as i64),
+                    cdf: dist.cdf(k as i64),
+                })
+                .collect(),
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        match self {
+            Grid::Continuous(points) => {
+                csv.push_str("x,pdf,cdf\n");
+                for point in points {
+                    csv.push_str(&format!("{},{},{}\n", point.x, point.pdf, point.cdf));
+                }
+            }
+            Grid::Discrete(points) => {
+                csv.push_str("x,pmf,cdf\n");
+                for point in points {
+                    csv.push_str(&format!("{},{},{}\n", point.x, point.pmf, point.cdf));
+                }
+            }
+        }
+        csv
+    }
+
+    /// An array of objects keyed like the CSV columns.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+/// Saves `url` as `file_name` through a temporary link.
+fn download(file_name: &str, url: &str) {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let link: HtmlElement = document.create_element("a").unwrap().unchecked_into();
+    link.set_attribute("href", url).unwrap();
+    link.set_attribute("download", file_name).unwrap();
+    link.click();
+}
+
+/// Saves `text` as `file_name` through an object URL, which unlike a data URL
+/// has no length limit: a full-resolution surface SVG runs to tens of MB.
+fn download_text(file_name: &str, media_type: &str, text: &str) {
+    let parts = Array::of1(&JsValue::from_str(text));
+    let blob =
+        Blob::new_with_str_sequence_and_options(&parts, BlobPropertyBag::new().type_(media_type))
+            .unwrap();
+    let url = Url::create_object_url_with_blob(&blob).unwrap();
+    download(file_name, &url);
+    // Released once the click has been handled and the download started
+    Timeout::new(0, move || {
+        Url::revoke_object_url(&url).ok();
+    })
+    .forget();
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ExportButtonsProps {
+    /// Base name of the downloaded files.
+    pub name: &'static str,
+    pub canvas_ref: NodeRef,
+    /// Draws the chart as SVG at the given size in pixels.
+    pub render_svg: Callback<(u32, u32), String>,
+    /// Evaluates the plotted curve; charts without one export images only.
+    #[prop_or_default]
+    pub grid: Option<Callback<(), Grid>>,
+}
+
+/// Buttons downloading the chart on `canvas_ref` as PNG or SVG, and its
+/// data as CSV or JSON.
+#[function_component(ExportButtons)]
+pub fn export_buttons(props: &ExportButtonsProps) -> Html {
+    let on_png = {
+        let canvas_ref = props.canvas_ref.clone();
+        let name = props.name;
+        Callback::from(move |_| {
+            if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                if let Ok(url) = canvas.to_data_url() {
+                    download(&format!("{}.png", name), &url);
+                }
+            }
+        })
+    };
+
+    let on_svg = {
+        let canvas_ref = props.canvas_ref.clone();
+        let name = props.name;
+        let render_svg = props.render_svg.clone();
+        Callback::from(move |_| {
+            if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                let svg = render_svg.emit((canvas.width(), canvas.height()));
+                download_text(&format!("{}.svg", name), "image/svg+xml", &svg);
+            }
+        })
+    };
+
+    let on_data =
+        |extension: &'static str, media_type: &'static str, format: fn(&Grid) -> String| {
+            let name = props.name;
+            let grid = props.grid.clone();
+            Callback::from(move |_| {
+                if let Some(grid) = &grid {
+                    let text = format(&grid.emit(()));
+                    download_text(&format!("{}.{}", name, extension), media_type, &text);
+                }
+            })
+        };
+
+    html! {
+        <div>
+            <button onclick={on_png}>{ "Export PNG" }</button>
+            { " " }
+            <button onclick={on_svg}>{ "Export SVG" }</button>
+            if props.grid.is_some() {
+                { " " }
+                <button onclick={on_data("csv", "text/csv", Grid::to_csv)}>{ "Export CSV" }</button>
+                { " " }
+                <button onclick={on_data("json", "application/json", Grid::to_json)}>{ "Export JSON" }</button>
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::continuous::Uniform;
+    use crate::stats::discrete::Bernoulli;
+
+    #[test]
+    fn test_grid_formats() {
+        let grid = Grid::discrete(&Bernoulli::new(0.25), 0..2);
+        assert_eq!(grid.to_csv(), "x,pmf,cdf\n0,0.75,0.75\n1,0.25,1\n");
+        let json: serde_json::Value = serde_json::from_str(&grid.to_json()).unwrap();
+        assert_eq!(json[1]["x"], 1);
+        assert_eq!(json[1]["pmf"], 0.25);
+
+        let grid = Grid::continuous(&Uniform::new(0.0, 2.0), [0.5, 1.0]);
+        assert_eq!(grid.to_csv(), "x,pdf,cdf\n0.5,0.5,0.25\n1,0.5,0.5\n");
+        let json: serde_json::Value = serde_json::from_str(&grid.to_json()).unwrap();
+        assert_eq!(json[0]["cdf"], 0.25);
+    }
+
+    #[test]
+    fn test_draw_svg_produces_document() {
+        let svg = draw_svg((200, 100), |root| {
+            root.fill(&WHITE).unwrap();
+            root.draw(&Circle::new((50, 50), 10, RED.filled())).unwrap();
+        });
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("width=\"200\""));
+        assert!(svg.contains("<circle"));
+    }
+}
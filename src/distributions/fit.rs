@@ -0,0 +1,502 @@
+//! Maximum-likelihood fitting of pasted or uploaded data.
+//!
+//! Every applicable family is fitted and ranked by AIC, continuous and discrete
+//! families separately; the selected fit is drawn over the data the way
+//! simulated draws are drawn on the distribution charts, followed by
+//! goodness-of-fit diagnostics.
+
+use crate::distributions::axes::Axes;
+use crate::distributions::continuous::{
+    beta, cauchy, exponential, gamma, gumbel, laplace, log_normal, logistic, normal, weibull,
+};
+use crate::distributions::controls::CdfToggle;
+use crate::distributions::diagnostics::Diagnostics;
+use crate::distributions::discrete::{geometric, negative_binomial, poisson};
+use crate::distributions::export::{draw_svg, ExportButtons, Grid};
+use crate::distributions::input::{parse_numbers, use_debounced};
+use crate::distributions::moments::format_moment;
+use crate::distributions::simulation::HISTOGRAM_BINS;
+use crate::stats::descriptive::{frequencies, histogram};
+use crate::stats::fit::{are_counts, fit_all, Family, Fit};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{HtmlCanvasElement, HtmlInputElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+const DEFAULT_DATA: &str = "2.03, 3.14, 0.87, 4.35, 1.62, 1.89, 9.95, 2.52, 7.14, 5.13, \
+4.06, 3.76, 4.47, 12.80, 4.08, 5.04, 1.54, 0.70, 2.32, 3.74, 3.26, 5.47, 2.84, 2.59, 0.83, \
+7.91, 2.04, 1.65, 0.67, 13.86, 7.21, 0.40, 3.25, 5.31, 2.41, 3.51, 3.03, 0.68, 8.41, 2.39";
+
+/// Data the fit gives less probability than this beyond are left off the chart.
+const OUTLIER_QUANTILE: f64 = 1e-6;
+
+/// Parsed data with its fits, best first.
+type Fitted = Result<(Vec<f64>, Vec<Fit>), String>;
+
+fn fit_text(text: &str) -> Fitted {
+    let data = parse_numbers(text)?;
+    if data.len() < 2 {
+        return Err("Enter at least two numbers".to_string());
+    }
+    let fits = fit_all(&data);
+    if fits.is_empty() {
+        return Err("No family can describe these data".to_string());
+    }
+    Ok((data, fits))
+}
+
+/// The best discrete fit for counts, otherwise the best continuous one.
+fn preferred<'a>(data: &[f64], fits: &'a [Fit]) -> Option<&'a Fit> {
+    let counts = are_counts(data);
+    fits.iter()
+        .find(|fit| fit.family.is_discrete() == counts)
+        .or(fits.first())
+}
+
+/// Estimates with their standard errors, such as "λ = 0.25 ± 0.04".
+fn format_parameters(fit: &Fit) -> String {
+    fit.family
+        .parameter_names()
+        .iter()
+        .zip(fit.parameters.iter().zip(&fit.standard_errors))
+        .map(|(name, (estimate, se))| {
+            format!(
+                "{} = {} ± {}",
+                name,
+                format_moment(*estimate),
+                format_moment(*se)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[function_component(Fitting)]
+pub fn fitting() -> Html {
+    let text = use_state(|| DEFAULT_DATA.to_string());
+    let selected = use_state(|| None::<Family>);
+    let show_cdf = use_state(|| false);
+    let canvas_ref = use_node_ref();
+
+    // Fitted once typing or pasting pauses, not on every keystroke
+    let settled = use_debounced((*text).clone());
+    let fitted = use_memo(|text| fit_text(text), settled);
+    // The selected family while it still fits, otherwise the preferred one
+    let fit = match &*fitted {
+        Ok((data, fits)) => fits
+            .iter()
+            .find(|fit| Some(fit.family) == *selected)
+            .or_else(|| preferred(data, fits))
+            .cloned(),
+        Err(_) => None,
+    };
+
+    {
+        let fitted = fitted.clone();
+        let drawn = fit.clone();
+        let show_cdf = *show_cdf;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    let root = backend.into_drawing_area();
+                    match (&*fitted, &drawn) {
+                        (Ok((data, _)), Some(fit)) => draw_chart(&root, data, fit, show_cdf),
+                        _ => root.fill(&WHITE).unwrap(),
+                    }
+                }
+                || ()
+            },
+            (fit.clone(), show_cdf),
+        );
+    }
+
+    let oninput_text = {
+        let text = text.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlTextAreaElement>() {
+                text.set(input.value());
+            }
+        })
+    };
+
+    let onchange_file = {
+        let text = text.clone();
+        Callback::from(move |e: Event| {
+            let Some(input) = e.target_dyn_into::<HtmlInputElement>() else {
+                return;
+            };
+            if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                let text = text.clone();
+                spawn_local(async move {
+                    if let Some(contents) = JsFuture::from(file.text())
+                        .await
+                        .ok()
+                        .and_then(|value| value.as_string())
+                    {
+                        text.set(contents);
+                    }
+                });
+            }
+        })
+    };
+
+    let onchange_family = |family: Family| {
+        let selected = selected.clone();
+        Callback::from(move |_: Event| selected.set(Some(family)))
+    };
+
+    let render_svg = {
+        let fitted = fitted.clone();
+        let fit = fit.clone();
+        let show_cdf = *show_cdf;
+        Callback::from(move |size| {
+            draw_svg(size, |root| match (&*fitted, &fit) {
+                (Ok((data, _)), Some(fit)) => draw_chart(root, data, fit, show_cdf),
+                _ => root.fill(&WHITE).unwrap(),
+            })
+        })
+    };
+
+    let grid = match (&*fitted, &fit) {
+        (Ok((data, _)), Some(fit)) => {
+            let data = data.clone();
+            let fit = fit.clone();
+            let show_cdf = *show_cdf;
+            Some(Callback::from(move |_| {
+                match fit.family.continuous(&fit.parameters) {
+                    Some(dist) => Grid::continuous(
+                        dist.as_ref(),
+                        continuous_axes(&data, &fit, show_cdf).points(),
+                    ),
+                    None => {
+                        let dist = fit.family.discrete(&fit.parameters).unwrap();
+                        Grid::discrete(dist.as_ref(), discrete_axes(&data, &fit, show_cdf).points())
+                    }
+                }
+            }))
+        }
+        _ => None,
+    };
+
+    let table = |title: &str, rows: Vec<&Fit>| {
+        if rows.is_empty() {
+            return html! {};
+        }
+        html! {
+            <>
+                <h3>{ title }</h3>
+                <table style="width: 100%;">
+                    <tr>
+                        <th></th>
+                        <th>{ "Family" }</th>
+                        <th>{ "Estimates" }</th>
+                        <th>{ "Log-likelihood" }</th>
+                        <th>{ "AIC" }</th>
+                        <th>{ "BIC" }</th>
+                    </tr>
+                    { for rows.into_iter().map(|row| html! {
+                        <tr>
+                            <td>
+                                <input type="radio" name="fit-family"
+                                checked={fit.as_ref().map(|fit| fit.family) == Some(row.family)}
+                                onchange={onchange_family(row.family)} />
+                            </td>
+                            <td>{ row.family.name() }</td>
+                            <td>{ format_parameters(row) }</td>
+                            <td>{ format!("{:.2}", row.log_likelihood) }</td>
+                            <td>{ format!("{:.2}", row.aic) }</td>
+                            <td>{ format!("{:.2}", row.bic) }</td>
+                        </tr>
+                    }) }
+                </table>
+            </>
+        }
+    };
+
+    let results = match &*fitted {
+        Ok((data, fits)) => html! {
+            <>
+                <p>
+                    { format!(
+                        "{} {} values; each group ranked by AIC (lower is better), estimates ± standard errors",
+                        data.len(),
+                        if are_counts(data) { "count" } else { "continuous" },
+                    ) }
+                </p>
+                { table(
+                    "Continuous families",
+                    fits.iter().filter(|fit| !fit.family.is_discrete()).collect(),
+                ) }
+                { table(
+                    "Discrete families",
+                    fits.iter().filter(|fit| fit.family.is_discrete()).collect(),
+                ) }
+            </>
+        },
+        Err(message) => html! { <p style="color: red;">{ message }</p> },
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h1>{ "Fit a Distribution" }</h1>
+            <p>
+                { "Paste a column of numbers or upload a text or CSV file. Every continuous family is fitted, and counts (non-negative integers) by the discrete families as well. Densities and probabilities are not comparable, so the two groups are ranked separately." }
+            </p>
+            <textarea rows="5" style="width: 100%;" value={(*text).clone()}
+            oninput={oninput_text} />
+            <div>
+                <label>{ "Upload: " }</label>
+                <input type="file" accept=".txt,.csv" onchange={onchange_file} />
+            </div>
+            { results }
+            <CdfToggle show_cdf={show_cdf.clone()} />
+            <canvas ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="fit" {canvas_ref} {render_svg} {grid} />
+            if let (Ok((data, _)), Some(fit)) = (&*fitted, fit) {
+                <Diagnostics data={data.clone()} {fit} />
+            }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct FamilyPickerProps {
+    /// Name of the radio group, unique on the page.
+    pub name: &'static str,
+    pub families: Vec<Family>,
+    pub family: UseStateHandle<Family>,
+    /// Parameters of `family`, in the order of its constructor.
+    pub parameters: UseStateHandle<Vec<f64>>,
+}
+
+/// Radio buttons choosing a family and number inputs for its parameters.
+///
+/// Switching family starts from its default parameters; values the family
+/// does not accept are ignored.
+#[function_component(FamilyPicker)]
+pub fn family_picker(props: &FamilyPickerProps) -> Html {
+    let onchange_family = |option: Family| {
+        let family = props.family.clone();
+        let parameters = props.parameters.clone();
+        Callback::from(move |_: Event| {
+            family.set(option);
+            parameters.set(option.default_parameters());
+        })
+    };
+
+    let oninput_parameter = |i: usize| {
+        let family = *props.family;
+        let parameters = props.parameters.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    let mut updated = (*parameters).clone();
+                    updated[i] = value;
+                    if family.accepts(&updated) {
+                        parameters.set(updated);
+                    }
+                }
+            }
+        })
+    };
+
+    html! {
+        <div>
+            <div>
+                { for props.families.iter().map(|&option| html! {
+                    <label>
+                        <input type="radio" name={props.name} checked={*props.family == option}
+                        onchange={onchange_family(option)} />
+                        { format!(" {} ", option.name()) }
+                    </label>
+                }) }
+            </div>
+            <div>
+                { for props.family.parameter_names().iter().zip(props.parameters.iter()).enumerate().map(|(i, (name, value))| html! {
+                    <>
+                        <label>{ format!(" {}: ", name) }</label>
+                        <input type="number" step="any" value={value.to_string()}
+                        oninput={oninput_parameter(i)} style="width: 6em;" />
+                    </>
+                }) }
+            </div>
+        </div>
+    }
+}
+
+/// Axes of the fitted density widened to all of the data.
+fn continuous_axes(data: &[f64], fit: &Fit, show_cdf: bool) -> Axes<f64> {
+    let dist = fit.family.continuous(&fit.parameters).unwrap();
+    let mut axes = Axes::continuous(dist.as_ref(), show_cdf);
+    axes.x_min = data.iter().copied().fold(axes.x_min, f64::min);
+    axes.x_max = data.iter().copied().fold(axes.x_max, f64::max);
+    if !show_cdf {
+        let bins = histogram(data, axes.x_min, axes.x_max, HISTOGRAM_BINS);
+        axes.y_max = bins
+            .iter()
+            .map(|bin| bin.density * 1.1)
+            .fold(axes.y_max, f64::max);
+    }
+    axes
+}
+
+/// Axes of the fitted probabilities widened to the data, short of outliers the
+/// fit gives less than `OUTLIER_QUANTILE` of its mass beyond.
+fn discrete_axes(data: &[f64], fit: &Fit, show_cdf: bool) -> Axes<i32> {
+    let dist = fit.family.discrete(&fit.parameters).unwrap();
+    let counts = frequencies(&data.iter().map(|&x| x as i64).collect::<Vec<_>>());
+    let (Some(&first), Some(&last)) = (counts.keys().next(), counts.keys().next_back()) else {
+        return Axes::discrete(dist.as_ref(), show_cdf);
+    };
+    let mut axes = Axes::discrete_covering(
+        dist.as_ref(),
+        first.max(dist.quantile(OUTLIER_QUANTILE)),
+        last.min(dist.quantile(1.0 - OUTLIER_QUANTILE)),
+        show_cdf,
+    );
+    if !show_cdf {
+        let n = data.len() as f64;
+        for &count in counts
+            .range(axes.x_min as i64..=axes.x_max as i64)
+            .map(|(_, c)| c)
+        {
+            axes.y_max = axes.y_max.max(count as f64 / n * 1.1);
+        }
+    }
+    axes
+}
+
+/// The fitted density (or probabilities) over the histogram of the data, drawn
+/// by the chart of the family's own page.
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    data: &[f64],
+    fit: &Fit,
+    show_cdf: bool,
+) {
+    let p = &fit.parameters;
+    if fit.family.is_discrete() {
+        let axes = discrete_axes(data, fit, show_cdf);
+        let counts: Vec<i64> = data.iter().map(|&x| x as i64).collect();
+        let data = Some(counts.as_slice());
+        match fit.family {
+            Family::Poisson => {
+                poisson::draw_chart(root, axes, p[0], show_cdf, None, &[], None, data)
+            }
+            Family::Geometric => {
+                geometric::draw_chart(root, axes, p[0], show_cdf, None, &[], None, data)
+            }
+            Family::NegativeBinomial => negative_binomial::draw_chart(
+                root,
+                axes,
+                p[1],
+                p[0],
+                show_cdf,
+                None,
+                &[],
+                None,
+                data,
+            ),
+            _ => unreachable!("{} is continuous", fit.family.name()),
+        }
+        return;
+    }
+
+    let axes = continuous_axes(data, fit, show_cdf);
+    let data = Some(data);
+    match fit.family {
+        Family::Normal => normal::draw_chart(
+            root,
+            axes,
+            p[0],
+            p[1],
+            None,
+            show_cdf,
+            None,
+            &[],
+            None,
+            data,
+        ),
+        Family::Exponential => {
+            exponential::draw_chart(root, axes, p[0], show_cdf, None, &[], None, data)
+        }
+        Family::Gamma => gamma::draw_chart(root, axes, p[0], p[1], show_cdf, None, &[], None, data),
+        Family::Weibull => {
+            weibull::draw_chart(root, axes, p[0], p[1], show_cdf, None, &[], None, data)
+        }
+        Family::LogNormal => {
+            log_normal::draw_chart(root, axes, p[0], p[1], show_cdf, None, &[], None, data)
+        }
+        Family::Beta => beta::draw_chart(root, axes, p[0], p[1], show_cdf, None, &[], None, data),
+        Family::Laplace => {
+            laplace::draw_chart(root, axes, p[0], p[1], show_cdf, None, &[], None, data)
+        }
+        Family::Logistic => {
+            logistic::draw_chart(root, axes, p[0], p[1], show_cdf, None, &[], None, data)
+        }
+        Family::Gumbel => {
+            gumbel::draw_chart(root, axes, p[0], p[1], show_cdf, None, &[], None, data)
+        }
+        Family::Cauchy => {
+            cauchy::draw_chart(root, axes, p[0], p[1], show_cdf, None, &[], None, data)
+        }
+        _ => unreachable!("{} is discrete", fit.family.name()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distributions::axes::MAX_BARS;
+
+    #[test]
+    fn test_default_data_fits_and_draws() {
+        let (data, fits) = fit_text(DEFAULT_DATA).unwrap();
+        assert_eq!(data.len(), 40);
+        assert!(fits.iter().all(|fit| !fit.family.is_discrete()));
+        assert!(fits.windows(2).all(|pair| pair[0].aic <= pair[1].aic));
+
+        for fit in &fits {
+            let svg = draw_svg((400, 300), |root| draw_chart(root, &data, fit, false));
+            assert!(svg.contains("Data (n = 40)"));
+        }
+
+        let (counts, fits) = fit_text("0 1 1 2 3 0 4 2").unwrap();
+        assert!(fits.iter().any(|fit| !fit.family.is_discrete()));
+        let best = preferred(&counts, &fits).unwrap();
+        assert!(best.family.is_discrete());
+        for fit in &fits {
+            let svg = draw_svg((400, 300), |root| draw_chart(root, &counts, fit, true));
+            assert!(svg.contains("Data (n = 8)"));
+        }
+
+        let (changes, fits) = fit_text("-2 0 1 -1 3 0 -4 2").unwrap();
+        assert!(!preferred(&changes, &fits).unwrap().family.is_discrete());
+        assert!(fit_text("1").is_err());
+    }
+
+    #[test]
+    fn test_discrete_outliers_stay_off_the_chart() {
+        let (counts, fits) = fit_text("0 1 2 1 3 2 1 1000000000").unwrap();
+        let best = preferred(&counts, &fits).unwrap();
+        assert!(best.family.is_discrete());
+        for show_cdf in [false, true] {
+            let axes = discrete_axes(&counts, best, show_cdf);
+            assert_eq!(axes.x_min, 0);
+            assert!(axes.x_max - axes.x_min <= MAX_BARS);
+            let svg = draw_svg((400, 300), |root| draw_chart(root, &counts, best, show_cdf));
+            assert!(svg.contains("Data (n = 8)"));
+        }
+    }
+}
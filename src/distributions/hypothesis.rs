@@ -0,0 +1,539 @@
+//! Hypothesis testing playground.
+//!
+//! Samples are entered as summary statistics or pasted data, categorical data
+//! as counts. The statistic is drawn on the chart of the t, χ² or F component
+//! for its null distribution, with the critical region for the chosen α shaded.
+
+use crate::distributions::axes::Axes;
+use crate::distributions::continuous::{chi_squared, f, student};
+use crate::distributions::export::{draw_svg, ExportButtons};
+use crate::distributions::input::{parse_counts, parse_numbers};
+use crate::distributions::interval::CriticalRegion;
+use crate::stats::hypothesis::{
+    chi_squared_goodness_of_fit, chi_squared_independence, f_test, one_sample_t, welch_t,
+    Alternative, Reference, Summary, TestStatistic,
+};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::{HtmlCanvasElement, HtmlInputElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TestKind {
+    OneSampleT,
+    TwoSampleT,
+    FTest,
+    GoodnessOfFit,
+    Independence,
+}
+
+impl TestKind {
+    const ALL: [TestKind; 5] = [
+        Self::OneSampleT,
+        Self::TwoSampleT,
+        Self::FTest,
+        Self::GoodnessOfFit,
+        Self::Independence,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::OneSampleT => "One-sample t-test",
+            Self::TwoSampleT => "Two-sample t-test (Welch)",
+            Self::FTest => "F-test for variances",
+            Self::GoodnessOfFit => "χ² goodness of fit",
+            Self::Independence => "χ² independence",
+        }
+    }
+
+    fn hypotheses(self) -> &'static str {
+        match self {
+            Self::OneSampleT => "H₀: μ = μ₀",
+            Self::TwoSampleT => "H₀: μ₁ = μ₂",
+            Self::FTest => "H₀: σ₁² = σ₂²",
+            Self::GoodnessOfFit => "H₀: the categories occur in the given proportions",
+            Self::Independence => "H₀: rows and columns are independent",
+        }
+    }
+
+    /// χ² tests only reject for large statistics.
+    fn has_alternative(self) -> bool {
+        !matches!(self, Self::GoodnessOfFit | Self::Independence)
+    }
+}
+
+impl Alternative {
+    const ALL: [Alternative; 3] = [Self::TwoSided, Self::Less, Self::Greater];
+}
+
+fn alternative_label(alternative: Alternative) -> &'static str {
+    match alternative {
+        Alternative::TwoSided => "two-sided",
+        Alternative::Less => "less",
+        Alternative::Greater => "greater",
+    }
+}
+
+/// A table of counts with one row per line.
+fn parse_table(text: &str) -> Result<Vec<Vec<f64>>, String> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_counts)
+        .collect()
+}
+
+/// One sample as typed in: summary statistics or raw data.
+#[derive(Clone, Debug, PartialEq)]
+struct SampleEntry {
+    use_data: bool,
+    mean: String,
+    std_dev: String,
+    n: String,
+    data: String,
+}
+
+impl SampleEntry {
+    fn new(mean: &str, std_dev: &str, n: &str, data: &str) -> Self {
+        Self {
+            use_data: false,
+            mean: mean.to_string(),
+            std_dev: std_dev.to_string(),
+            n: n.to_string(),
+            data: data.to_string(),
+        }
+    }
+
+    fn summary(&self) -> Result<Summary, String> {
+        let summary = if self.use_data {
+            Summary::of(&parse_numbers(&self.data)?)
+        } else {
+            let number = |text: &str, name: &str| {
+                text.trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|x| x.is_finite())
+                    .ok_or_else(|| format!("{} must be a number", name))
+            };
+            Summary {
+                mean: number(&self.mean, "The mean")?,
+                std_dev: number(&self.std_dev, "The standard deviation")?,
+                n: self
+                    .n
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| "The sample size must be a whole number".to_string())?,
+            }
+        };
+        if summary.n < 2 {
+            return Err("Each sample needs at least two observations".to_string());
+        }
+        if summary.std_dev.is_nan() || summary.std_dev <= 0.0 {
+            return Err("Each sample needs a positive standard deviation".to_string());
+        }
+        Ok(summary)
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct SampleInputProps {
+    label: &'static str,
+    sample: UseStateHandle<SampleEntry>,
+}
+
+#[function_component(SampleInput)]
+fn sample_input(props: &SampleInputProps) -> Html {
+    let sample = props.sample.clone();
+    let onchange_mode = |use_data: bool| {
+        let sample = sample.clone();
+        Callback::from(move |_: Event| {
+            sample.set(SampleEntry {
+                use_data,
+                ..(*sample).clone()
+            })
+        })
+    };
+
+    let oninput_field = |set: fn(&mut SampleEntry, String)| {
+        let sample = sample.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                let mut entry = (*sample).clone();
+                set(&mut entry, input.value());
+                sample.set(entry);
+            }
+        })
+    };
+
+    let oninput_data = {
+        let sample = sample.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlTextAreaElement>() {
+                sample.set(SampleEntry {
+                    data: input.value(),
+                    ..(*sample).clone()
+                });
+            }
+        })
+    };
+
+    let name = format!("mode-{}", props.label);
+    html! {
+        <fieldset>
+            <legend>{ props.label }</legend>
+            <label>
+                <input type="radio" name={name.clone()} checked={!sample.use_data}
+                onchange={onchange_mode(false)} />
+                { " Summary statistics " }
+            </label>
+            <label>
+                <input type="radio" {name} checked={sample.use_data}
+                onchange={onchange_mode(true)} />
+                { " Data " }
+            </label>
+            if sample.use_data {
+                <div>
+                    <textarea rows="3" style="width: 100%;" value={sample.data.clone()}
+                    oninput={oninput_data} placeholder="Numbers separated by commas or spaces" />
+                </div>
+            } else {
+                <div>
+                    <label>{ "Mean: " }</label>
+                    <input type="number" step="any" value={sample.mean.clone()}
+                    oninput={oninput_field(|entry, value| entry.mean = value)} style="width: 6em;" />
+                    <label>{ " Standard deviation: " }</label>
+                    <input type="number" step="any" value={sample.std_dev.clone()}
+                    oninput={oninput_field(|entry, value| entry.std_dev = value)} style="width: 6em;" />
+                    <label>{ " n: " }</label>
+                    <input type="number" step="1" min="2" value={sample.n.clone()}
+                    oninput={oninput_field(|entry, value| entry.n = value)} style="width: 6em;" />
+                </div>
+            }
+        </fieldset>
+    }
+}
+
+#[function_component(HypothesisTesting)]
+pub fn hypothesis_testing() -> Html {
+    let kind = use_state(|| TestKind::OneSampleT);
+    let alternative = use_state(|| Alternative::TwoSided);
+    let alpha = use_state(|| 0.05);
+    let mu0 = use_state(|| "5".to_string());
+    let sample_a =
+        use_state(|| SampleEntry::new("5.45", "0.42", "6", "5.1, 4.9, 5.6, 5.8, 6.0, 5.3"));
+    let sample_b = use_state(|| SampleEntry::new("7", "3.74", "6", "2, 4, 6, 8, 10, 12"));
+    let observed = use_state(|| "18, 22, 30, 30".to_string());
+    let proportions = use_state(String::new);
+    let table = use_state(|| "20, 30\n25, 25".to_string());
+    let canvas_ref = use_node_ref();
+
+    let outcome: Result<TestStatistic, String> = match *kind {
+        TestKind::OneSampleT => sample_a.summary().and_then(|sample| {
+            let mu0 = mu0
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| "μ₀ must be a number".to_string())?;
+            Ok(one_sample_t(sample, mu0))
+        }),
+        TestKind::TwoSampleT => sample_a
+            .summary()
+            .and_then(|a| Ok(welch_t(a, sample_b.summary()?))),
+        TestKind::FTest => sample_a
+            .summary()
+            .and_then(|a| Ok(f_test(a, sample_b.summary()?))),
+        TestKind::GoodnessOfFit => parse_counts(&observed).and_then(|observed| {
+            let proportions = match parse_numbers(&proportions)? {
+                proportions if proportions.is_empty() => vec![1.0; observed.len()],
+                proportions => proportions,
+            };
+            chi_squared_goodness_of_fit(&observed, &proportions).ok_or_else(|| {
+                "Enter at least two counts, with one positive proportion per count".to_string()
+            })
+        }),
+        TestKind::Independence => parse_table(&table).and_then(|table| {
+            chi_squared_independence(&table).ok_or_else(|| {
+                "Enter a table of at least 2 × 2 counts, every row and column with a positive total"
+                    .to_string()
+            })
+        }),
+    };
+    let alternative_used = if kind.has_alternative() {
+        *alternative
+    } else {
+        Alternative::Greater
+    };
+    let test = outcome.as_ref().ok().copied();
+
+    {
+        let alpha = *alpha;
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                    // Set the canvas size to match its parent container
+                    let parent = canvas.parent_element().unwrap();
+                    let width = parent.client_width();
+                    let height = (width as f64 * 0.75) as i32; // Maintain aspect ratio
+                    canvas.set_width(width as u32);
+                    canvas.set_height(height as u32);
+
+                    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+                    let root = backend.into_drawing_area();
+                    match test {
+                        Some(test) => draw_chart(&root, test, alternative_used, alpha),
+                        None => root.fill(&WHITE).unwrap(),
+                    }
+                }
+                || ()
+            },
+            (test, alternative_used, alpha),
+        );
+    }
+
+    let onchange_kind = |option: TestKind| {
+        let kind = kind.clone();
+        Callback::from(move |_: Event| kind.set(option))
+    };
+
+    let onchange_alternative = |option: Alternative| {
+        let alternative = alternative.clone();
+        Callback::from(move |_: Event| alternative.set(option))
+    };
+
+    let oninput_alpha = {
+        let alpha = alpha.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    if value > 0.0 && value < 1.0 {
+                        alpha.set(value);
+                    }
+                }
+            }
+        })
+    };
+
+    let oninput_text = |state: UseStateHandle<String>| {
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                state.set(input.value());
+            } else if let Some(input) = e.target_dyn_into::<HtmlTextAreaElement>() {
+                state.set(input.value());
+            }
+        })
+    };
+
+    let render_svg = {
+        let alpha = *alpha;
+        Callback::from(move |size| {
+            draw_svg(size, |root| match test {
+                Some(test) => draw_chart(root, test, alternative_used, alpha),
+                None => root.fill(&WHITE).unwrap(),
+            })
+        })
+    };
+
+    let inputs = match *kind {
+        TestKind::OneSampleT => html! {
+            <>
+                <SampleInput label="Sample" sample={sample_a.clone()} />
+                <div>
+                    <label>{ "μ₀: " }</label>
+                    <input type="number" step="any" value={(*mu0).clone()}
+                    oninput={oninput_text(mu0.clone())} style="width: 6em;" />
+                </div>
+            </>
+        },
+        TestKind::TwoSampleT | TestKind::FTest => html! {
+            <>
+                <SampleInput label="Sample 1" sample={sample_a.clone()} />
+                <SampleInput label="Sample 2" sample={sample_b.clone()} />
+            </>
+        },
+        TestKind::GoodnessOfFit => html! {
+            <>
+                <div>
+                    <label>{ "Observed counts: " }</label>
+                    <input type="text" value={(*observed).clone()}
+                    oninput={oninput_text(observed.clone())} style="width: 60%;" />
+                </div>
+                <div>
+                    <label>{ "Expected proportions: " }</label>
+                    <input type="text" value={(*proportions).clone()} placeholder="Equal if left empty"
+                    oninput={oninput_text(proportions.clone())} style="width: 60%;" />
+                </div>
+            </>
+        },
+        TestKind::Independence => html! {
+            <div>
+                <label>{ "Contingency table, one row per line:" }</label>
+                <textarea rows="4" style="width: 100%;" value={(*table).clone()}
+                oninput={oninput_text(table.clone())} />
+            </div>
+        },
+    };
+
+    let results = match &outcome {
+        Ok(test) => {
+            let symbol = test.reference.symbol();
+            let p_value = test.p_value(alternative_used);
+            let critical_region = match test.critical_values(alternative_used, *alpha) {
+                (Some(lower), Some(upper)) => {
+                    format!("{0} < {1:.4} or {0} > {2:.4}", symbol, lower, upper)
+                }
+                (Some(lower), None) => format!("{} < {:.4}", symbol, lower),
+                (None, Some(upper)) => format!("{} > {:.4}", symbol, upper),
+                (None, None) => String::new(),
+            };
+            let decision = if p_value <= *alpha {
+                format!("Reject H₀ at α = {}", *alpha)
+            } else {
+                format!("Do not reject H₀ at α = {}", *alpha)
+            };
+            html! {
+                <>
+                    <p>{ format!("Statistic: {} = {:.4}, distributed as {} under H₀", symbol, test.statistic, test.reference.name()) }</p>
+                    <p>{ format!("p-value ({}): {:.4}", alternative_label(alternative_used), p_value) }</p>
+                    <p>{ format!("Critical region: {}", critical_region) }</p>
+                    <p><strong>{ decision }</strong></p>
+                </>
+            }
+        }
+        Err(message) => html! { <p style="color: red;">{ message }</p> },
+    };
+
+    html! {
+        <div style="width: 100%; max-width: 800px; margin: auto;">
+            <h1>{ "Hypothesis Testing" }</h1>
+            <div>
+                { for TestKind::ALL.into_iter().map(|option| html! {
+                    <label>
+                        <input type="radio" name="test-kind" checked={*kind == option}
+                        onchange={onchange_kind(option)} />
+                        { format!(" {} ", option.label()) }
+                    </label>
+                }) }
+            </div>
+            <p>{ kind.hypotheses() }</p>
+            { inputs }
+            if kind.has_alternative() {
+                <div>
+                    <label>{ "Alternative: " }</label>
+                    { for Alternative::ALL.into_iter().map(|option| html! {
+                        <label>
+                            <input type="radio" name="alternative" checked={*alternative == option}
+                            onchange={onchange_alternative(option)} />
+                            { format!(" {} ", alternative_label(option)) }
+                        </label>
+                    }) }
+                </div>
+            }
+            <div>
+                <label>{ "Significance level α: " }</label>
+                <input type="number" min="0.001" max="0.5" step="0.005" value={alpha.to_string()}
+                oninput={oninput_alpha} style="width: 6em;" />
+            </div>
+            { results }
+            <canvas ref={canvas_ref.clone()} style="width: 100%; height: auto;"></canvas>
+            <ExportButtons name="hypothesis-test" {canvas_ref} {render_svg} />
+        </div>
+    }
+}
+
+/// Null distribution drawn by its own component, the t, χ² or F chart, with
+/// the critical region shaded and the statistic marked.
+fn draw_chart<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    test: TestStatistic,
+    alternative: Alternative,
+    alpha: f64,
+) {
+    let mut axes = Axes::continuous(test.reference.dist().as_ref(), false);
+    // Keep the statistic in view even far out in a tail
+    if test.statistic.is_finite() {
+        let padding = 0.05 * (axes.x_max - axes.x_min);
+        axes.x_min = axes.x_min.min(test.statistic - padding);
+        axes.x_max = axes.x_max.max(test.statistic + padding);
+    }
+    let (lower, upper) = test.critical_values(alternative, alpha);
+    let critical = Some(CriticalRegion {
+        lower,
+        upper,
+        alpha,
+        statistic: test.statistic,
+        symbol: test.reference.symbol(),
+    });
+
+    match test.reference {
+        Reference::StudentT { df } => {
+            student::draw_chart(root, axes, df, false, None, &[], None, critical)
+        }
+        Reference::ChiSquared { df } => {
+            chi_squared::draw_chart(root, axes, df, false, None, &[], None, critical)
+        }
+        Reference::F { df1, df2 } => {
+            f::draw_chart(root, axes, df1, df2, false, None, &[], None, critical)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_inputs() {
+        assert_eq!(
+            parse_table("1 2\n\n3 4\n"),
+            Ok(vec![vec![1.0, 2.0], vec![3.0, 4.0]])
+        );
+
+        assert!(parse_table("1 2\n3 -4").is_err());
+
+        let mut entry = SampleEntry::new("1", "0", "5", "1, 2, 3");
+        assert!(entry.summary().is_err());
+        entry.use_data = true;
+        assert_eq!(entry.summary().map(|summary| summary.n), Ok(3));
+
+        // Sample sizes are whole numbers, never rounded or saturated
+        assert_eq!(
+            SampleEntry::new("1", "2", " 5 ", "").summary().map(|s| s.n),
+            Ok(5)
+        );
+        for n in ["2.7", "-3", "1e3", "many"] {
+            assert!(
+                SampleEntry::new("1", "2", n, "").summary().is_err(),
+                "{}",
+                n
+            );
+        }
+        assert!(SampleEntry::new("NaN", "2", "5", "").summary().is_err());
+    }
+
+    #[test]
+    fn test_charts_shade_the_critical_region() {
+        let sample = Summary {
+            mean: 5.45,
+            std_dev: 0.42,
+            n: 6,
+        };
+        let other = Summary {
+            mean: 7.0,
+            std_dev: 3.74,
+            n: 6,
+        };
+        let tests = [
+            one_sample_t(sample, 5.0),
+            chi_squared_goodness_of_fit(&[18.0, 22.0, 30.0, 30.0], &[1.0; 4]).unwrap(),
+            f_test(sample, other),
+        ];
+        for test in tests {
+            let svg = draw_svg((640, 480), |root| {
+                draw_chart(root, test, Alternative::TwoSided, 0.05)
+            });
+            assert!(svg.contains("Critical region, α = 0.05"));
+            let marker = format!("{} = {:.4}", test.reference.symbol(), test.statistic);
+            assert!(svg.contains(&marker), "{}", marker);
+        }
+    }
+}
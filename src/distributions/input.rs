@@ -0,0 +1,87 @@
+//! Numbers typed or pasted into the pages: parsing them, and letting slow
+//! computations wait until the inputs settle.
+
+use gloo_timers::callback::Timeout;
+use yew::prelude::*;
+
+/// Quiet time after the last input before a slow computation follows it.
+pub const DEBOUNCE_MS: u32 = 150;
+
+/// One finite number; "NaN" and "inf" parse as floats but are not numbers here.
+pub fn parse_number(text: &str) -> Result<f64, String> {
+    let text = text.trim();
+    text.parse::<f64>()
+        .ok()
+        .filter(|x| x.is_finite())
+        .ok_or_else(|| format!("\"{}\" is not a number", text))
+}
+
+/// Finite numbers separated by commas, semicolons or whitespace.
+pub fn parse_numbers(text: &str) -> Result<Vec<f64>, String> {
+    text.split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(parse_number)
+        .collect()
+}
+
+/// Counts, whole numbers of zero or more, separated like [`parse_numbers`].
+pub fn parse_counts(text: &str) -> Result<Vec<f64>, String> {
+    let counts = parse_numbers(text)?;
+    match counts
+        .iter()
+        .find(|&&count| count < 0.0 || count.fract() != 0.0)
+    {
+        Some(count) => Err(format!(
+            "{} is not a count: counts are whole numbers of zero or more",
+            count
+        )),
+        None => Ok(counts),
+    }
+}
+
+/// `value` once it has stayed the same for [`DEBOUNCE_MS`], starting from
+/// the first one. Work keyed on the result runs when a slider or text box
+/// settles rather than on every input event.
+#[hook]
+pub fn use_debounced<T>(value: T) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let settled = use_state_eq(|| value.clone());
+    {
+        let settled = settled.clone();
+        use_effect_with_deps(
+            move |value: &T| {
+                let value = value.clone();
+                let timeout = Timeout::new(DEBOUNCE_MS, move || settled.set(value));
+                // Dropping the timeout cancels it when the input changes again
+                move || drop(timeout)
+            },
+            value,
+        );
+    }
+    (*settled).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_numbers_and_counts() {
+        assert_eq!(parse_number(" -2.5 "), Ok(-2.5));
+        assert!(parse_number("-inf").is_err());
+        assert_eq!(
+            parse_numbers("1, 2;3\n 4.5  -1e1"),
+            Ok(vec![1.0, 2.0, 3.0, 4.5, -10.0])
+        );
+        assert_eq!(parse_numbers(" \n"), Ok(vec![]));
+        assert!(parse_numbers("1, two").is_err());
+        assert!(parse_numbers("1, NaN").is_err());
+        assert!(parse_numbers("inf").is_err());
+
+        assert_eq!(parse_counts("0 3, 12"), Ok(vec![0.0, 3.0, 12.0]));
+        assert!(parse_counts("1, 2.5").is_err());
+        assert!(parse_counts("4, -1").is_err());
+    }
+}
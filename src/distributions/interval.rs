@@ -0,0 +1,333 @@
+//! Probability of an interval, shaded on the chart.
+//!
+//! Continuous charts answer P(a < X < b) = F(b) - F(a) and shade the area
+//! under the density. Discrete charts answer P(a ≤ X ≤ b) = F(b) - F(a - 1)
+//! and highlight the bars. On a CDF chart the probability is the height of
+//! the shaded band between F(a) and F(b).
+
+use crate::distributions::axes::Axes;
+use crate::stats::{Continuous, Discrete};
+use plotters::coord::types::{RangedCoordf64, RangedCoordi32};
+use plotters::prelude::*;
+use std::fmt::Display;
+use std::str::FromStr;
+use yew::prelude::*;
+
+/// Points along the shaded part of a continuous density.
+const AREA_POINTS: usize = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval<X> {
+    pub lower: X,
+    pub upper: X,
+}
+
+impl Interval<f64> {
+    /// P(lower < X < upper)
+    pub fn probability(&self, dist: &impl Continuous) -> f64 {
+        (dist.cdf(self.upper) - dist.cdf(self.lower)).max(0.0)
+    }
+}
+
+impl Interval<i32> {
+    /// P(lower ≤ X ≤ upper)
+    pub fn probability(&self, dist: &impl Discrete) -> f64 {
+        (dist.cdf(self.upper as i64) - dist.cdf(self.lower as i64 - 1)).max(0.0)
+    }
+}
+
+/// Values the ends of an interval can take.
+pub trait Bound: Copy + PartialEq + Display + FromStr + 'static {
+    /// `step` of the number inputs.
+    const STEP: &'static str;
+
+    /// The event whose probability is computed, such as "P(0 < X < 1)".
+    fn event(interval: &Interval<Self>) -> String;
+
+    /// Interval covering the middle third of `axes`.
+    fn middle_third(axes: &Axes<Self>) -> Interval<Self>;
+}
+
+impl Bound for f64 {
+    const STEP: &'static str = "any";
+
+    fn event(interval: &Interval<f64>) -> String {
+        format!("P({} < X < {})", interval.lower, interval.upper)
+    }
+
+    fn middle_third(axes: &Axes<f64>) -> Interval<f64> {
+        let third = (axes.x_max - axes.x_min) / 3.0;
+        let round = |x: f64| (x * 100.0).round() / 100.0;
+        Interval {
+            lower: round(axes.x_min + third),
+            upper: round(axes.x_max - third),
+        }
+    }
+}
+
+impl Bound for i32 {
+    const STEP: &'static str = "1";
+
+    fn event(interval: &Interval<i32>) -> String {
+        format!("P({} ≤ X ≤ {})", interval.lower, interval.upper)
+    }
+
+    fn middle_third(axes: &Axes<i32>) -> Interval<i32> {
+        let third = (axes.x_max - axes.x_min) / 3;
+        Interval {
+            lower: axes.x_min + third,
+            upper: axes.x_max - third,
+        }
+    }
+}
+
+/// Shades the interval under a continuous density, or as a band on its CDF.
+pub fn shade_continuous<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+    dist: &impl Continuous,
+    interval: Interval<f64>,
+    show_cdf: bool,
+) {
+    let label = format!(
+        "{} = {:.4}",
+        f64::event(&interval),
+        interval.probability(dist)
+    );
+    shade_area(chart, dist, interval, show_cdf, BLUE.mix(0.3), Some(label));
+}
+
+/// Shading of [`shade_continuous`] in `color`, with a legend entry when
+/// `label` is given. Returns whether anything was drawn.
+fn shade_area<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+    dist: &impl Continuous,
+    interval: Interval<f64>,
+    show_cdf: bool,
+    color: RGBAColor,
+    label: Option<String>,
+) -> bool {
+    let x_range = chart.x_range();
+    let y_max = chart.y_range().end;
+    let lower = interval.lower.max(x_range.start);
+    let upper = interval.upper.min(x_range.end);
+
+    let series = if show_cdf {
+        chart.draw_series(std::iter::once(Rectangle::new(
+            [
+                (interval.lower, dist.cdf(interval.lower)),
+                (interval.upper, dist.cdf(interval.upper)),
+            ],
+            color.filled(),
+        )))
+    } else if lower < upper {
+        chart.draw_series(AreaSeries::new(
+            (0..=AREA_POINTS)
+                .map(|i| lower + (upper - lower) * i as f64 / AREA_POINTS as f64)
+                .map(|x| (x, dist.pdf(x).min(y_max))),
+            0.0,
+            color,
+        ))
+    } else {
+        return false;
+    };
+    let series = series.unwrap();
+    if let Some(label) = label {
+        series
+            .label(label)
+            .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], color.filled()));
+    }
+    true
+}
+
+/// Rejection region of a test at level `alpha`, shaded on the chart of the
+/// statistic's null distribution: the tails beyond the critical values, with
+/// the observed statistic marked.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CriticalRegion {
+    pub lower: Option<f64>,
+    pub upper: Option<f64>,
+    pub alpha: f64,
+    pub statistic: f64,
+    /// Symbol of the statistic, such as "t".
+    pub symbol: &'static str,
+}
+
+/// Shades the tails of `region` like an interval, and draws its statistic.
+pub fn shade_critical_region<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+    dist: &impl Continuous,
+    region: CriticalRegion,
+    show_cdf: bool,
+) {
+    let x_range = chart.x_range();
+    let y_range = chart.y_range();
+    let tails = [
+        region.lower.map(|lower| Interval {
+            lower: x_range.start,
+            upper: lower,
+        }),
+        region.upper.map(|upper| Interval {
+            lower: upper,
+            upper: x_range.end,
+        }),
+    ];
+    // One legend entry for both tails
+    let mut label = Some(format!("Critical region, α = {}", region.alpha));
+    for tail in tails.into_iter().flatten() {
+        if tail.lower < tail.upper
+            && shade_area(chart, dist, tail, show_cdf, RED.mix(0.3), label.clone())
+        {
+            label = None;
+        }
+    }
+
+    if region.statistic.is_finite() {
+        chart
+            .draw_series(LineSeries::new(
+                [
+                    (region.statistic, y_range.start),
+                    (region.statistic, y_range.end),
+                ],
+                BLACK.stroke_width(2),
+            ))
+            .unwrap()
+            .label(format!("{} = {:.4}", region.symbol, region.statistic))
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK));
+    }
+}
+
+/// Highlights the bars inside the interval, or the band they add to the CDF.
+pub fn shade_discrete<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordi32, RangedCoordf64>>,
+    dist: &impl Discrete,
+    interval: Interval<i32>,
+    show_cdf: bool,
+) {
+    let x_range = chart.x_range();
+    let color = BLUE.mix(0.5);
+    let label = format!(
+        "{} = {:.4}",
+        i32::event(&interval),
+        interval.probability(dist)
+    );
+
+    let series = if show_cdf {
+        chart.draw_series(std::iter::once(Rectangle::new(
+            [
+                (interval.lower, dist.cdf(interval.lower as i64 - 1)),
+                (interval.upper + 1, dist.cdf(interval.upper as i64)),
+            ],
+            color.filled(),
+        )))
+    } else {
+        let lower = interval.lower.max(x_range.start);
+        let upper = interval.upper.min(x_range.end - 1);
+        chart.draw_series(
+            (lower..=upper)
+                .map(|k| Rectangle::new([(k, 0.0), (k + 1, dist.pmf(k as i64))], color.filled())),
+        )
+    };
+    series
+        .unwrap()
+        .label(label)
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], color.filled()));
+}
+
+#[derive(Properties, PartialEq)]
+pub struct IntervalControlsProps<X: Bound> {
+    /// The interval asked about, or `None` while nothing is shaded.
+    pub interval: UseStateHandle<Option<Interval<X>>>,
+    /// Ranges of the chart, which the interval starts from.
+    pub axes: Axes<X>,
+    /// Probability of the interval under the current parameters.
+    pub probability: Option<f64>,
+}
+
+/// Checkbox and inputs choosing the interval to shade, with its probability.
+#[function_component(IntervalControls)]
+pub fn interval_controls<X: Bound>(props: &IntervalControlsProps<X>) -> Html {
+    let onchange = {
+        let interval = props.interval.clone();
+        let axes = props.axes;
+        Callback::from(move |_: Event| {
+            interval.set(match *interval {
+                Some(_) => None,
+                None => Some(X::middle_third(&axes)),
+            })
+        })
+    };
+
+    let oninput = |set: fn(&mut Interval<X>, X)| {
+        let interval = props.interval.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let (Some(mut current), Ok(value)) = (*interval, input.value().parse::<X>()) {
+                    set(&mut current, value);
+                    interval.set(Some(current));
+                }
+            }
+        })
+    };
+
+    html! {
+        <div>
+            <label>
+                <input type="checkbox" checked={props.interval.is_some()} {onchange} />
+                { " Shade the probability of an interval" }
+            </label>
+            if let Some(interval) = *props.interval {
+                <label>{ " a: " }</label>
+                <input type="number" step={X::STEP} value={interval.lower.to_string()}
+                oninput={oninput(|interval, value| interval.lower = value)} style="width: 6em;" />
+                <label>{ " b: " }</label>
+                <input type="number" step={X::STEP} value={interval.upper.to_string()}
+                oninput={oninput(|interval, value| interval.upper = value)} style="width: 6em;" />
+                if let Some(probability) = props.probability {
+                    <span>{ format!(" {} = {:.4}", X::event(&interval), probability) }</span>
+                }
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::continuous::Normal;
+    use crate::stats::discrete::Binomial;
+
+    #[test]
+    fn test_interval_probabilities() {
+        let interval = Interval {
+            lower: -1.96,
+            upper: 1.96,
+        };
+        assert!((interval.probability(&Normal::standard()) - 0.95).abs() < 1e-3);
+        let reversed = Interval {
+            lower: 1.0,
+            upper: -1.0,
+        };
+        assert_eq!(reversed.probability(&Normal::standard()), 0.0);
+
+        // Both ends count for a discrete distribution
+        let dist = Binomial::new(2, 0.5);
+        let interval = Interval { lower: 1, upper: 2 };
+        assert!((interval.probability(&dist) - 0.75).abs() < 1e-12);
+        let interval = Interval { lower: 1, upper: 1 };
+        assert!((interval.probability(&dist) - 0.5).abs() < 1e-12);
+        assert_eq!(i32::event(&interval), "P(1 ≤ X ≤ 1)");
+    }
+
+    #[test]
+    fn test_default_interval_is_inside_the_axes() {
+        let axes = Axes::continuous(&Normal::new(0.0, 1.0), false);
+        let interval = f64::middle_third(&axes);
+        assert!(axes.x_min < interval.lower && interval.lower < interval.upper);
+        assert!(interval.upper < axes.x_max);
+
+        let axes = Axes::discrete(&Binomial::new(30, 0.5), false);
+        let interval = i32::middle_third(&axes);
+        assert!(axes.x_min <= interval.lower && interval.lower <= interval.upper);
+        assert!(interval.upper <= axes.x_max);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::distributions::input::use_debounced;
+use crate::stats::{Continuous, Discrete, Distribution};
+use std::fmt::Debug;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+/// Formats a moment for display, spelling out the ones that do not exist.
+pub fn format_moment(value: f64) -> String {
+    if value.is_nan() {
+        "undefined".to_string()
+    } else if value.is_infinite() {
+        "∞".to_string()
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+/// Chart caption with the mean and variance of `dist`.
+pub fn moments_caption(dist: &impl Distribution) -> String {
+    format!(
+        "Mean: {}, Variance: {}",
+        format_moment(dist.mean()),
+        format_moment(dist.variance())
+    )
+}
+
+/// Label and formatted value of one line of the panel.
+type Row = (&'static str, String);
+
+/// Rows shared by continuous and discrete distributions, with `median` and
+/// `modes` already formatted.
+fn rows(dist: &impl Distribution, median: String, modes: String, entropy: f64) -> Vec<Row> {
+    vec![
+        ("Mean", format_moment(dist.mean())),
+        ("Variance", format_moment(dist.variance())),
+        ("Standard deviation", format_moment(dist.std_dev())),
+        ("Skewness", format_moment(dist.skewness())),
+        ("Excess kurtosis", format_moment(dist.excess_kurtosis())),
+        ("Median", median),
+        ("Mode(s)", modes),
+        ("Entropy (nats)", format_moment(entropy)),
+    ]
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MomentsPanelProps {
+    pub rows: Vec<Row>,
+    /// The distribution with its parameters, which the MGF is memoized on.
+    pub distribution: String,
+    /// The moment-generating function, evaluated at the t typed into the panel.
+    pub mgf: Callback<f64, f64>,
+}
+
+impl MomentsPanelProps {
+    pub fn continuous(dist: impl Continuous + Debug + 'static) -> Self {
+        let modes = dist.modes();
+        let modes = if modes.is_empty() {
+            "every point of the support".to_string()
+        } else {
+            modes
+                .into_iter()
+                .map(format_moment)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        Self {
+            rows: rows(&dist, format_moment(dist.median()), modes, dist.entropy()),
+            distribution: format!("{:?}", dist),
+            mgf: Callback::from(move |t| dist.mgf(t)),
+        }
+    }
+
+    pub fn discrete(dist: impl Discrete + Debug + 'static) -> Self {
+        let modes = dist.modes();
+        let modes = if modes.is_empty() {
+            "every value of the support".to_string()
+        } else {
+            modes
+                .into_iter()
+                .map(|k| k.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        Self {
+            rows: rows(&dist, dist.median().to_string(), modes, dist.entropy()),
+            distribution: format!("{:?}", dist),
+            mgf: Callback::from(move |t| dist.mgf(t)),
+        }
+    }
+}
+
+/// Table of the moments, median, modes, entropy and MGF of a distribution,
+/// with "undefined" and "∞" where they do not exist or diverge.
+///
+/// Some MGFs are integrated numerically, so the value is only recomputed once
+/// the parameters and t settle, not on every slider tick.
+#[function_component(MomentsPanel)]
+pub fn moments_panel(props: &MomentsPanelProps) -> Html {
+    let t = use_state(|| 0.5);
+    let settled = use_debounced((props.distribution.clone(), *t));
+    let mgf = {
+        let mgf = props.mgf.clone();
+        use_memo(move |(_, t)| mgf.emit(*t), settled)
+    };
+
+    let oninput = {
+        let t = t.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<f64>() {
+                    t.set(value);
+                }
+            }
+        })
+    };
+
+    html! {
+        <div>
+            <h3>{ "Moments" }</h3>
+            <table style="width: 100%;">
+                { for props.rows.iter().map(|(label, value)| html! {
+                    <tr>
+                        <td>{ *label }</td>
+                        <td>{ value.clone() }</td>
+                    </tr>
+                }) }
+                <tr>
+                    <td>
+                        { "MGF M(t) = E[exp(tX)] at t = " }
+                        <input type="number" step="0.1" value={t.to_string()} {oninput}
+                        style="width: 5em;" />
+                    </td>
+                    <td>{ format_moment(*mgf) }</td>
+                </tr>
+            </table>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::continuous::{Uniform, F};
+    use crate::stats::discrete::Poisson;
+
+    #[test]
+    fn test_rows_spell_out_missing_moments() {
+        let value = |props: &MomentsPanelProps, label: &str| {
+            props
+                .rows
+                .iter()
+                .find(|(row, _)| *row == label)
+                .unwrap()
+                .1
+                .clone()
+        };
+
+        let f = MomentsPanelProps::continuous(F::new(5.0, 4.0));
+        assert_eq!(value(&f, "Variance"), "∞");
+        assert_eq!(value(&f, "Skewness"), "undefined");
+        assert_eq!(f.mgf.emit(0.5), f64::INFINITY);
+        assert_eq!(f.mgf.emit(0.0), 1.0);
+        let other = MomentsPanelProps::continuous(F::new(5.0, 4.5));
+        assert_ne!(f.distribution, other.distribution);
+
+        let uniform = MomentsPanelProps::continuous(Uniform::new(0.0, 2.0));
+        assert_eq!(value(&uniform, "Median"), "1.00");
+        assert_eq!(value(&uniform, "Mode(s)"), "every point of the support");
+
+        let poisson = MomentsPanelProps::discrete(Poisson::new(3.0));
+        assert_eq!(value(&poisson, "Mode(s)"), "2, 3");
+        assert_eq!(value(&poisson, "Median"), "3");
+        assert_eq!(value(&poisson, "Excess kurtosis"), "0.33");
+    }
+}
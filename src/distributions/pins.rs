@@ -0,0 +1,221 @@
+//! Pinned series: frozen curves drawn next to the live one for comparison.
+//!
+//! Pinning evaluates the current distribution on the points of its chart and
+//! keeps the result under a label. The distribution page shares one list of
+//! pins between all of its charts, so a Binomial can be compared with its
+//! Poisson approximation. Charts draw the pins of their own kind only.
+
+use crate::distributions::axes::Axes;
+use crate::distributions::export::Grid;
+use plotters::coord::types::{RangedCoordf64, RangedCoordi32};
+use plotters::prelude::*;
+use yew::prelude::*;
+
+/// Colours of the pinned series, in pinning order. Red is the live series and
+/// black the simulated draws.
+const PALETTE: [RGBColor; 6] = [
+    RGBColor(31, 119, 180),
+    RGBColor(44, 160, 44),
+    RGBColor(255, 127, 14),
+    RGBColor(148, 103, 189),
+    RGBColor(23, 190, 207),
+    RGBColor(140, 86, 75),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PinnedSeries {
+    pub label: String,
+    pub grid: Grid,
+}
+
+/// The pinned series shared through the context of the distribution page.
+pub type Pins = UseStateHandle<Vec<PinnedSeries>>;
+
+fn color(index: usize) -> RGBColor {
+    PALETTE[index % PALETTE.len()]
+}
+
+/// The pins of the enclosing page, or pins of the component's own outside one.
+#[hook]
+pub fn use_pins() -> Pins {
+    let shared = use_context::<Pins>();
+    let own = use_state(Vec::new);
+    shared.unwrap_or(own)
+}
+
+/// Points of a continuous pin inside `axes`, with values capped at the top of
+/// the chart.
+fn continuous_points(grid: &Grid, axes: &Axes<f64>, show_cdf: bool) -> Option<Vec<(f64, f64)>> {
+    match grid {
+        Grid::Continuous(points) => Some(
+            points
+                .iter()
+                .filter(|point| (axes.x_min..=axes.x_max).contains(&point.x))
+                .map(|point| {
+                    let y = if show_cdf { point.cdf } else { point.pdf };
+                    (point.x, y.min(axes.y_max))
+                })
+                .collect(),
+        ),
+        Grid::Discrete(_) => None,
+    }
+}
+
+/// Outline of a discrete pin inside `axes`, stepping across the bar [k, k + 1)
+/// of every value.
+fn discrete_steps(grid: &Grid, axes: &Axes<i32>, show_cdf: bool) -> Option<Vec<(i32, f64)>> {
+    match grid {
+        Grid::Discrete(points) => Some(
+            points
+                .iter()
+                .filter(|point| (axes.x_min as i64..=axes.x_max as i64).contains(&point.x))
+                .flat_map(|point| {
+                    let y = if show_cdf { point.cdf } else { point.pmf }.min(axes.y_max);
+                    let k = point.x as i32;
+                    [(k, y), (k + 1, y)]
+                })
+                .collect(),
+        ),
+        Grid::Continuous(_) => None,
+    }
+}
+
+/// Draws the continuous pins as lines on a continuous chart.
+pub fn draw_continuous_pins<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+    axes: &Axes<f64>,
+    pins: &[PinnedSeries],
+    show_cdf: bool,
+) {
+    for (index, pin) in pins.iter().enumerate() {
+        if let Some(points) = continuous_points(&pin.grid, axes, show_cdf) {
+            let color = color(index);
+            chart
+                .draw_series(LineSeries::new(points, color.stroke_width(2)))
+                .unwrap()
+                .label(pin.label.clone())
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+        }
+    }
+}
+
+/// Draws the discrete pins as step outlines on a discrete chart.
+pub fn draw_discrete_pins<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordi32, RangedCoordf64>>,
+    axes: &Axes<i32>,
+    pins: &[PinnedSeries],
+    show_cdf: bool,
+) {
+    for (index, pin) in pins.iter().enumerate() {
+        if let Some(steps) = discrete_steps(&pin.grid, axes, show_cdf) {
+            let color = color(index);
+            chart
+                .draw_series(LineSeries::new(steps, color.stroke_width(2)))
+                .unwrap()
+                .label(pin.label.clone())
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PinControlsProps {
+    pub pins: Pins,
+    /// Legend entry of the live series, naming the distribution and its parameters.
+    pub label: String,
+    /// Evaluates the live series on the points of its chart.
+    pub grid: Callback<(), Grid>,
+}
+
+/// Button pinning the live series, and the list of pins with their colours.
+#[function_component(PinControls)]
+pub fn pin_controls(props: &PinControlsProps) -> Html {
+    let on_pin = {
+        let pins = props.pins.clone();
+        let label = props.label.clone();
+        let grid = props.grid.clone();
+        Callback::from(move |_| {
+            let mut list = (*pins).clone();
+            list.push(PinnedSeries {
+                label: label.clone(),
+                grid: grid.emit(()),
+            });
+            pins.set(list);
+        })
+    };
+
+    let on_clear = {
+        let pins = props.pins.clone();
+        Callback::from(move |_| pins.set(Vec::new()))
+    };
+
+    let on_remove = |index: usize| {
+        let pins = props.pins.clone();
+        Callback::from(move |_| {
+            let mut list = (*pins).clone();
+            list.remove(index);
+            pins.set(list);
+        })
+    };
+
+    html! {
+        <div>
+            <button onclick={on_pin}>{ " 📌 Pin series " }</button>
+            if !props.pins.is_empty() {
+                <button onclick={on_clear}>{ " ✕ Clear pins " }</button>
+                <ul>
+                    { for props.pins.iter().enumerate().map(|(index, pin)| {
+                        let RGBColor(r, g, b) = color(index);
+                        html! {
+                            <li>
+                                <span style={format!("color: rgb({}, {}, {});", r, g, b)}>{ "■ " }</span>
+                                { &pin.label }
+                                { " " }
+                                <button onclick={on_remove(index)}>{ "✕" }</button>
+                            </li>
+                        }
+                    }) }
+                </ul>
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::continuous::Uniform;
+    use crate::stats::discrete::Bernoulli;
+
+    #[test]
+    fn test_pins_are_drawn_on_charts_of_their_kind() {
+        let discrete = Grid::discrete(&Bernoulli::new(0.25), 0..2);
+        let continuous = Grid::continuous(&Uniform::new(0.0, 2.0), [-1.0, 0.5, 1.0, 3.0]);
+        let discrete_axes = Axes {
+            x_min: 1,
+            x_max: 4,
+            y_max: 1.0,
+        };
+        let continuous_axes = Axes {
+            x_min: 0.0,
+            x_max: 2.0,
+            y_max: 0.4,
+        };
+
+        assert_eq!(
+            discrete_steps(&discrete, &discrete_axes, false),
+            Some(vec![(1, 0.25), (2, 0.25)])
+        );
+        assert_eq!(discrete_steps(&continuous, &discrete_axes, false), None);
+
+        assert_eq!(
+            continuous_points(&continuous, &continuous_axes, false),
+            Some(vec![(0.5, 0.4), (1.0, 0.4)])
+        );
+        assert_eq!(
+            continuous_points(&continuous, &continuous_axes, true),
+            Some(vec![(0.5, 0.25), (1.0, 0.4)])
+        );
+        assert_eq!(continuous_points(&discrete, &continuous_axes, false), None);
+    }
+}
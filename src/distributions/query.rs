@@ -0,0 +1,348 @@
+//! Distribution parameters kept in the URL query string.
+//!
+//! A query such as `?dist=gamma&alpha=3&beta=0.5` describes one distribution:
+//! `dist` names it and the remaining pairs are its parameters. Components read
+//! their initial parameters from it and write every change back, so a URL can
+//! be reloaded or shared. Parameters of any other distribution are kept
+//! alongside, prefixed by its name, as in `&normal.mean=1`.
+
+use crate::stats::fit::Family;
+use std::ops::RangeInclusive;
+use yew::prelude::*;
+use yew_router::history::{BrowserHistory, History};
+use yew_router::prelude::*;
+use yew_router::AnyRoute;
+
+/// Query key naming the distribution the other pairs belong to.
+pub const DIST_KEY: &str = "dist";
+
+type Query = Vec<(String, String)>;
+
+/// A parameter that can be written to and read back from a query value.
+pub trait QueryValue: Sized {
+    fn to_query(&self) -> String;
+    fn from_query(value: &str) -> Option<Self>;
+}
+
+macro_rules! impl_query_value {
+    ($($t:ty),*) => {
+        $(impl QueryValue for $t {
+            fn to_query(&self) -> String {
+                self.to_string()
+            }
+
+            fn from_query(value: &str) -> Option<Self> {
+                value.parse().ok()
+            }
+        })*
+    };
+}
+
+impl_query_value!(f64, i32, i64, u32, u64, usize);
+
+/// Vectors are written comma separated.
+impl<const N: usize> QueryValue for [f64; N] {
+    fn to_query(&self) -> String {
+        self.map(|x| x.to_string()).join(",")
+    }
+
+    fn from_query(value: &str) -> Option<Self> {
+        let values: Option<Vec<f64>> = value.split(',').map(|x| x.parse().ok()).collect();
+        values?.try_into().ok()
+    }
+}
+
+/// Lists of any length are written comma separated, like vectors.
+impl QueryValue for Vec<f64> {
+    fn to_query(&self) -> String {
+        self.iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn from_query(value: &str) -> Option<Self> {
+        value.split(',').map(|x| x.parse().ok()).collect()
+    }
+}
+
+/// Families are written as their lowercased names, hyphenated.
+impl QueryValue for Family {
+    fn to_query(&self) -> String {
+        self.name().to_lowercase().replace(' ', "-")
+    }
+
+    fn from_query(value: &str) -> Option<Self> {
+        Family::ALL
+            .into_iter()
+            .find(|family| family.to_query() == value)
+    }
+}
+
+/// Matrices are written row by row, rows separated by semicolons.
+impl<const N: usize, const M: usize> QueryValue for [[f64; N]; M] {
+    fn to_query(&self) -> String {
+        self.map(|row| row.to_query()).join(";")
+    }
+
+    fn from_query(value: &str) -> Option<Self> {
+        let rows: Option<Vec<[f64; N]>> = value.split(';').map(QueryValue::from_query).collect();
+        rows?.try_into().ok()
+    }
+}
+
+/// Distribution named by a query, if any.
+pub fn query_dist(query: &[(String, String)]) -> Option<&str> {
+    query
+        .iter()
+        .find(|(key, _)| key == DIST_KEY)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Key under which `key` of `dist` is stored in `query`: bare for the
+/// distribution named by `dist`, or for the first one written to an empty
+/// query, and prefixed by the distribution otherwise.
+fn query_key(query: &[(String, String)], dist: &str, key: &str) -> String {
+    let prefixed = format!("{}.{}", dist, key);
+    let named = query_dist(query).is_none_or(|named| named == dist);
+    if named && !query.iter().any(|(k, _)| *k == prefixed) {
+        key.to_string()
+    } else {
+        prefixed
+    }
+}
+
+/// Value of `key` of `dist`, if the query holds one.
+fn lookup<T: QueryValue>(query: &[(String, String)], dist: &str, key: &str) -> Option<T> {
+    // Pairs of an unrelated query are not parameters
+    query_dist(query)?;
+    let key = query_key(query, dist, key);
+    query
+        .iter()
+        .find(|(k, _)| *k == key)
+        .and_then(|(_, value)| T::from_query(value))
+}
+
+/// `query` with `key` of `dist` set to `value`, keeping every other pair.
+fn update(mut query: Query, dist: &str, key: &str, value: String) -> Query {
+    if query_dist(&query).is_none() {
+        query.insert(0, (DIST_KEY.to_string(), dist.to_string()));
+    }
+    let key = query_key(&query, dist, key);
+    match query.iter_mut().find(|(k, _)| *k == key) {
+        Some(pair) => pair.1 = value,
+        None => query.push((key, value)),
+    }
+    query
+}
+
+/// `value`, or `default` when it is missing or unordered (NaN), clamped into
+/// `range`.
+fn clamp_into<T: PartialOrd + Clone>(value: Option<T>, default: T, range: &RangeInclusive<T>) -> T {
+    let value = value
+        .filter(|value| value.partial_cmp(value).is_some())
+        .unwrap_or(default);
+    if value < *range.start() {
+        range.start().clone()
+    } else if value > *range.end() {
+        range.end().clone()
+    } else {
+        value
+    }
+}
+
+/// `use_state` for parameter `key` of distribution `dist`, backed by the URL.
+///
+/// The state starts from the query when it describes `dist` and falls back to
+/// `default` otherwise. Only for parameters where every value that parses is
+/// valid; see [`use_query_state_in`] and [`use_query_state_if`] for the rest.
+#[hook]
+pub fn use_query_state<T>(dist: &'static str, key: &'static str, default: T) -> UseStateHandle<T>
+where
+    T: QueryValue + Clone + PartialEq + 'static,
+{
+    use_resolved_query_state(dist, key, move |value| value.unwrap_or(default))
+}
+
+/// [`use_query_state`] for a parameter restricted to `range`, usually that of
+/// its slider. Values from the query are clamped into it, NaN falls back to
+/// `default`.
+#[hook]
+pub fn use_query_state_in<T>(
+    dist: &'static str,
+    key: &'static str,
+    default: T,
+    range: RangeInclusive<T>,
+) -> UseStateHandle<T>
+where
+    T: QueryValue + Clone + PartialOrd + 'static,
+{
+    use_resolved_query_state(dist, key, move |value| clamp_into(value, default, &range))
+}
+
+/// [`use_query_state`] for a parameter whose valid values are those accepted
+/// by `valid`; any other value from the query falls back to `default`.
+#[hook]
+pub fn use_query_state_if<T, V>(
+    dist: &'static str,
+    key: &'static str,
+    default: T,
+    valid: V,
+) -> UseStateHandle<T>
+where
+    T: QueryValue + Clone + PartialEq + 'static,
+    V: FnOnce(&T) -> bool,
+{
+    use_resolved_query_state(dist, key, move |value| {
+        value.filter(valid).unwrap_or(default)
+    })
+}
+
+/// Shared body of the hooks above: `resolve` turns the value read from the
+/// query, if any, into the initial state.
+///
+/// Changes replace the current history entry rather than pushing one per
+/// slider step.
+#[hook]
+fn use_resolved_query_state<T, F>(
+    dist: &'static str,
+    key: &'static str,
+    resolve: F,
+) -> UseStateHandle<T>
+where
+    T: QueryValue + Clone + PartialEq + 'static,
+    F: FnOnce(Option<T>) -> T,
+{
+    let location = use_location();
+    let navigator = use_navigator();
+    let state = use_state(move || {
+        resolve(
+            location
+                .and_then(|location| location.query::<Query>().ok())
+                .and_then(|query| lookup(&query, dist, key)),
+        )
+    });
+    let mounted = use_mut_ref(|| false);
+
+    use_effect_with_deps(
+        move |value: &T| {
+            // Opening a distribution alone leaves the URL untouched
+            let changed = mounted.replace(true);
+            if let (true, Some(navigator)) = (changed, navigator) {
+                // Read the live location: several parameters may change in one render
+                let location = BrowserHistory::new().location();
+                let query = location.query::<Query>().unwrap_or_default();
+                let query = update(query, dist, key, value.to_query());
+                let route = AnyRoute::new(location.path());
+                navigator.replace_with_query(&route, &query).ok();
+            }
+            || ()
+        },
+        (*state).clone(),
+    );
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(pairs: &[(&str, &str)]) -> Query {
+        pairs
+            .iter()
+            .map(|&(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_lookup_only_reads_own_distribution() {
+        let gamma = query(&[("dist", "gamma"), ("alpha", "3"), ("beta", "0.5")]);
+        assert_eq!(lookup::<f64>(&gamma, "gamma", "alpha"), Some(3.0));
+        assert_eq!(lookup::<f64>(&gamma, "gamma", "beta"), Some(0.5));
+        assert_eq!(lookup::<f64>(&gamma, "gamma", "scale"), None);
+        assert_eq!(lookup::<f64>(&gamma, "beta", "alpha"), None);
+        assert_eq!(lookup::<i32>(&gamma, "gamma", "beta"), None);
+
+        // A prefixed pair wins over the bare one of the named distribution
+        let prefixed = query(&[("dist", "gamma"), ("alpha", "3"), ("gamma.alpha", "4")]);
+        assert_eq!(lookup::<f64>(&prefixed, "gamma", "alpha"), Some(4.0));
+        assert_eq!(
+            update(prefixed, "gamma", "alpha", "5".to_string()),
+            query(&[("dist", "gamma"), ("alpha", "3"), ("gamma.alpha", "5")])
+        );
+    }
+
+    #[test]
+    fn test_update_keeps_other_distributions() {
+        assert_eq!(
+            update(Vec::new(), "gamma", "alpha", "3".to_string()),
+            query(&[("dist", "gamma"), ("alpha", "3")])
+        );
+        let gamma = query(&[("dist", "gamma"), ("alpha", "3")]);
+        assert_eq!(
+            update(gamma.clone(), "gamma", "beta", "0.5".to_string()),
+            query(&[("dist", "gamma"), ("alpha", "3"), ("beta", "0.5")])
+        );
+        assert_eq!(
+            update(gamma.clone(), "gamma", "alpha", "2".to_string()),
+            query(&[("dist", "gamma"), ("alpha", "2")])
+        );
+
+        // Gamma α=3 plus Normal μ=1 in one URL
+        let both = update(gamma, "normal", "mean", "1".to_string());
+        assert_eq!(
+            both,
+            query(&[("dist", "gamma"), ("alpha", "3"), ("normal.mean", "1")])
+        );
+        let both = update(both, "normal", "mean", "2".to_string());
+        assert_eq!(lookup::<f64>(&both, "gamma", "alpha"), Some(3.0));
+        assert_eq!(lookup::<f64>(&both, "normal", "mean"), Some(2.0));
+        assert_eq!(lookup::<f64>(&both, "normal", "alpha"), None);
+        assert_eq!(lookup::<f64>(&both, "gamma", "mean"), None);
+    }
+
+    #[test]
+    fn test_arrays_round_trip() {
+        let mean = [0.5, -1.0, 2.0];
+        assert_eq!(mean.to_query(), "0.5,-1,2");
+        assert_eq!(<[f64; 3]>::from_query(&mean.to_query()), Some(mean));
+        assert_eq!(<[f64; 3]>::from_query("1,2"), None);
+        assert_eq!(Vec::<f64>::from_query("0.5,0.9"), Some(vec![0.5, 0.9]));
+        assert_eq!(Vec::<f64>::from_query("0.5,x"), None);
+        for family in Family::ALL {
+            assert_eq!(Family::from_query(&family.to_query()), Some(family));
+        }
+        assert_eq!(Family::LogNormal.to_query(), "log-normal");
+
+        let covariance = [[1.0, 0.5], [0.5, 2.0]];
+        assert_eq!(covariance.to_query(), "1,0.5;0.5,2");
+        assert_eq!(
+            <[[f64; 2]; 2]>::from_query(&covariance.to_query()),
+            Some(covariance)
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_values_are_clamped() {
+        let binomial = query(&[("dist", "binomial"), ("n", "-1"), ("p", "2")]);
+        let n = lookup::<i32>(&binomial, "binomial", "n");
+        assert_eq!(clamp_into(n, 10, &(1..=500)), 1);
+        let p = lookup::<f64>(&binomial, "binomial", "p");
+        assert_eq!(clamp_into(p, 0.5, &(0.001..=0.999)), 0.999);
+
+        let gamma = query(&[("dist", "gamma"), ("alpha", "NaN"), ("beta", "inf")]);
+        let alpha = lookup::<f64>(&gamma, "gamma", "alpha");
+        assert_eq!(clamp_into(alpha, 1.0, &(0.01..=10.0)), 1.0);
+        let beta = lookup::<f64>(&gamma, "gamma", "beta");
+        assert_eq!(clamp_into(beta, 1.0, &(0.01..=10.0)), 10.0);
+        let missing = lookup::<f64>(&gamma, "gamma", "scale");
+        assert_eq!(clamp_into(missing, 1.0, &(0.01..=10.0)), 1.0);
+        assert_eq!(clamp_into(missing, 20.0, &(0.01..=10.0)), 10.0);
+
+        // Values that do not parse never reach the range
+        let poisson = query(&[("dist", "poisson"), ("lambda", "-x")]);
+        let lambda = lookup::<f64>(&poisson, "poisson", "lambda");
+        assert_eq!(clamp_into(lambda, 1.0, &(0.01..=20.0)), 1.0);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::stats::descriptive::{empirical_cdf, frequencies, histogram, mean, variance};
+use plotters::coord::types::{RangedCoordf64, RangedCoordi32};
+use plotters::prelude::*;
+use yew::prelude::*;
+
+pub const HISTOGRAM_BINS: usize = 40;
+const ECDF_POINTS: usize = 400;
+
+/// Number of draws and the seed they are generated from.
+///
+/// Keeping the seed instead of the draws means the histogram follows the
+/// sliders while staying reproducible.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Simulation {
+    pub size: usize,
+    pub seed: u64,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SimulationControlsProps {
+    pub simulation: UseStateHandle<Option<Simulation>>,
+}
+
+#[function_component(SimulationControls)]
+pub fn simulation_controls(props: &SimulationControlsProps) -> Html {
+    let size = use_state(|| 1000);
+    let seed = use_state(|| 1);
+
+    let oninput_size = {
+        let size = size.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<usize>() {
+                    size.set(value);
+                }
+            }
+        })
+    };
+
+    let oninput_seed = {
+        let seed = seed.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if let Ok(value) = input.value().parse::<u64>() {
+                    seed.set(value);
+                }
+            }
+        })
+    };
+
+    let on_simulate = {
+        let simulation = props.simulation.clone();
+        let size = size.clone();
+        let seed = seed.clone();
+        Callback::from(move |_| {
+            simulation.set(Some(Simulation {
+                size: *size,
+                seed: *seed,
+            }))
+        })
+    };
+
+    let on_clear = {
+        let simulation = props.simulation.clone();
+        Callback::from(move |_| simulation.set(None))
+    };
+
+    html! {
+        <div>
+            <label>{ "Simulate N draws: " }</label>
+            <input type="range" min="10" max="10000" step="10" value={(*size).to_string()}
+            oninput={oninput_size} style="width: 40%;" />
+            <span>{ *size }</span>
+            <label>{ " Seed: " }</label>
+            <input type="number" min="0" value={(*seed).to_string()}
+            oninput={oninput_seed} style="width: 6em;" />
+            <button onclick={on_simulate}>{ " ▶ Simulate " }</button>
+            if props.simulation.is_some() {
+                <button onclick={on_clear}>{ " ✕ Clear " }</button>
+            }
+        </div>
+    }
+}
+
+pub fn sample_caption(draws: &[f64]) -> String {
+    format!(
+        "Sample Mean: {:.2}, Sample Variance: {:.2}",
+        mean(draws),
+        variance(draws)
+    )
+}
+
+pub fn discrete_sample_caption(draws: &[i64]) -> String {
+    sample_caption(&draws.iter().map(|&k| k as f64).collect::<Vec<_>>())
+}
+
+/// Overlays a density histogram (or the empirical CDF) of `draws` on a continuous chart.
+pub fn draw_histogram<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+    draws: &[f64],
+    show_cdf: bool,
+) {
+    let label = format!("Simulated (n = {})", draws.len());
+    draw_labelled_histogram(chart, draws, show_cdf, label);
+}
+
+/// [`draw_histogram`] under a legend entry of the caller's choosing.
+pub fn draw_labelled_histogram<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+    draws: &[f64],
+    show_cdf: bool,
+    label: String,
+) {
+    let x_range = chart.x_range();
+    let color = BLACK.mix(0.3);
+
+    if show_cdf {
+        let mut sorted = draws.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let step = (x_range.end - x_range.start) / ECDF_POINTS as f64;
+        chart
+            .draw_series(LineSeries::new(
+                (0..=ECDF_POINTS).map(|i| {
+                    let x = x_range.start + i as f64 * step;
+                    (x, empirical_cdf(&sorted, x))
+                }),
+                color.stroke_width(2),
+            ))
+            .unwrap()
+            .label(label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    } else {
+        chart
+            .draw_series(
+                histogram(draws, x_range.start, x_range.end, HISTOGRAM_BINS)
+                    .into_iter()
+                    .map(|bin| {
+                        Rectangle::new([(bin.lower, 0.0), (bin.upper, bin.density)], color.filled())
+                    }),
+            )
+            .unwrap()
+            .label(label)
+            .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], color.filled()));
+    }
+}
+
+/// Outlines the relative frequencies (or the empirical CDF) of `draws` on a discrete chart.
+pub fn draw_frequencies<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordi32, RangedCoordf64>>,
+    draws: &[i64],
+    show_cdf: bool,
+) {
+    let label = format!("Simulated (n = {})", draws.len());
+    draw_labelled_frequencies(chart, draws, show_cdf, label);
+}
+
+/// [`draw_frequencies`] under a legend entry of the caller's choosing.
+pub fn draw_labelled_frequencies<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordi32, RangedCoordf64>>,
+    draws: &[i64],
+    show_cdf: bool,
+    label: String,
+) {
+    let x_range = chart.x_range();
+    let n = draws.len() as f64;
+    let style = BLACK.stroke_width(2);
+    let counts = frequencies(draws);
+    let mut below: usize = counts.range(..x_range.start as i64).map(|(_, c)| c).sum();
+
+    chart
+        .draw_series(x_range.map(|k| {
+            let count = counts.get(&(k as i64)).copied().unwrap_or(0);
+            below += count;
+            let share = if show_cdf { below } else { count } as f64 / n;
+            Rectangle::new([(k, 0.0), (k + 1, share)], style)
+        }))
+        .unwrap()
+        .label(label)
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], style));
+}
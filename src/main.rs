@@ -4,6 +4,8 @@ use yew::html::ChildrenProps;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use rust_wasm_github::distributions::{approximations, bayes, clt, confidence, fit, hypothesis};
+
 mod distribution;
 mod words;
 
@@ -15,6 +17,20 @@ enum Route {
     Words,
     #[at("/rust-wasm-github/distribution")]
     Distribution,
+    #[at("/rust-wasm-github/distribution/:name")]
+    DistributionItem { name: String },
+    #[at("/rust-wasm-github/approximations")]
+    Approximations,
+    #[at("/rust-wasm-github/clt")]
+    CentralLimitTheorem,
+    #[at("/rust-wasm-github/hypothesis")]
+    HypothesisTesting,
+    #[at("/rust-wasm-github/confidence")]
+    ConfidenceIntervals,
+    #[at("/rust-wasm-github/fit")]
+    Fitting,
+    #[at("/rust-wasm-github/bayes")]
+    BayesianUpdating,
     #[not_found]
     #[at("/404")]
     NotFound,
@@ -38,6 +54,36 @@ fn switch(routes: Route) -> Html {
                             { "📊 Distribution to associate your statistics with images" }
                         </button>
                     </Link<Route>>
+                    <Link<Route> to={Route::Approximations}>
+                        <button>
+                            { "🔁 Approximations to watch one distribution turn into another" }
+                        </button>
+                    </Link<Route>>
+                    <Link<Route> to={Route::CentralLimitTheorem}>
+                        <button>
+                            { "🎲 Central limit theorem to see sample means turn normal" }
+                        </button>
+                    </Link<Route>>
+                    <Link<Route> to={Route::HypothesisTesting}>
+                        <button>
+                            { "⚖️ Hypothesis testing to decide with t, χ² and F" }
+                        </button>
+                    </Link<Route>>
+                    <Link<Route> to={Route::ConfidenceIntervals}>
+                        <button>
+                            { "🎯 Confidence intervals to see how often they cover the truth" }
+                        </button>
+                    </Link<Route>>
+                    <Link<Route> to={Route::Fitting}>
+                        <button>
+                            { "📐 Fit a distribution to your own data by maximum likelihood" }
+                        </button>
+                    </Link<Route>>
+                    <Link<Route> to={Route::BayesianUpdating}>
+                        <button>
+                            { "🔮 Bayesian updating to turn a prior into a posterior" }
+                        </button>
+                    </Link<Route>>
                     <button>
                         { "(TBD) 道 Roadmap to see the future of this project" }
                     </button>
@@ -54,6 +100,41 @@ fn switch(routes: Route) -> Html {
                 <distribution::Distribution />
             </div>
         },
+        Route::DistributionItem { name } => html! {
+            <div>
+                <distribution::Distribution name={AttrValue::from(name)} />
+            </div>
+        },
+        Route::Approximations => html! {
+            <div>
+                <approximations::Approximations />
+            </div>
+        },
+        Route::CentralLimitTheorem => html! {
+            <div>
+                <clt::CentralLimitTheorem />
+            </div>
+        },
+        Route::HypothesisTesting => html! {
+            <div>
+                <hypothesis::HypothesisTesting />
+            </div>
+        },
+        Route::ConfidenceIntervals => html! {
+            <div>
+                <confidence::ConfidenceIntervals />
+            </div>
+        },
+        Route::Fitting => html! {
+            <div>
+                <fit::Fitting />
+            </div>
+        },
+        Route::BayesianUpdating => html! {
+            <div>
+                <bayes::BayesianUpdating />
+            </div>
+        },
         Route::NotFound => html! { <h1>{ "NotFound" }</h1> },
     }
 }
@@ -0,0 +1,752 @@
+pub mod bayes;
+pub mod confidence;
+pub mod continuous;
+pub mod descriptive;
+pub mod discrete;
+pub mod distance;
+pub mod fit;
+pub mod goodness_of_fit;
+pub mod hypothesis;
+pub mod linalg;
+pub mod rng;
+pub mod special;
+
+pub use rng::Rng;
+
+use std::ops::RangeInclusive;
+
+/// Closed interval `[lower, upper]` on which a distribution puts its mass.
+///
+/// Unbounded ends are represented by `f64::NEG_INFINITY` / `f64::INFINITY`.
+/// Discrete distributions use integer-valued bounds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Support {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl Support {
+    pub fn new(lower: f64, upper: f64) -> Self {
+        Self { lower, upper }
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.lower <= x && x <= self.upper
+    }
+}
+
+/// Properties shared by every univariate distribution.
+///
+/// Moments that do not exist are reported as `f64::NAN`, moments that diverge
+/// as `f64::INFINITY`.
+pub trait Distribution {
+    fn mean(&self) -> f64;
+
+    fn variance(&self) -> f64;
+
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Third standardised moment E[(X - μ)³] / σ³.
+    fn skewness(&self) -> f64;
+
+    /// Fourth standardised moment less 3, the value for a normal.
+    fn excess_kurtosis(&self) -> f64;
+
+    /// Moment-generating function E[e^(tX)]. The integrand is positive, so it
+    /// is never undefined: 1 at t = 0 and `f64::INFINITY` where it diverges.
+    fn mgf(&self, t: f64) -> f64;
+
+    fn support(&self) -> Support;
+}
+
+/// A distribution with a probability density function.
+pub trait Continuous: Distribution {
+    fn pdf(&self, x: f64) -> f64;
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+
+    /// P(X <= x)
+    fn cdf(&self, x: f64) -> f64;
+
+    /// P(X > x)
+    fn sf(&self, x: f64) -> f64 {
+        1.0 - self.cdf(x)
+    }
+
+    /// Differential entropy -E[ln f(X)] in nats.
+    fn entropy(&self) -> f64;
+
+    fn median(&self) -> f64 {
+        self.quantile(0.5)
+    }
+
+    /// Points where the density peaks; empty when it is flat across the
+    /// whole support.
+    fn modes(&self) -> Vec<f64>;
+
+    /// Inverse of the cdf: the x with P(X <= x) = p.
+    ///
+    /// The default brackets the root inside the support and bisects, which
+    /// works for any continuous cdf; families with a closed form override it.
+    fn quantile(&self, p: f64) -> f64 {
+        let support = self.support();
+        if p <= 0.0 {
+            return support.lower;
+        }
+        if p >= 1.0 {
+            return support.upper;
+        }
+
+        let mut step = 1.0;
+        let mut lower = if support.lower.is_finite() {
+            support.lower
+        } else {
+            let mut x = support.upper.min(0.0) - step;
+            while self.cdf(x) > p {
+                step *= 2.0;
+                x -= step;
+            }
+            x
+        };
+        step = 1.0;
+        let mut upper = if support.upper.is_finite() {
+            support.upper
+        } else {
+            let mut x = lower.max(0.0) + step;
+            while self.cdf(x) < p {
+                step *= 2.0;
+                x += step;
+            }
+            x
+        };
+
+        for _ in 0..200 {
+            let middle = 0.5 * (lower + upper);
+            if middle <= lower || middle >= upper {
+                break;
+            }
+            if self.cdf(middle) < p {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        0.5 * (lower + upper)
+    }
+}
+
+/// A distribution with a probability mass function on the integers.
+pub trait Discrete: Distribution {
+    fn pmf(&self, k: i64) -> f64;
+
+    fn ln_pmf(&self, k: i64) -> f64 {
+        self.pmf(k).ln()
+    }
+
+    /// P(X <= k)
+    ///
+    /// The default sums the pmf from the lower end of the support, so it is
+    /// only suitable for distributions bounded below.
+    fn cdf(&self, k: i64) -> f64 {
+        let lower = self.support().lower as i64;
+        if k < lower {
+            return 0.0;
+        }
+        (lower..=k).map(|i| self.pmf(i)).sum::<f64>().min(1.0)
+    }
+
+    /// P(X > k)
+    fn sf(&self, k: i64) -> f64 {
+        1.0 - self.cdf(k)
+    }
+
+    /// Values carrying all but a negligible share of the mass, for sums over
+    /// the support.
+    fn bulk(&self) -> RangeInclusive<i64> {
+        let support = self.support();
+        let lower = if support.lower.is_finite() {
+            support.lower as i64
+        } else {
+            self.quantile(1e-15)
+        };
+        let upper = if support.upper.is_finite() {
+            support.upper as i64
+        } else {
+            self.quantile(1.0 - 1e-15)
+        };
+        lower..=upper
+    }
+
+    /// E[f(X)] summed over the [`Discrete::bulk`] of the support.
+    fn expectation(&self, f: &dyn Fn(i64) -> f64) -> f64 {
+        self.bulk()
+            .map(|k| {
+                let p = self.pmf(k);
+                if p > 0.0 {
+                    p * f(k)
+                } else {
+                    0.0
+                }
+            })
+            .sum()
+    }
+
+    /// Shannon entropy -E[ln p(X)] in nats.
+    fn entropy(&self) -> f64 {
+        -self.expectation(&|k| self.ln_pmf(k))
+    }
+
+    fn median(&self) -> f64 {
+        self.quantile(0.5) as f64
+    }
+
+    /// Values of largest probability, all of them when several tie; empty
+    /// when the pmf is flat across the whole support.
+    fn modes(&self) -> Vec<i64> {
+        let mut modes = Vec::new();
+        let mut largest = 0.0;
+        for k in self.bulk() {
+            let p = self.pmf(k);
+            if p > largest * (1.0 + 1e-9) {
+                largest = p;
+                modes.clear();
+                modes.push(k);
+            } else if p > 0.0 && p >= largest * (1.0 - 1e-9) {
+                modes.push(k);
+            }
+        }
+        modes
+    }
+
+    /// Smallest k with P(X <= k) >= p.
+    fn quantile(&self, p: f64) -> i64 {
+        let support = self.support();
+        let lower = support.lower as i64;
+        if p <= 0.0 {
+            return lower;
+        }
+        if p >= 1.0 {
+            return if support.upper.is_finite() {
+                support.upper as i64
+            } else {
+                i64::MAX
+            };
+        }
+
+        // Gallop upwards to bracket the answer, then bisect
+        let mut below = lower - 1;
+        let mut step = 1;
+        let mut above = lower;
+        while self.cdf(above) < p {
+            if support.upper.is_finite() && above >= support.upper as i64 {
+                return support.upper as i64;
+            }
+            if above > i64::MAX / 4 {
+                return above;
+            }
+            below = above;
+            above += step;
+            step *= 2;
+        }
+        while above - below > 1 {
+            let middle = below + (above - below) / 2;
+            if self.cdf(middle) < p {
+                below = middle;
+            } else {
+                above = middle;
+            }
+        }
+        above
+    }
+
+    /// One random value. The default is inverse transform sampling, the
+    /// quantile of a uniform variate; families with a cheaper exact sampler
+    /// override it.
+    fn variate(&self, rng: &mut Rng) -> i64 {
+        self.quantile(rng.next_f64())
+    }
+}
+
+/// Random variate generation from a seedable [`Rng`].
+pub trait Sample {
+    type Value;
+
+    fn draw(&self, rng: &mut Rng) -> Self::Value;
+
+    fn sample(&self, rng: &mut Rng, n: usize) -> Vec<Self::Value> {
+        (0..n).map(|_| self.draw(rng)).collect()
+    }
+}
+
+impl<D: Discrete> Sample for D {
+    type Value = i64;
+
+    fn draw(&self, rng: &mut Rng) -> i64 {
+        self.variate(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::continuous::*;
+    use super::discrete::*;
+    use super::*;
+
+    // Trapezoidal integral of the pdf from `from` to `to`.
+    fn integrate(dist: &dyn Continuous, from: f64, to: f64) -> f64 {
+        let steps = 20_000;
+        let h = (to - from) / steps as f64;
+        (0..steps)
+            .map(|i| {
+                let x = from + i as f64 * h;
+                0.5 * h * (dist.pdf(x) + dist.pdf(x + h))
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_continuous_cdf_matches_integrated_pdf() {
+        let cases: Vec<(Box<dyn Continuous>, f64, f64)> = vec![
+            (Box::new(Exponential::new(1.5)), 0.0, 2.0),
+            (Box::new(Weibull::new(2.0, 1.5)), 0.0, 1.0),
+            (Box::new(Gamma::new(3.0, 0.5)), 0.0, 4.0),
+            (Box::new(ChiSquared::new(4.0)), 0.0, 3.0),
+            (Box::new(Beta::new(2.0, 5.0)), 0.0, 0.3),
+            (Box::new(F::new(5.0, 10.0)), 0.0, 1.2),
+            (Box::new(LogNormal::new(0.0, 0.5)), 0.0, 1.3),
+            (Box::new(Normal::new(1.0, 2.0)), -9.0, 2.0),
+            (Box::new(Uniform::new(-1.0, 3.0)), -1.0, 1.5),
+            (Box::new(Laplace::new(1.0, 0.5)), -6.0, 2.0),
+            (Box::new(Logistic::new(-1.0, 0.7)), -20.0, 0.5),
+            (Box::new(Pareto::new(1.5, 3.0)), 1.5, 4.0),
+            (Box::new(Gumbel::new(0.5, 2.0)), -8.0, 3.0),
+        ];
+        for (dist, from, to) in cases {
+            let expected = integrate(dist.as_ref(), from, to);
+            assert!((dist.cdf(to) - expected).abs() < 1e-4);
+        }
+
+        let t = StudentT::new(5.0);
+        assert!((t.cdf(0.0) - 0.5).abs() < 1e-12);
+        assert!((t.cdf(1.0) - t.cdf(0.0) - integrate(&t, 0.0, 1.0)).abs() < 1e-6);
+
+        let cauchy = Cauchy::new(0.5, 2.0);
+        assert!((cauchy.cdf(0.5) - 0.5).abs() < 1e-12);
+        assert!((cauchy.cdf(3.0) - cauchy.cdf(-1.0) - integrate(&cauchy, -1.0, 3.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_discrete_cdf_matches_summed_pmf() {
+        let cases: Vec<(Box<dyn Discrete>, i64)> = vec![
+            (Box::new(Binomial::new(20, 0.3)), 7),
+            (Box::new(Poisson::new(4.0)), 5),
+            (Box::new(Geometric::new(0.2)), 6),
+            (Box::new(Hypergeometric::new(50, 20, 10)), 4),
+            (Box::new(Bernoulli::new(0.3)), 0),
+            (Box::new(DiscreteUniform::new(-2, 7)), 3),
+            (Box::new(BetaBinomial::new(15, 2.0, 3.5)), 6),
+            (Box::new(Zipf::new(30, 1.2)), 4),
+        ];
+        for (dist, k) in cases {
+            let lower = dist.support().lower as i64;
+            let expected: f64 = (lower..=k).map(|i| dist.pmf(i)).sum();
+            assert!((dist.cdf(k) - expected).abs() < 1e-9);
+        }
+
+        let skellam = Skellam::new(3.0, 1.5);
+        let expected: f64 = (-40..=1).map(|i| skellam.pmf(i)).sum();
+        assert!((skellam.cdf(1) - expected).abs() < 1e-9);
+        assert!(((-40..=40).map(|i| skellam.pmf(i)).sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_quantile_inverts_cdf() {
+        let cases: Vec<Box<dyn Continuous>> = vec![
+            Box::new(Exponential::new(1.5)),
+            Box::new(Weibull::new(2.0, 1.5)),
+            Box::new(Gamma::new(0.5, 2.0)),
+            Box::new(ChiSquared::new(3.0)),
+            Box::new(Beta::new(0.5, 0.5)),
+            Box::new(StudentT::new(3.0)),
+            Box::new(F::new(4.0, 7.0)),
+            Box::new(LogNormal::new(0.3, 0.8)),
+            Box::new(Normal::new(-1.0, 0.5)),
+            Box::new(Uniform::new(-1.0, 3.0)),
+            Box::new(Cauchy::new(0.5, 2.0)),
+            Box::new(Laplace::new(1.0, 0.5)),
+            Box::new(Logistic::new(-1.0, 0.7)),
+            Box::new(Pareto::new(1.5, 3.0)),
+            Box::new(Gumbel::new(0.5, 2.0)),
+        ];
+        for dist in cases {
+            for p in [0.001, 0.05, 0.5, 0.9, 0.999] {
+                let x = dist.quantile(p);
+                assert!((dist.cdf(x) - p).abs() < 1e-9);
+                assert!((dist.sf(x) - (1.0 - p)).abs() < 1e-9);
+            }
+        }
+
+        assert!((Normal::new(0.0, 1.0).quantile(0.975) - 1.959_963_984_540_054).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_discrete_quantile_is_smallest_k_reaching_p() {
+        let cases: Vec<Box<dyn Discrete>> = vec![
+            Box::new(Binomial::new(30, 0.4)),
+            Box::new(Poisson::new(7.5)),
+            Box::new(Geometric::new(0.3)),
+            Box::new(NegativeBinomial::new(4.0, 0.35)),
+            Box::new(Hypergeometric::new(60, 25, 12)),
+            Box::new(Bernoulli::new(0.65)),
+            Box::new(DiscreteUniform::new(3, 12)),
+            Box::new(BetaBinomial::new(25, 0.7, 1.8)),
+            Box::new(Zipf::new(50, 1.5)),
+            Box::new(Skellam::new(2.0, 4.5)),
+        ];
+        for dist in cases {
+            for p in [0.01, 0.25, 0.5, 0.75, 0.99] {
+                let k = dist.quantile(p);
+                assert!(dist.cdf(k) >= p);
+                assert!(dist.cdf(k - 1) < p);
+                assert!((dist.sf(k) - (1.0 - dist.cdf(k))).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_densities_with_large_parameters_stay_finite() {
+        // These overflowed when the normalising constants were built from Γ directly
+        assert!((StudentT::new(500.0).pdf(0.0) - 0.398_742_859_253_527_26).abs() < 1e-12);
+        assert!((F::new(400.0, 400.0).pdf(1.0) - 3.986_930_196_377_884).abs() < 1e-10);
+        assert!((Gamma::new(300.0, 2.0).pdf(150.0) - 0.046_053_092_298_358_62).abs() < 1e-12);
+        assert!(Beta::new(800.0, 900.0).pdf(0.47).is_finite());
+    }
+
+    /// Skewness, excess kurtosis, entropy and M(t) for each t, from
+    /// probabilities `weights` at points `xs`.
+    fn summed_moments(xs: &[f64], weights: &[f64], ts: &[f64]) -> Vec<f64> {
+        let expect = |g: &dyn Fn(f64) -> f64| -> f64 {
+            xs.iter().zip(weights).map(|(&x, &w)| w * g(x)).sum()
+        };
+        let mean = expect(&|x| x);
+        let variance = expect(&|x| (x - mean).powi(2));
+        let mut moments = vec![
+            expect(&|x| (x - mean).powi(3)) / variance.powf(1.5),
+            expect(&|x| (x - mean).powi(4)) / variance.powi(2) - 3.0,
+            -weights.iter().map(|&w| special::xlogy(w, w)).sum::<f64>(),
+        ];
+        moments.extend(ts.iter().map(|&t| expect(&|x| (t * x).exp())));
+        moments
+    }
+
+    #[test]
+    fn test_higher_moments_match_integrated_density() {
+        let ts = [0.2, -0.7];
+        let names = [
+            "skewness",
+            "excess kurtosis",
+            "entropy",
+            "M(0.2)",
+            "M(-0.7)",
+        ];
+        // Each case lists the values that do not exist and the ones that
+        // diverge, which a truncated range cannot show
+        type Case = (
+            Box<dyn Continuous>,
+            f64,
+            f64,
+            &'static [&'static str],
+            &'static [&'static str],
+        );
+        let cases: Vec<Case> = vec![
+            (Box::new(Exponential::new(1.5)), 0.0, 40.0, &[], &[]),
+            (Box::new(Weibull::new(2.0, 1.5)), 0.0, 15.0, &[], &[]),
+            (
+                Box::new(Weibull::new(0.8, 1.0)),
+                0.0,
+                400.0,
+                &[],
+                &["M(0.2)"],
+            ),
+            (Box::new(Gamma::new(3.0, 0.5)), 0.0, 100.0, &[], &[]),
+            (Box::new(ChiSquared::new(4.0)), 0.0, 120.0, &[], &[]),
+            (Box::new(Beta::new(2.0, 5.0)), 0.0, 1.0, &[], &[]),
+            (Box::new(F::new(5.0, 20.0)), 0.0, 400.0, &[], &["M(0.2)"]),
+            (
+                Box::new(F::new(5.0, 4.0)),
+                0.0,
+                4000.0,
+                &["skewness", "excess kurtosis"],
+                &["M(0.2)"],
+            ),
+            (
+                Box::new(StudentT::new(10.0)),
+                -300.0,
+                300.0,
+                &[],
+                &["M(0.2)", "M(-0.7)"],
+            ),
+            (
+                Box::new(StudentT::new(3.5)),
+                -300.0,
+                300.0,
+                &[],
+                &["excess kurtosis", "M(0.2)", "M(-0.7)"],
+            ),
+            (
+                Box::new(LogNormal::new(0.0, 0.4)),
+                0.0,
+                30.0,
+                &[],
+                &["M(0.2)"],
+            ),
+            (Box::new(Normal::new(1.0, 2.0)), -24.0, 26.0, &[], &[]),
+            (Box::new(Uniform::new(-1.0, 3.0)), -1.0, 3.0, &[], &[]),
+            (Box::new(Laplace::new(1.0, 0.5)), -24.0, 26.0, &[], &[]),
+            (Box::new(Logistic::new(-1.0, 0.7)), -60.0, 60.0, &[], &[]),
+            (
+                Box::new(Pareto::new(1.5, 8.0)),
+                1.5,
+                300.0,
+                &[],
+                &["M(0.2)"],
+            ),
+            (Box::new(Gumbel::new(0.5, 2.0)), -20.0, 100.0, &[], &[]),
+        ];
+        for (dist, from, to, undefined, divergent) in cases {
+            let steps = 200_000;
+            let h = (to - from) / steps as f64;
+            let xs: Vec<f64> = (0..steps).map(|i| from + (i as f64 + 0.5) * h).collect();
+            let densities: Vec<f64> = xs.iter().map(|&x| dist.pdf(x)).collect();
+            let weights: Vec<f64> = densities.iter().map(|&f| f * h).collect();
+            let mut expected = summed_moments(&xs, &weights, &ts);
+            // Differential entropy is -∫ f ln f, not -Σ p ln p
+            expected[2] = -densities
+                .iter()
+                .map(|&f| h * special::xlogy(f, f))
+                .sum::<f64>();
+            let mut actual = vec![dist.skewness(), dist.excess_kurtosis(), dist.entropy()];
+            actual.extend(ts.iter().map(|&t| dist.mgf(t)));
+            for ((name, actual), expected) in names.iter().zip(actual).zip(expected) {
+                if undefined.contains(name) {
+                    assert!(actual.is_nan(), "{}: {}", name, actual);
+                } else if divergent.contains(name) {
+                    assert_eq!(actual, f64::INFINITY, "{}", name);
+                } else {
+                    assert!(
+                        (actual - expected).abs() < 2e-3 * expected.abs().max(1.0),
+                        "{}: {} against {}",
+                        name,
+                        actual,
+                        expected
+                    );
+                }
+            }
+
+            assert!((dist.cdf(dist.median()) - 0.5).abs() < 1e-9);
+            for mode in dist.modes() {
+                assert!(dist.pdf(mode) >= dist.pdf(mode + 1e-3));
+                assert!(mode <= from || dist.pdf(mode) >= dist.pdf(mode - 1e-3));
+            }
+        }
+
+        let ts = [0.2, -0.3];
+        let cases: Vec<(Box<dyn Discrete>, i64, i64)> = vec![
+            (Box::new(Binomial::new(20, 0.3)), 0, 20),
+            (Box::new(Poisson::new(4.0)), 0, 80),
+            (Box::new(Geometric::new(0.4)), 1, 200),
+            (Box::new(NegativeBinomial::new(3.0, 0.4)), 0, 300),
+            (Box::new(Hypergeometric::new(50, 20, 10)), 0, 10),
+            (Box::new(Bernoulli::new(0.3)), 0, 1),
+            (Box::new(DiscreteUniform::new(-2, 7)), -2, 7),
+            (Box::new(BetaBinomial::new(15, 2.0, 3.5)), 0, 15),
+            (Box::new(Zipf::new(30, 1.2)), 1, 30),
+            (Box::new(Skellam::new(3.0, 1.5)), -60, 60),
+        ];
+        for (dist, lower, upper) in cases {
+            let xs: Vec<f64> = (lower..=upper).map(|k| k as f64).collect();
+            let weights: Vec<f64> = (lower..=upper).map(|k| dist.pmf(k)).collect();
+            let expected = summed_moments(&xs, &weights, &ts);
+            let mut actual = vec![dist.skewness(), dist.excess_kurtosis(), dist.entropy()];
+            actual.extend(ts.iter().map(|&t| dist.mgf(t)));
+            for (actual, expected) in actual.into_iter().zip(expected) {
+                assert!((actual - expected).abs() < 1e-9 * expected.abs().max(1.0));
+            }
+
+            assert_eq!(dist.median() as i64, dist.quantile(0.5));
+            let largest = weights.iter().cloned().fold(0.0, f64::max);
+            for mode in dist.modes() {
+                assert!((dist.pmf(mode) - largest).abs() < 1e-12);
+            }
+        }
+        // Ties: Poisson(3) peaks at both 2 and 3, a fair coin at both faces
+        assert_eq!(Poisson::new(3.0).modes(), vec![2, 3]);
+        assert_eq!(Bernoulli::new(0.5).modes(), vec![0, 1]);
+
+        // Moments that do not exist, and ones that diverge
+        let f = F::new(5.0, 6.0);
+        assert!(f.skewness().is_infinite() && f.excess_kurtosis().is_infinite());
+        let f = F::new(5.0, 4.0);
+        assert!(f.skewness().is_nan() && f.excess_kurtosis().is_nan());
+        assert!(StudentT::new(3.0).skewness().is_nan());
+        assert!(StudentT::new(3.0).excess_kurtosis().is_infinite());
+        assert_eq!(Cauchy::new(0.0, 1.0).mgf(0.1), f64::INFINITY);
+        assert_eq!(Cauchy::new(0.0, 1.0).mgf(0.0), 1.0);
+        assert_eq!(StudentT::new(3.0).mgf(-0.1), f64::INFINITY);
+        assert_eq!(Pareto::new(1.0, 2.0).mgf(0.0), 1.0);
+        assert!(Exponential::new(1.0).mgf(1.0).is_infinite());
+        assert!(Beta::new(0.5, 0.5).modes() == vec![0.0, 1.0]);
+        assert!(Uniform::new(0.0, 1.0).modes().is_empty());
+    }
+
+    fn sample_moments(draws: &[f64]) -> (f64, f64) {
+        (descriptive::mean(draws), descriptive::variance(draws))
+    }
+
+    #[test]
+    fn test_samples_match_moments() {
+        let mut rng = Rng::new(2024);
+        let n = 40_000;
+
+        let continuous: Vec<(Box<dyn Distribution>, Vec<f64>)> = vec![
+            (
+                Box::new(Exponential::new(2.0)),
+                Exponential::new(2.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Weibull::new(1.5, 2.0)),
+                Weibull::new(1.5, 2.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Gamma::new(0.4, 1.5)),
+                Gamma::new(0.4, 1.5).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Gamma::new(5.0, 0.5)),
+                Gamma::new(5.0, 0.5).sample(&mut rng, n),
+            ),
+            (
+                Box::new(ChiSquared::new(3.0)),
+                ChiSquared::new(3.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Beta::new(2.0, 3.0)),
+                Beta::new(2.0, 3.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(StudentT::new(8.0)),
+                StudentT::new(8.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(F::new(6.0, 12.0)),
+                F::new(6.0, 12.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(LogNormal::new(0.0, 0.4)),
+                LogNormal::new(0.0, 0.4).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Normal::new(3.0, 2.0)),
+                Normal::new(3.0, 2.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Uniform::new(-1.0, 3.0)),
+                Uniform::new(-1.0, 3.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Laplace::new(1.0, 0.5)),
+                Laplace::new(1.0, 0.5).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Logistic::new(-1.0, 0.7)),
+                Logistic::new(-1.0, 0.7).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Pareto::new(1.5, 8.0)),
+                Pareto::new(1.5, 8.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Gumbel::new(0.5, 2.0)),
+                Gumbel::new(0.5, 2.0).sample(&mut rng, n),
+            ),
+        ];
+        let discrete: Vec<(Box<dyn Distribution>, Vec<i64>)> = vec![
+            (
+                Box::new(Binomial::new(40, 0.3)),
+                Binomial::new(40, 0.3).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Poisson::new(6.0)),
+                Poisson::new(6.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Geometric::new(0.25)),
+                Geometric::new(0.25).sample(&mut rng, n),
+            ),
+            (
+                Box::new(NegativeBinomial::new(3.0, 0.4)),
+                NegativeBinomial::new(3.0, 0.4).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Hypergeometric::new(50, 20, 10)),
+                Hypergeometric::new(50, 20, 10).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Bernoulli::new(0.3)),
+                Bernoulli::new(0.3).sample(&mut rng, n),
+            ),
+            (
+                Box::new(DiscreteUniform::new(-2, 7)),
+                DiscreteUniform::new(-2, 7).sample(&mut rng, n),
+            ),
+            (
+                Box::new(BetaBinomial::new(20, 2.0, 3.0)),
+                BetaBinomial::new(20, 2.0, 3.0).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Zipf::new(20, 1.1)),
+                Zipf::new(20, 1.1).sample(&mut rng, n),
+            ),
+            (
+                Box::new(Skellam::new(3.0, 1.5)),
+                Skellam::new(3.0, 1.5).sample(&mut rng, n),
+            ),
+        ];
+        let discrete = discrete
+            .into_iter()
+            .map(|(dist, draws)| (dist, draws.into_iter().map(|k| k as f64).collect()));
+
+        for (dist, draws) in continuous.into_iter().chain(discrete) {
+            let (mean, variance) = sample_moments(&draws);
+            let standard_error = (dist.variance() / n as f64).sqrt();
+            assert!((mean - dist.mean()).abs() < 5.0 * standard_error);
+            assert!((variance / dist.variance() - 1.0).abs() < 0.1);
+        }
+
+        // The Cauchy has no moments to compare against, so check its quartiles instead
+        let cauchy = Cauchy::new(0.5, 2.0);
+        let draws = cauchy.sample(&mut rng, n);
+        for p in [0.25, 0.5, 0.75] {
+            let below = draws.iter().filter(|&&x| x <= cauchy.quantile(p)).count();
+            assert!((below as f64 / n as f64 - p).abs() < 0.01);
+        }
+
+        let pairs = BivariateNormal::new(1.0, -1.0, 2.0, 0.5, 0.6).sample(&mut rng, n);
+        let (xs, ys): (Vec<f64>, Vec<f64>) = pairs.into_iter().unzip();
+        let correlation = descriptive::correlation(&xs, &ys);
+        assert!((correlation - 0.6).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_sampling_is_reproducible() {
+        let dist = Gamma::new(2.5, 1.0);
+        assert_eq!(
+            dist.sample(&mut Rng::new(9), 50),
+            dist.sample(&mut Rng::new(9), 50)
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! Conjugate Bayesian updating.
+//!
+//! Each prior is conjugate to its data model, so the posterior after any
+//! number of observations is the same family with updated parameters, and the
+//! posterior predictive has a closed form too.
+
+use crate::stats::continuous::{Beta, Gamma, Normal};
+use crate::stats::discrete::{BetaBinomial, NegativeBinomial};
+use crate::stats::{Continuous, Discrete};
+
+/// Trials in the posterior predictive of the Beta–Binomial model.
+pub const PREDICTIVE_TRIALS: u64 = 10;
+
+/// A prior, or a posterior, over the parameter of a data model.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Prior {
+    /// Beta(α, β) on the success probability of 0/1 trials.
+    Beta { alpha: f64, beta: f64 },
+    /// Gamma(shape, rate) on the rate of Poisson counts.
+    Gamma { shape: f64, rate: f64 },
+    /// Normal(mean, std_dev) on the mean of normal data whose standard
+    /// deviation `sigma` is known.
+    Normal { mean: f64, std_dev: f64, sigma: f64 },
+}
+
+/// Distribution of the next observation(s), discrete or continuous.
+pub enum Predictive {
+    Discrete(Box<dyn Discrete>),
+    Continuous(Box<dyn Continuous>),
+}
+
+impl Prior {
+    pub fn dist(&self) -> Box<dyn Continuous> {
+        match *self {
+            Prior::Beta { alpha, beta } => Box::new(Beta::new(alpha, beta)),
+            Prior::Gamma { shape, rate } => Box::new(Gamma::new(shape, rate)),
+            Prior::Normal { mean, std_dev, .. } => Box::new(Normal::new(mean, std_dev)),
+        }
+    }
+
+    /// The posterior after `observations`.
+    pub fn update(&self, observations: &[f64]) -> Prior {
+        let n = observations.len() as f64;
+        let sum: f64 = observations.iter().sum();
+        match *self {
+            Prior::Beta { alpha, beta } => Prior::Beta {
+                alpha: alpha + sum,
+                beta: beta + n - sum,
+            },
+            Prior::Gamma { shape, rate } => Prior::Gamma {
+                shape: shape + sum,
+                rate: rate + n,
+            },
+            Prior::Normal {
+                mean,
+                std_dev,
+                sigma,
+            } => {
+                // Precisions add, and the mean is their weighted average
+                let prior_precision = std_dev.powi(-2);
+                let data_precision = n / (sigma * sigma);
+                let precision = prior_precision + data_precision;
+                Prior::Normal {
+                    mean: (prior_precision * mean + sum / (sigma * sigma)) / precision,
+                    std_dev: precision.powf(-0.5),
+                    sigma,
+                }
+            }
+        }
+    }
+
+    /// The likelihood of `observations` as a function of the parameter,
+    /// scaled to integrate to one, or `None` before any observation.
+    pub fn likelihood(&self, observations: &[f64]) -> Option<Box<dyn Continuous>> {
+        if observations.is_empty() {
+            return None;
+        }
+        let n = observations.len() as f64;
+        let sum: f64 = observations.iter().sum();
+        Some(match *self {
+            Prior::Beta { .. } => Box::new(Beta::new(sum + 1.0, n - sum + 1.0)),
+            Prior::Gamma { .. } => Box::new(Gamma::new(sum + 1.0, n)),
+            Prior::Normal { sigma, .. } => Box::new(Normal::new(sum / n, sigma / n.sqrt())),
+        })
+    }
+
+    /// Equal-tailed interval holding probability `level`.
+    pub fn credible_interval(&self, level: f64) -> (f64, f64) {
+        let dist = self.dist();
+        let tail = (1.0 - level) / 2.0;
+        (dist.quantile(tail), dist.quantile(1.0 - tail))
+    }
+
+    /// Distribution of the next observation given this prior (or posterior);
+    /// for 0/1 trials, of the successes in the next [`PREDICTIVE_TRIALS`].
+    pub fn predictive(&self) -> Predictive {
+        match *self {
+            Prior::Beta { alpha, beta } => {
+                Predictive::Discrete(Box::new(BetaBinomial::new(PREDICTIVE_TRIALS, alpha, beta)))
+            }
+            Prior::Gamma { shape, rate } => {
+                Predictive::Discrete(Box::new(NegativeBinomial::new(shape, rate / (rate + 1.0))))
+            }
+            Prior::Normal {
+                mean,
+                std_dev,
+                sigma,
+            } => Predictive::Continuous(Box::new(Normal::new(
+                mean,
+                (std_dev * std_dev + sigma * sigma).sqrt(),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_posteriors() {
+        let trials = [1.0, 0.0, 1.0, 1.0];
+        let posterior = Prior::Beta {
+            alpha: 2.0,
+            beta: 2.0,
+        }
+        .update(&trials);
+        assert_eq!(
+            posterior,
+            Prior::Beta {
+                alpha: 5.0,
+                beta: 3.0
+            }
+        );
+        // Updating one observation at a time ends in the same place
+        let stepwise = trials.iter().fold(
+            Prior::Beta {
+                alpha: 2.0,
+                beta: 2.0,
+            },
+            |prior, &x| prior.update(&[x]),
+        );
+        assert_eq!(stepwise, posterior);
+
+        let posterior = Prior::Gamma {
+            shape: 1.0,
+            rate: 1.0,
+        }
+        .update(&[2.0, 4.0, 3.0]);
+        assert_eq!(
+            posterior,
+            Prior::Gamma {
+                shape: 10.0,
+                rate: 4.0
+            }
+        );
+
+        let posterior = Prior::Normal {
+            mean: 0.0,
+            std_dev: 1.0,
+            sigma: 2.0,
+        }
+        .update(&[1.0, 3.0, 2.0, 2.0]);
+        // Prior precision 1 and data precision 4 / 4 = 1 weigh equally
+        assert_eq!(
+            posterior,
+            Prior::Normal {
+                mean: 1.0,
+                std_dev: 0.5_f64.sqrt(),
+                sigma: 2.0
+            }
+        );
+        let (lower, upper) = posterior.credible_interval(0.95);
+        assert!((upper - 1.0 - 1.959964 * 0.5_f64.sqrt()).abs() < 1e-5);
+        assert!((lower + upper - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_likelihoods_and_predictives() {
+        let prior = Prior::Gamma {
+            shape: 2.0,
+            rate: 0.5,
+        };
+        assert!(prior.likelihood(&[]).is_none());
+        // The Poisson likelihood of counts summing to 6 over 3 draws peaks at 2
+        let likelihood = prior.likelihood(&[1.0, 2.0, 3.0]).unwrap();
+        assert!(likelihood.pdf(2.0) > likelihood.pdf(1.9));
+        assert!(likelihood.pdf(2.0) > likelihood.pdf(2.1));
+
+        // Predictive means equal the posterior means of the data model
+        let Predictive::Discrete(counts) = prior.predictive() else {
+            panic!("Poisson counts are discrete");
+        };
+        assert!((counts.mean() - 4.0).abs() < 1e-12);
+        let Predictive::Discrete(successes) = (Prior::Beta {
+            alpha: 3.0,
+            beta: 1.0,
+        })
+        .predictive() else {
+            panic!("successes are discrete");
+        };
+        assert!((successes.mean() - 7.5).abs() < 1e-12);
+        let Predictive::Continuous(next) = (Prior::Normal {
+            mean: 1.0,
+            std_dev: 3.0,
+            sigma: 4.0,
+        })
+        .predictive() else {
+            panic!("normal data are continuous");
+        };
+        assert!((next.std_dev() - 5.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,147 @@
+//! Confidence intervals for a mean and for a proportion.
+//!
+//! `level` is the confidence level, such as 0.95, and every interval is
+//! two-sided with (1 - level) / 2 in each tail.
+
+use crate::stats::continuous::{Beta, Normal, StudentT};
+use crate::stats::descriptive::{mean, variance};
+use crate::stats::{Continuous, Rng};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConfidenceInterval {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl ConfidenceInterval {
+    pub fn covers(&self, parameter: f64) -> bool {
+        self.lower <= parameter && parameter <= self.upper
+    }
+
+    fn around(center: f64, half_width: f64) -> Self {
+        Self {
+            lower: center - half_width,
+            upper: center + half_width,
+        }
+    }
+}
+
+/// Tail probability on each side.
+fn tail(level: f64) -> f64 {
+    (1.0 - level) / 2.0
+}
+
+fn z_critical(level: f64) -> f64 {
+    Normal::standard().quantile(1.0 - tail(level))
+}
+
+/// Interval for the mean when the population standard deviation is known.
+pub fn z_interval(data: &[f64], std_dev: f64, level: f64) -> ConfidenceInterval {
+    let n = data.len() as f64;
+    ConfidenceInterval::around(mean(data), z_critical(level) * std_dev / n.sqrt())
+}
+
+/// Interval for the mean with the standard deviation estimated from the data.
+pub fn t_interval(data: &[f64], level: f64) -> ConfidenceInterval {
+    let n = data.len() as f64;
+    let t = StudentT::new(n - 1.0).quantile(1.0 - tail(level));
+    ConfidenceInterval::around(mean(data), t * (variance(data) / n).sqrt())
+}
+
+/// Percentile bootstrap interval for the mean from `resamples` resamples.
+pub fn bootstrap_interval(
+    data: &[f64],
+    level: f64,
+    resamples: usize,
+    rng: &mut Rng,
+) -> ConfidenceInterval {
+    let n = data.len();
+    let mut means: Vec<f64> = (0..resamples)
+        .map(|_| {
+            (0..n)
+                .map(|_| data[((rng.next_f64() * n as f64) as usize).min(n - 1)])
+                .sum::<f64>()
+                / n as f64
+        })
+        .collect();
+    means.sort_by(f64::total_cmp);
+    let at = |p: f64| means[((resamples - 1) as f64 * p).round() as usize];
+    ConfidenceInterval {
+        lower: at(tail(level)),
+        upper: at(1.0 - tail(level)),
+    }
+}
+
+/// Normal approximation p̂ ± z √(p̂(1 - p̂)/n), which collapses to a point
+/// when p̂ is 0 or 1.
+pub fn wald_interval(successes: u64, n: u64, level: f64) -> ConfidenceInterval {
+    let p = successes as f64 / n as f64;
+    ConfidenceInterval::around(p, z_critical(level) * (p * (1.0 - p) / n as f64).sqrt())
+}
+
+/// Inversion of the score test, which stays inside [0, 1].
+pub fn wilson_interval(successes: u64, n: u64, level: f64) -> ConfidenceInterval {
+    let (p, n) = (successes as f64 / n as f64, n as f64);
+    let z = z_critical(level);
+    let shrink = 1.0 + z * z / n;
+    let center = (p + z * z / (2.0 * n)) / shrink;
+    let half_width = z * (p * (1.0 - p) / n + z * z / (4.0 * n * n)).sqrt() / shrink;
+    ConfidenceInterval::around(center, half_width)
+}
+
+/// Exact interval from the Beta quantiles, at least `level` for every p.
+pub fn clopper_pearson_interval(successes: u64, n: u64, level: f64) -> ConfidenceInterval {
+    let (x, n) = (successes as f64, n as f64);
+    ConfidenceInterval {
+        lower: if successes == 0 {
+            0.0
+        } else {
+            Beta::new(x, n - x + 1.0).quantile(tail(level))
+        },
+        upper: if x == n {
+            1.0
+        } else {
+            Beta::new(x + 1.0, n - x).quantile(1.0 - tail(level))
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::discrete::Binomial;
+    use crate::stats::Discrete;
+
+    #[test]
+    fn test_mean_intervals() {
+        let data = [5.1, 4.9, 5.6, 5.8, 6.0, 5.3];
+        let interval = t_interval(&data, 0.95);
+        assert!((interval.lower - 5.00600).abs() < 1e-4);
+        assert!((interval.upper - 5.89400).abs() < 1e-4);
+
+        let interval = z_interval(&data, 0.6, 0.95);
+        assert!(
+            (interval.upper - interval.lower - 2.0 * 1.959964 * 0.6 / 6.0_f64.sqrt()).abs() < 1e-5
+        );
+
+        let interval = bootstrap_interval(&data, 0.95, 2000, &mut Rng::new(3));
+        assert!(interval.covers(5.45));
+        assert!(4.9 <= interval.lower && interval.upper <= 6.0);
+    }
+
+    #[test]
+    fn test_proportion_intervals() {
+        let interval = wilson_interval(7, 20, 0.95);
+        assert!((interval.lower - 0.18119).abs() < 1e-4);
+        assert!((interval.upper - 0.56715).abs() < 1e-4);
+
+        let interval = wald_interval(0, 20, 0.95);
+        assert_eq!((interval.lower, interval.upper), (0.0, 0.0));
+
+        // Each Clopper–Pearson end leaves exactly (1 - level) / 2 in its tail
+        let interval = clopper_pearson_interval(7, 20, 0.95);
+        assert!((Binomial::new(20, interval.upper).cdf(7) - 0.025).abs() < 1e-6);
+        assert!((Binomial::new(20, interval.lower).sf(6) - 0.025).abs() < 1e-6);
+        assert_eq!(clopper_pearson_interval(20, 20, 0.95).upper, 1.0);
+    }
+}
@@ -0,0 +1,47 @@
+pub mod beta;
+pub mod bivariate_normal;
+pub mod cauchy;
+pub mod chi_squared;
+pub mod exponential;
+pub mod f;
+pub mod gamma;
+pub mod gumbel;
+pub mod laplace;
+pub mod log_normal;
+pub mod logistic;
+pub mod multivariate_normal;
+pub mod normal;
+pub mod pareto;
+pub mod student;
+pub mod uniform;
+pub mod weibull;
+
+pub use beta::Beta;
+pub use bivariate_normal::BivariateNormal;
+pub use cauchy::Cauchy;
+pub use chi_squared::ChiSquared;
+pub use exponential::Exponential;
+pub use f::F;
+pub use gamma::Gamma;
+pub use gumbel::Gumbel;
+pub use laplace::Laplace;
+pub use log_normal::LogNormal;
+pub use logistic::Logistic;
+pub use multivariate_normal::MultivariateNormal;
+pub use normal::Normal;
+pub use pareto::Pareto;
+pub use student::StudentT;
+pub use uniform::Uniform;
+pub use weibull::Weibull;
+
+use crate::stats::Continuous;
+
+/// E[e^(tX)] for t ≤ 0 on a support bounded below by zero, as
+/// ∫ e^(t Q(u)) du over (0, 1), whose integrand stays within (0, 1].
+pub(crate) fn mgf_by_quadrature(dist: &(impl Continuous + ?Sized), t: f64) -> f64 {
+    let steps = 4000;
+    (0..steps)
+        .map(|i| (t * dist.quantile((i as f64 + 0.5) / steps as f64)).exp())
+        .sum::<f64>()
+        / steps as f64
+}
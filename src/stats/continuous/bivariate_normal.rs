@@ -0,0 +1,141 @@
+use crate::stats::continuous::{ChiSquared, Normal};
+use crate::stats::linalg::symmetric_eigen_2x2;
+use crate::stats::{Continuous, Rng, Sample};
+use serde::{Deserialize, Serialize};
+
+/// Bivariate normal distribution of (X, Y).
+///
+/// Being two-dimensional it does not implement [`crate::stats::Distribution`];
+/// its marginals and conditionals do.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BivariateNormal {
+    mean_x: f64,
+    mean_y: f64,
+    variance_x: f64,
+    variance_y: f64,
+    correlation: f64,
+}
+
+impl BivariateNormal {
+    pub fn new(
+        mean_x: f64,
+        mean_y: f64,
+        variance_x: f64,
+        variance_y: f64,
+        correlation: f64,
+    ) -> Self {
+        Self {
+            mean_x,
+            mean_y,
+            variance_x,
+            variance_y,
+            correlation,
+        }
+    }
+
+    pub fn pdf(&self, x: f64, y: f64) -> f64 {
+        let std_dev_x = self.variance_x.sqrt();
+        let std_dev_y = self.variance_y.sqrt();
+        let rho = self.correlation;
+
+        let z = (x - self.mean_x) / std_dev_x;
+        let w = (y - self.mean_y) / std_dev_y;
+
+        let rho2 = rho.powi(2);
+        let exp = (-0.5 / (1.0 - rho2) * (z.powi(2) - 2.0 * rho * z * w + w.powi(2))).exp();
+        let norm = 1.0 / (2.0 * std_dev_x * std_dev_y * (1.0 - rho2).sqrt() * std::f64::consts::PI);
+
+        norm * exp
+    }
+
+    /// Largest value of the density, reached at the mean.
+    pub fn peak_density(&self) -> f64 {
+        let spread = (self.variance_x * self.variance_y * (1.0 - self.correlation.powi(2))).sqrt();
+        1.0 / (2.0 * std::f64::consts::PI * spread)
+    }
+
+    /// Principal axes of the covariance matrix as (variance, unit direction),
+    /// major axis first.
+    pub fn principal_axes(&self) -> [(f64, [f64; 2]); 2] {
+        let covariance = self.correlation * (self.variance_x * self.variance_y).sqrt();
+        symmetric_eigen_2x2(self.variance_x, covariance, self.variance_y)
+    }
+
+    /// Boundary of the smallest region holding `probability` of the mass,
+    /// traced with `points` vertices.
+    ///
+    /// The region is an ellipse along the principal axes whose squared
+    /// Mahalanobis radius is the χ²(2) quantile of `probability`.
+    pub fn ellipse(&self, probability: f64, points: usize) -> Vec<(f64, f64)> {
+        let radius = ChiSquared::new(2.0).quantile(probability).sqrt();
+        let [(major, u), (minor, v)] = self.principal_axes();
+        let (a, b) = (radius * major.sqrt(), radius * minor.sqrt());
+        (0..=points)
+            .map(|i| {
+                let angle = 2.0 * std::f64::consts::PI * i as f64 / points as f64;
+                let (s, t) = (a * angle.cos(), b * angle.sin());
+                (
+                    self.mean_x + s * u[0] + t * v[0],
+                    self.mean_y + s * u[1] + t * v[1],
+                )
+            })
+            .collect()
+    }
+
+    pub fn marginal_x(&self) -> Normal {
+        Normal::from_variance(self.mean_x, self.variance_x)
+    }
+
+    pub fn marginal_y(&self) -> Normal {
+        Normal::from_variance(self.mean_y, self.variance_y)
+    }
+
+    /// Distribution of Y given X = x.
+    pub fn conditional_y(&self, x: f64) -> Normal {
+        let mean = self.mean_y
+            + self.correlation * (self.variance_y / self.variance_x).sqrt() * (x - self.mean_x);
+        let variance = self.variance_y * (1.0 - self.correlation.powi(2));
+        Normal::from_variance(mean, variance)
+    }
+}
+
+impl Sample for BivariateNormal {
+    type Value = (f64, f64);
+
+    fn draw(&self, rng: &mut Rng) -> (f64, f64) {
+        let z = rng.standard_normal();
+        let w = rng.standard_normal();
+        let rho = self.correlation;
+        let x = self.mean_x + self.variance_x.sqrt() * z;
+        let y = self.mean_y + self.variance_y.sqrt() * (rho * z + (1.0 - rho * rho).sqrt() * w);
+        (x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellipse_encloses_probability() {
+        let dist = BivariateNormal::new(1.0, -1.0, 2.0, 0.5, 0.6);
+        let draws = dist.sample(&mut Rng::new(5), 20_000);
+        assert!((dist.peak_density() - dist.pdf(1.0, -1.0)).abs() < 1e-12);
+        for probability in [0.5, 0.9, 0.95] {
+            let ellipse = dist.ellipse(probability, 64);
+
+            // The ellipse is a contour: the density is the same all along it
+            let level = dist.pdf(ellipse[0].0, ellipse[0].1);
+            assert!(level < dist.peak_density());
+            for &(x, y) in &ellipse {
+                assert!((dist.pdf(x, y) / level - 1.0).abs() < 1e-9);
+            }
+
+            let inside = draws
+                .iter()
+                .filter(|&&(x, y)| dist.pdf(x, y) > level)
+                .count();
+            assert!((inside as f64 / draws.len() as f64 - probability).abs() < 0.01);
+        }
+    }
+}
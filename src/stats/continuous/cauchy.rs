@@ -0,0 +1,87 @@
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+use std::f64::consts::PI;
+
+/// Cauchy distribution with location x₀ and scale γ.
+///
+/// Its tails are so heavy that neither the mean nor the variance exists.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cauchy {
+    location: f64,
+    scale: f64,
+}
+
+impl Cauchy {
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+}
+
+impl Distribution for Cauchy {
+    fn mean(&self) -> f64 {
+        f64::NAN
+    }
+
+    fn variance(&self) -> f64 {
+        f64::NAN
+    }
+
+    fn skewness(&self) -> f64 {
+        f64::NAN
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        f64::NAN
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        // Both tails are polynomial, so E[e^(tX)] diverges away from zero
+        if t == 0.0 {
+            1.0
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+impl Continuous for Cauchy {
+    fn pdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        1.0 / (PI * self.scale * (1.0 + z * z))
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        0.5 + ((x - self.location) / self.scale).atan() / PI
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        0.5 - ((x - self.location) / self.scale).atan() / PI
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        self.location + self.scale * (PI * (p - 0.5)).tan()
+    }
+
+    fn entropy(&self) -> f64 {
+        (4.0 * PI * self.scale).ln()
+    }
+
+    fn median(&self) -> f64 {
+        self.location
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        vec![self.location]
+    }
+}
+
+impl Sample for Cauchy {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        self.location + self.scale * rng.standard_normal() / rng.standard_normal()
+    }
+}
@@ -0,0 +1,85 @@
+use crate::stats::special::{digamma, gamma_p, gamma_q, ln_gamma, xlogy};
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+
+/// Chi-squared distribution with n degrees of freedom.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChiSquared {
+    degrees_of_freedom: f64,
+}
+
+impl ChiSquared {
+    pub fn new(degrees_of_freedom: f64) -> Self {
+        Self { degrees_of_freedom }
+    }
+}
+
+impl Distribution for ChiSquared {
+    fn mean(&self) -> f64 {
+        self.degrees_of_freedom
+    }
+
+    fn variance(&self) -> f64 {
+        2.0 * self.degrees_of_freedom
+    }
+
+    fn skewness(&self) -> f64 {
+        (8.0 / self.degrees_of_freedom).sqrt()
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        12.0 / self.degrees_of_freedom
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        if t < 0.5 {
+            (1.0 - 2.0 * t).powf(-self.degrees_of_freedom / 2.0)
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(0.0, f64::INFINITY)
+    }
+}
+
+impl Continuous for ChiSquared {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let k = self.degrees_of_freedom / 2.0;
+            let lambda: f64 = 0.5;
+            k * lambda.ln() + xlogy(k - 1.0, x) - lambda * x - ln_gamma(k)
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        gamma_p(self.degrees_of_freedom / 2.0, x / 2.0)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        gamma_q(self.degrees_of_freedom / 2.0, x / 2.0)
+    }
+
+    fn entropy(&self) -> f64 {
+        let half = self.degrees_of_freedom / 2.0;
+        half + std::f64::consts::LN_2 + ln_gamma(half) + (1.0 - half) * digamma(half)
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        vec![(self.degrees_of_freedom - 2.0).max(0.0)]
+    }
+}
+
+impl Sample for ChiSquared {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        2.0 * rng.standard_gamma(self.degrees_of_freedom / 2.0)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+
+/// Exponential distribution with rate λ.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Exponential {
+    lambda: f64,
+}
+
+impl Exponential {
+    pub fn new(lambda: f64) -> Self {
+        Self { lambda }
+    }
+}
+
+impl Distribution for Exponential {
+    fn mean(&self) -> f64 {
+        1.0 / self.lambda
+    }
+
+    fn variance(&self) -> f64 {
+        1.0 / (self.lambda * self.lambda)
+    }
+
+    fn skewness(&self) -> f64 {
+        2.0
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        6.0
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        if t < self.lambda {
+            self.lambda / (self.lambda - t)
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(0.0, f64::INFINITY)
+    }
+}
+
+impl Continuous for Exponential {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            self.lambda * (-self.lambda * x).exp()
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            1.0 - (-self.lambda * x).exp()
+        }
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else {
+            (-self.lambda * x).exp()
+        }
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        -(1.0 - p).ln() / self.lambda
+    }
+
+    fn entropy(&self) -> f64 {
+        1.0 - self.lambda.ln()
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        vec![0.0]
+    }
+}
+
+impl Sample for Exponential {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        rng.standard_exponential() / self.lambda
+    }
+}
@@ -0,0 +1,134 @@
+use crate::stats::continuous::mgf_by_quadrature;
+use crate::stats::special::{beta_inc, digamma, ln_beta};
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+
+/// F-distribution with df1 and df2 degrees of freedom.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct F {
+    df1: f64,
+    df2: f64,
+}
+
+impl F {
+    pub fn new(df1: f64, df2: f64) -> Self {
+        Self { df1, df2 }
+    }
+}
+
+impl Distribution for F {
+    fn mean(&self) -> f64 {
+        if self.df2 > 2.0 {
+            self.df2 / (self.df2 - 2.0)
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn variance(&self) -> f64 {
+        let (df1, df2) = (self.df1, self.df2);
+        if df2 > 4.0 {
+            (2.0 * df2.powi(2) * (df1 + df2 - 2.0)) / (df1 * (df2 - 2.0).powi(2) * (df2 - 4.0))
+        } else if df2 > 2.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn skewness(&self) -> f64 {
+        let (df1, df2) = (self.df1, self.df2);
+        if df2 > 6.0 {
+            (2.0 * df1 + df2 - 2.0) * (8.0 * (df2 - 4.0)).sqrt()
+                / ((df2 - 6.0) * (df1 * (df1 + df2 - 2.0)).sqrt())
+        } else if df2 > 4.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        let (df1, df2) = (self.df1, self.df2);
+        if df2 > 8.0 {
+            12.0 * (df1 * (5.0 * df2 - 22.0) * (df1 + df2 - 2.0)
+                + (df2 - 4.0) * (df2 - 2.0).powi(2))
+                / (df1 * (df2 - 6.0) * (df2 - 8.0) * (df1 + df2 - 2.0))
+        } else if df2 > 4.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        // Like Student's t, the right tail is polynomial
+        if t > 0.0 {
+            f64::INFINITY
+        } else if t == 0.0 {
+            1.0
+        } else {
+            mgf_by_quadrature(self, t)
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(0.0, f64::INFINITY)
+    }
+}
+
+impl Continuous for F {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let (df1, df2) = (self.df1, self.df2);
+        if x <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        let ln_numerator = df1 * (df1 * x).ln() + df2 * df2.ln();
+        let ln_denominator = (df1 + df2) * (df1 * x + df2).ln();
+        0.5 * (ln_numerator - ln_denominator) - x.ln() - ln_beta(df1 / 2.0, df2 / 2.0)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let (df1, df2) = (self.df1, self.df2);
+        beta_inc(df1 / 2.0, df2 / 2.0, df1 * x / (df1 * x + df2))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        let (df1, df2) = (self.df1, self.df2);
+        beta_inc(df2 / 2.0, df1 / 2.0, df2 / (df1 * x + df2))
+    }
+
+    fn entropy(&self) -> f64 {
+        let (a, b) = (self.df1 / 2.0, self.df2 / 2.0);
+        (self.df2 / self.df1).ln() + ln_beta(a, b) + (1.0 - a) * digamma(a) - (1.0 + b) * digamma(b)
+            + (a + b) * digamma(a + b)
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        let (df1, df2) = (self.df1, self.df2);
+        if df1 > 2.0 {
+            vec![(df1 - 2.0) / df1 * df2 / (df2 + 2.0)]
+        } else {
+            vec![0.0]
+        }
+    }
+}
+
+impl Sample for F {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        let numerator = 2.0 * rng.standard_gamma(self.df1 / 2.0) / self.df1;
+        let denominator = 2.0 * rng.standard_gamma(self.df2 / 2.0) / self.df2;
+        numerator / denominator
+    }
+}
@@ -0,0 +1,86 @@
+use crate::stats::special::{ln_gamma, EULER_GAMMA};
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+use std::f64::consts::PI;
+
+/// Gumbel (type I extreme value) distribution with location μ and scale β.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gumbel {
+    location: f64,
+    scale: f64,
+}
+
+impl Gumbel {
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+}
+
+impl Distribution for Gumbel {
+    fn mean(&self) -> f64 {
+        self.location + self.scale * EULER_GAMMA
+    }
+
+    fn variance(&self) -> f64 {
+        (PI * self.scale).powi(2) / 6.0
+    }
+
+    fn skewness(&self) -> f64 {
+        // 12√6 ζ(3) / π³
+        1.139_547_099_404_648_7
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        2.4
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        if self.scale * t < 1.0 {
+            (ln_gamma(1.0 - self.scale * t) + self.location * t).exp()
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+impl Continuous for Gumbel {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        -(z + (-z).exp()) - self.scale.ln()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        (-(-(x - self.location) / self.scale).exp()).exp()
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        -(-(-(x - self.location) / self.scale).exp()).exp_m1()
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        self.location - self.scale * (-p.ln()).ln()
+    }
+
+    fn entropy(&self) -> f64 {
+        self.scale.ln() + EULER_GAMMA + 1.0
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        vec![self.location]
+    }
+}
+
+impl Sample for Gumbel {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        self.location - self.scale * rng.standard_exponential().ln()
+    }
+}
@@ -0,0 +1,96 @@
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+
+/// Laplace (double exponential) distribution with location μ and scale b.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Laplace {
+    location: f64,
+    scale: f64,
+}
+
+impl Laplace {
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+}
+
+impl Distribution for Laplace {
+    fn mean(&self) -> f64 {
+        self.location
+    }
+
+    fn variance(&self) -> f64 {
+        2.0 * self.scale * self.scale
+    }
+
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        3.0
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        if (self.scale * t).abs() < 1.0 {
+            (self.location * t).exp() / (1.0 - (self.scale * t).powi(2))
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+impl Continuous for Laplace {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        -(x - self.location).abs() / self.scale - (2.0 * self.scale).ln()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z < 0.0 {
+            0.5 * z.exp()
+        } else {
+            1.0 - 0.5 * (-z).exp()
+        }
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.cdf(2.0 * self.location - x)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        if p < 0.5 {
+            self.location + self.scale * (2.0 * p).ln()
+        } else {
+            self.location - self.scale * (2.0 * (1.0 - p)).ln()
+        }
+    }
+
+    fn entropy(&self) -> f64 {
+        1.0 + (2.0 * self.scale).ln()
+    }
+
+    fn median(&self) -> f64 {
+        self.location
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        vec![self.location]
+    }
+}
+
+impl Sample for Laplace {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        // The difference of two independent exponentials is Laplace
+        self.location + self.scale * (rng.standard_exponential() - rng.standard_exponential())
+    }
+}
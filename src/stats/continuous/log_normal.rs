@@ -0,0 +1,105 @@
+use crate::stats::continuous::mgf_by_quadrature;
+use crate::stats::special::{erfc, probit};
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+use std::f64::consts::PI;
+
+/// Log-normal distribution whose logarithm has mean μ and standard deviation σ.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogNormal {
+    mu: f64,
+    sigma: f64,
+}
+
+impl LogNormal {
+    pub fn new(mu: f64, sigma: f64) -> Self {
+        Self { mu, sigma }
+    }
+}
+
+impl Distribution for LogNormal {
+    fn mean(&self) -> f64 {
+        (self.mu + self.sigma.powi(2) / 2.0).exp()
+    }
+
+    fn variance(&self) -> f64 {
+        let sigma2 = self.sigma.powi(2);
+        (sigma2.exp() - 1.0) * (2.0 * self.mu + sigma2).exp()
+    }
+
+    fn skewness(&self) -> f64 {
+        let w = self.sigma.powi(2).exp();
+        (w + 2.0) * (w - 1.0).sqrt()
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        let w = self.sigma.powi(2).exp();
+        w.powi(4) + 2.0 * w.powi(3) + 3.0 * w.powi(2) - 6.0
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        // The right tail decays slower than any exponential
+        if t > 0.0 {
+            f64::INFINITY
+        } else if t == 0.0 {
+            1.0
+        } else {
+            mgf_by_quadrature(self, t)
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(0.0, f64::INFINITY)
+    }
+}
+
+impl Continuous for LogNormal {
+    fn pdf(&self, x: f64) -> f64 {
+        let (mu, sigma) = (self.mu, self.sigma);
+        if x <= 0.0 {
+            0.0
+        } else {
+            (1.0 / (x * sigma * (2.0 * std::f64::consts::PI).sqrt()))
+                * (-((x.ln() - mu).powi(2)) / (2.0 * sigma.powi(2))).exp()
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            0.5 * erfc(-(x.ln() - self.mu) / (self.sigma * std::f64::consts::SQRT_2))
+        }
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            0.5 * erfc((x.ln() - self.mu) / (self.sigma * std::f64::consts::SQRT_2))
+        }
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        (self.mu + self.sigma * probit(p)).exp()
+    }
+
+    fn entropy(&self) -> f64 {
+        self.mu + 0.5 * (1.0 + (2.0 * PI * self.sigma.powi(2)).ln())
+    }
+
+    fn median(&self) -> f64 {
+        self.mu.exp()
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        vec![(self.mu - self.sigma.powi(2)).exp()]
+    }
+}
+
+impl Sample for LogNormal {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        (self.mu + self.sigma * rng.standard_normal()).exp()
+    }
+}
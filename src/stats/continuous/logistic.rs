@@ -0,0 +1,89 @@
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+use std::f64::consts::PI;
+
+/// Logistic distribution with location μ and scale s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Logistic {
+    location: f64,
+    scale: f64,
+}
+
+impl Logistic {
+    pub fn new(location: f64, scale: f64) -> Self {
+        Self { location, scale }
+    }
+}
+
+impl Distribution for Logistic {
+    fn mean(&self) -> f64 {
+        self.location
+    }
+
+    fn variance(&self) -> f64 {
+        (self.scale * PI).powi(2) / 3.0
+    }
+
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        1.2
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        let st = self.scale * t;
+        if st == 0.0 {
+            1.0
+        } else if st.abs() < 1.0 {
+            // e^(μt) B(1 - st, 1 + st)
+            (self.location * t).exp() * PI * st / (PI * st).sin()
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+impl Continuous for Logistic {
+    fn pdf(&self, x: f64) -> f64 {
+        // Symmetric in z, so use -|z| to keep exp from overflowing
+        let e = (-((x - self.location) / self.scale).abs()).exp();
+        e / (self.scale * (1.0 + e).powi(2))
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        1.0 / (1.0 + (-(x - self.location) / self.scale).exp())
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        1.0 / (1.0 + ((x - self.location) / self.scale).exp())
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        self.location + self.scale * (p / (1.0 - p)).ln()
+    }
+
+    fn entropy(&self) -> f64 {
+        self.scale.ln() + 2.0
+    }
+
+    fn median(&self) -> f64 {
+        self.location
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        vec![self.location]
+    }
+}
+
+impl Sample for Logistic {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        self.quantile(rng.next_f64_nonzero())
+    }
+}
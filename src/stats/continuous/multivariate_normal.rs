@@ -0,0 +1,197 @@
+use crate::stats::continuous::Normal;
+use crate::stats::linalg::{cholesky, cholesky_solve, solve_lower, submatrix, Matrix};
+use crate::stats::{Rng, Sample};
+
+/// d-dimensional normal distribution with mean vector μ and covariance Σ.
+///
+/// Σ is factored once as L Lᵀ (Cholesky); the density, sampling and
+/// conditioning all work through L.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultivariateNormal {
+    mean: Vec<f64>,
+    covariance: Matrix,
+    cholesky: Matrix,
+}
+
+impl MultivariateNormal {
+    /// Returns `None` if the dimensions disagree or Σ is not symmetric
+    /// positive definite.
+    pub fn new(mean: Vec<f64>, covariance: Matrix) -> Option<Self> {
+        if covariance.len() != mean.len() {
+            return None;
+        }
+        let cholesky = cholesky(&covariance)?;
+        Some(Self {
+            mean,
+            covariance,
+            cholesky,
+        })
+    }
+
+    pub fn dim(&self) -> usize {
+        self.mean.len()
+    }
+
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    pub fn covariance(&self) -> &[Vec<f64>] {
+        &self.covariance
+    }
+
+    pub fn pdf(&self, x: &[f64]) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// -(d ln 2π + ln |Σ| + (x - μ)ᵀ Σ⁻¹ (x - μ)) / 2
+    pub fn ln_pdf(&self, x: &[f64]) -> f64 {
+        let centered: Vec<f64> = x.iter().zip(&self.mean).map(|(x, m)| x - m).collect();
+        // With L z = x - μ, the quadratic form is |z|²
+        let z = solve_lower(&self.cholesky, &centered);
+        let quadratic: f64 = z.iter().map(|z| z * z).sum();
+        let ln_determinant: f64 = (0..self.dim())
+            .map(|i| 2.0 * self.cholesky[i][i].ln())
+            .sum();
+        -0.5 * (self.dim() as f64 * (2.0 * std::f64::consts::PI).ln() + ln_determinant + quadratic)
+    }
+
+    /// Joint distribution of the components listed in `indices`.
+    pub fn marginal(&self, indices: &[usize]) -> Self {
+        let mean = indices.iter().map(|&i| self.mean[i]).collect();
+        let covariance = submatrix(&self.covariance, indices, indices);
+        Self::new(mean, covariance).expect("principal submatrices of Σ are positive definite")
+    }
+
+    /// Distribution of the single component `index`.
+    pub fn marginal_normal(&self, index: usize) -> Normal {
+        Normal::from_variance(self.mean[index], self.covariance[index][index])
+    }
+
+    /// Distribution of the remaining components given X[given[k]] = values[k].
+    ///
+    /// μ_a + Σ_ab Σ_bb⁻¹ (x_b - μ_b) and Σ_aa - Σ_ab Σ_bb⁻¹ Σ_ba, where b are
+    /// the observed components and a the rest, in their original order.
+    pub fn conditional(&self, given: &[usize], values: &[f64]) -> Self {
+        let rest: Vec<usize> = (0..self.dim()).filter(|i| !given.contains(i)).collect();
+        let sigma_bb = cholesky(&submatrix(&self.covariance, given, given))
+            .expect("principal submatrices of Σ are positive definite");
+        let sigma_ab = submatrix(&self.covariance, &rest, given);
+
+        let centered: Vec<f64> = given
+            .iter()
+            .zip(values)
+            .map(|(&i, x)| x - self.mean[i])
+            .collect();
+        let weights = cholesky_solve(&sigma_bb, &centered);
+        let mean = rest
+            .iter()
+            .zip(&sigma_ab)
+            .map(|(&i, row)| self.mean[i] + dot(row, &weights))
+            .collect();
+
+        // Σ_bb⁻¹ Σ_ba, one column per remaining component
+        let projections: Vec<Vec<f64>> = sigma_ab
+            .iter()
+            .map(|row| cholesky_solve(&sigma_bb, row))
+            .collect();
+        let covariance = rest
+            .iter()
+            .enumerate()
+            .map(|(r, &i)| {
+                rest.iter()
+                    .enumerate()
+                    .map(|(c, &j)| self.covariance[i][j] - dot(&sigma_ab[r], &projections[c]))
+                    .collect()
+            })
+            .collect();
+
+        Self::new(mean, covariance).expect("Schur complements of Σ are positive definite")
+    }
+}
+
+impl Sample for MultivariateNormal {
+    type Value = Vec<f64>;
+
+    /// μ + L z with z standard normal.
+    fn draw(&self, rng: &mut Rng) -> Vec<f64> {
+        let z: Vec<f64> = (0..self.dim()).map(|_| rng.standard_normal()).collect();
+        self.mean
+            .iter()
+            .zip(&self.cholesky)
+            .map(|(m, row)| m + dot(row, &z))
+            .collect()
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::continuous::BivariateNormal;
+    use crate::stats::descriptive::covariance;
+    use crate::stats::{Continuous, Distribution};
+
+    fn example() -> MultivariateNormal {
+        MultivariateNormal::new(
+            vec![1.0, -1.0, 0.5],
+            vec![
+                vec![2.0, 0.6, 0.3],
+                vec![0.6, 1.0, -0.2],
+                vec![0.3, -0.2, 1.5],
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_density_matches_bivariate_and_univariate() {
+        let bivariate = BivariateNormal::new(1.0, -1.0, 2.0, 1.0, 0.6 / 2.0_f64.sqrt());
+        let marginal = example().marginal(&[0, 1]);
+        for (x, y) in [(0.0, 0.0), (1.5, -2.0), (-1.0, 0.7)] {
+            assert!((marginal.pdf(&[x, y]) - bivariate.pdf(x, y)).abs() < 1e-12);
+        }
+
+        let normal = example().marginal_normal(2);
+        let univariate = example().marginal(&[2]);
+        assert!((univariate.pdf(&[1.3]) - normal.pdf(1.3)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_conditional_matches_bivariate() {
+        let bivariate = BivariateNormal::new(1.0, -1.0, 2.0, 1.0, 0.6 / 2.0_f64.sqrt());
+        let expected = bivariate.conditional_y(2.0);
+        let conditional = example().marginal(&[0, 1]).conditional(&[0], &[2.0]);
+        assert!((conditional.mean()[0] - expected.mean()).abs() < 1e-12);
+        assert!((conditional.covariance()[0][0] - expected.variance()).abs() < 1e-12);
+
+        // Conditioning on two components leaves a one-dimensional normal
+        let conditional = example().conditional(&[0, 2], &[0.0, 1.0]);
+        assert_eq!(conditional.dim(), 1);
+        assert!(conditional.covariance()[0][0] < 1.0);
+    }
+
+    #[test]
+    fn test_samples_match_covariance() {
+        let dist = example();
+        let draws = dist.sample(&mut Rng::new(11), 40_000);
+        let column = |i: usize| draws.iter().map(|x| x[i]).collect::<Vec<f64>>();
+        for i in 0..3 {
+            for j in 0..3 {
+                let sample = covariance(&column(i), &column(j));
+                assert!((sample - dist.covariance()[i][j]).abs() < 0.05);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_invalid_covariance() {
+        assert!(
+            MultivariateNormal::new(vec![0.0, 0.0], vec![vec![1.0, 2.0], vec![2.0, 1.0]]).is_none()
+        );
+        assert!(MultivariateNormal::new(vec![0.0], vec![vec![1.0, 0.0], vec![0.0, 1.0]]).is_none());
+    }
+}
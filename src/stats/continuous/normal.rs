@@ -0,0 +1,113 @@
+use crate::stats::special::{erfc, probit};
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+
+/// Normal distribution with mean μ and standard deviation σ.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    pub fn new(mean: f64, std_dev: f64) -> Self {
+        Self { mean, std_dev }
+    }
+
+    pub fn from_variance(mean: f64, variance: f64) -> Self {
+        Self::new(mean, variance.sqrt())
+    }
+
+    /// Standard normal distribution, N(0, 1).
+    pub fn standard() -> Self {
+        Self::new(0.0, 1.0)
+    }
+
+    /// Number of standard deviations `x` lies from the mean.
+    pub fn z_score(&self, x: f64) -> f64 {
+        (x - self.mean) / self.std_dev
+    }
+}
+
+impl Distribution for Normal {
+    fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    fn variance(&self) -> f64 {
+        self.std_dev * self.std_dev
+    }
+
+    fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        0.0
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        (self.mean * t + 0.5 * (self.std_dev * t).powi(2)).exp()
+    }
+
+    fn support(&self) -> Support {
+        Support::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+impl Continuous for Normal {
+    fn pdf(&self, x: f64) -> f64 {
+        let sigma = self.std_dev;
+        let z = (x - self.mean) / sigma;
+        (-0.5 * z.powi(2)).exp() / (sigma * (2.0 * std::f64::consts::PI).sqrt())
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        0.5 * erfc(-(x - self.mean) / (self.std_dev * std::f64::consts::SQRT_2))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        0.5 * erfc((x - self.mean) / (self.std_dev * std::f64::consts::SQRT_2))
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        self.mean + self.std_dev * probit(p)
+    }
+
+    fn entropy(&self) -> f64 {
+        0.5 * (2.0 * std::f64::consts::PI * std::f64::consts::E * self.variance()).ln()
+    }
+
+    fn median(&self) -> f64 {
+        self.mean
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mean]
+    }
+}
+
+impl Sample for Normal {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        self.mean + self.std_dev * rng.standard_normal()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_normal_probabilities() {
+        let z = Normal::standard();
+        assert!((z.cdf(0.0) - 0.5).abs() < 1e-15);
+        assert!((z.cdf(1.0) - z.cdf(-1.0) - 0.682_689_492_137_085_9).abs() < 1e-12);
+        assert!((z.cdf(1.959_963_984_540_054) - 0.975).abs() < 1e-12);
+        assert!((Normal::new(3.0, 2.0).z_score(7.0) - 2.0).abs() < 1e-15);
+    }
+}
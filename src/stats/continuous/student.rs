@@ -0,0 +1,120 @@
+use crate::stats::special::{beta_inc, digamma, ln_beta, ln_gamma};
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+
+/// Student's t-distribution with n degrees of freedom.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StudentT {
+    degrees_of_freedom: f64,
+}
+
+impl StudentT {
+    pub fn new(degrees_of_freedom: f64) -> Self {
+        Self { degrees_of_freedom }
+    }
+}
+
+impl Distribution for StudentT {
+    fn mean(&self) -> f64 {
+        if self.degrees_of_freedom > 1.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn variance(&self) -> f64 {
+        let n = self.degrees_of_freedom;
+        if n > 2.0 {
+            n / (n - 2.0)
+        } else if n > 1.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn skewness(&self) -> f64 {
+        if self.degrees_of_freedom > 3.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        let n = self.degrees_of_freedom;
+        if n > 4.0 {
+            6.0 / (n - 4.0)
+        } else if n > 2.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        // Polynomial tails on both sides, so only M(0) converges
+        if t == 0.0 {
+            1.0
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+impl Continuous for StudentT {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let n = self.degrees_of_freedom;
+        let ln_coefficient =
+            ln_gamma((n + 1.0) / 2.0) - ln_gamma(n / 2.0) - 0.5 * (std::f64::consts::PI * n).ln();
+        let exponent = -(n + 1.0) / 2.0;
+        ln_coefficient + exponent * ((x * x) / n).ln_1p()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        let n = self.degrees_of_freedom;
+        let tail = 0.5 * beta_inc(n / 2.0, 0.5, n / (n + x * x));
+        if x > 0.0 {
+            1.0 - tail
+        } else {
+            tail
+        }
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.cdf(-x)
+    }
+
+    fn entropy(&self) -> f64 {
+        let n = self.degrees_of_freedom;
+        (n + 1.0) / 2.0 * (digamma((n + 1.0) / 2.0) - digamma(n / 2.0))
+            + 0.5 * n.ln()
+            + ln_beta(n / 2.0, 0.5)
+    }
+
+    fn median(&self) -> f64 {
+        0.0
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        vec![0.0]
+    }
+}
+
+impl Sample for StudentT {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        let n = self.degrees_of_freedom;
+        let chi_squared = 2.0 * rng.standard_gamma(n / 2.0);
+        rng.standard_normal() / (chi_squared / n).sqrt()
+    }
+}
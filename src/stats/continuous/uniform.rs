@@ -0,0 +1,86 @@
+use crate::stats::{Continuous, Distribution, Rng, Sample, Support};
+
+/// Continuous uniform distribution on [a, b].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Uniform {
+    lower: f64,
+    upper: f64,
+}
+
+impl Uniform {
+    pub fn new(lower: f64, upper: f64) -> Self {
+        Self { lower, upper }
+    }
+}
+
+impl Distribution for Uniform {
+    fn mean(&self) -> f64 {
+        (self.lower + self.upper) / 2.0
+    }
+
+    fn variance(&self) -> f64 {
+        (self.upper - self.lower).powi(2) / 12.0
+    }
+
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        -1.2
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        if t == 0.0 {
+            1.0
+        } else {
+            ((t * self.upper).exp() - (t * self.lower).exp()) / (t * (self.upper - self.lower))
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(self.lower, self.upper)
+    }
+}
+
+impl Continuous for Uniform {
+    fn pdf(&self, x: f64) -> f64 {
+        if self.support().contains(x) {
+            1.0 / (self.upper - self.lower)
+        } else {
+            0.0
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        ((x - self.lower) / (self.upper - self.lower)).clamp(0.0, 1.0)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        ((self.upper - x) / (self.upper - self.lower)).clamp(0.0, 1.0)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        self.lower + p * (self.upper - self.lower)
+    }
+
+    fn entropy(&self) -> f64 {
+        (self.upper - self.lower).ln()
+    }
+
+    fn median(&self) -> f64 {
+        self.mean()
+    }
+
+    fn modes(&self) -> Vec<f64> {
+        Vec::new()
+    }
+}
+
+impl Sample for Uniform {
+    type Value = f64;
+
+    fn draw(&self, rng: &mut Rng) -> f64 {
+        self.quantile(rng.next_f64())
+    }
+}
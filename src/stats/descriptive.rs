@@ -0,0 +1,136 @@
+//! Summary statistics of observed or simulated data.
+
+use std::collections::BTreeMap;
+
+pub fn mean(data: &[f64]) -> f64 {
+    data.iter().sum::<f64>() / data.len() as f64
+}
+
+/// Unbiased sample variance (denominator n - 1).
+pub fn variance(data: &[f64]) -> f64 {
+    let n = data.len() as f64;
+    if n < 2.0 {
+        return f64::NAN;
+    }
+    let mean = mean(data);
+    data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+}
+
+/// Unbiased sample covariance of paired observations.
+pub fn covariance(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len().min(y.len()) as f64;
+    if n < 2.0 {
+        return f64::NAN;
+    }
+    let (mean_x, mean_y) = (mean(x), mean(y));
+    x.iter()
+        .zip(y)
+        .map(|(a, b)| (a - mean_x) * (b - mean_y))
+        .sum::<f64>()
+        / (n - 1.0)
+}
+
+/// Pearson correlation coefficient of paired observations.
+pub fn correlation(x: &[f64], y: &[f64]) -> f64 {
+    covariance(x, y) / (variance(x) * variance(y)).sqrt()
+}
+
+/// One bar of a [`histogram`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bin {
+    pub lower: f64,
+    pub upper: f64,
+    pub count: usize,
+    /// count / (n * width), so the bars integrate to one like a density
+    pub density: f64,
+}
+
+/// Equal-width histogram of `data` over `[lower, upper)`.
+///
+/// Densities are normalised by the total number of observations, including
+/// those outside the range, so the bars are directly comparable with a pdf.
+pub fn histogram(data: &[f64], lower: f64, upper: f64, bins: usize) -> Vec<Bin> {
+    let width = (upper - lower) / bins as f64;
+    let mut counts = vec![0; bins];
+    for &x in data {
+        if x >= lower && x < upper {
+            let index = (((x - lower) / width) as usize).min(bins - 1);
+            counts[index] += 1;
+        }
+    }
+    let n = data.len() as f64;
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| Bin {
+            lower: lower + i as f64 * width,
+            upper: lower + (i + 1) as f64 * width,
+            count,
+            density: count as f64 / (n * width),
+        })
+        .collect()
+}
+
+/// Proportion of `data` less than or equal to `x`, with `data` sorted ascending.
+pub fn empirical_cdf(sorted: &[f64], x: f64) -> f64 {
+    sorted.partition_point(|&value| value <= x) as f64 / sorted.len() as f64
+}
+
+/// Number of occurrences of each distinct value, in ascending order.
+pub fn frequencies(values: &[i64]) -> BTreeMap<i64, usize> {
+    let mut counts = BTreeMap::new();
+    for &value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_and_variance() {
+        let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(mean(&data), 5.0);
+        assert!((variance(&data) - 32.0 / 7.0).abs() < 1e-12);
+        assert!(variance(&[1.0]).is_nan());
+    }
+
+    #[test]
+    fn test_correlation() {
+        let x = [1.0, 2.0, 3.0, 4.0];
+        assert!((correlation(&x, &[2.0, 4.0, 6.0, 8.0]) - 1.0).abs() < 1e-12);
+        assert!((correlation(&x, &[4.0, 3.0, 2.0, 1.0]) + 1.0).abs() < 1e-12);
+        assert!((covariance(&x, &x) - variance(&x)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_histogram_integrates_to_one() {
+        let data: Vec<f64> = (0..1000).map(|i| i as f64 / 1000.0).collect();
+        let bins = histogram(&data, 0.0, 1.0, 20);
+        assert_eq!(bins.iter().map(|bin| bin.count).sum::<usize>(), 1000);
+        let area: f64 = bins
+            .iter()
+            .map(|bin| bin.density * (bin.upper - bin.lower))
+            .sum();
+        assert!((area - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_empirical_cdf() {
+        let sorted = [1.0, 2.0, 2.0, 3.0];
+        assert_eq!(empirical_cdf(&sorted, 0.5), 0.0);
+        assert_eq!(empirical_cdf(&sorted, 2.0), 0.75);
+        assert_eq!(empirical_cdf(&sorted, 3.0), 1.0);
+    }
+
+    #[test]
+    fn test_frequencies() {
+        let counts = frequencies(&[3, 1, 3, -2, 1_000_000_000, 3]);
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            [(-2, 1), (1, 1), (3, 3), (1_000_000_000, 1)]
+        );
+    }
+}
@@ -0,0 +1,30 @@
+pub mod bernoulli;
+pub mod beta_binomial;
+pub mod binomial;
+pub mod discrete_uniform;
+pub mod geometric;
+pub mod hypergeometric;
+pub mod negative_binomial;
+pub mod poisson;
+pub mod skellam;
+pub mod zipf;
+
+pub use bernoulli::Bernoulli;
+pub use beta_binomial::BetaBinomial;
+pub use binomial::Binomial;
+pub use discrete_uniform::DiscreteUniform;
+pub use geometric::Geometric;
+pub use hypergeometric::Hypergeometric;
+pub use negative_binomial::NegativeBinomial;
+pub use poisson::Poisson;
+pub use skellam::Skellam;
+pub use zipf::Zipf;
+
+use crate::stats::Discrete;
+
+/// E[(X - μ)^order] / σ^order summed over the bulk of the support, for
+/// families without a closed form.
+pub(crate) fn standardised_moment(dist: &(impl Discrete + ?Sized), order: i32) -> f64 {
+    let mean = dist.mean();
+    dist.expectation(&|k| (k as f64 - mean).powi(order)) / dist.std_dev().powi(order)
+}
@@ -0,0 +1,65 @@
+use crate::stats::{Discrete, Distribution, Rng, Support};
+
+/// Outcome of a single trial with success probability p.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bernoulli {
+    p: f64,
+}
+
+impl Bernoulli {
+    pub fn new(p: f64) -> Self {
+        Self { p }
+    }
+}
+
+impl Distribution for Bernoulli {
+    fn mean(&self) -> f64 {
+        self.p
+    }
+
+    fn variance(&self) -> f64 {
+        self.p * (1.0 - self.p)
+    }
+
+    fn skewness(&self) -> f64 {
+        let q = 1.0 - self.p;
+        (q - self.p) / (self.p * q).sqrt()
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        let pq = self.p * (1.0 - self.p);
+        (1.0 - 6.0 * pq) / pq
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        1.0 - self.p + self.p * t.exp()
+    }
+
+    fn support(&self) -> Support {
+        Support::new(0.0, 1.0)
+    }
+}
+
+impl Discrete for Bernoulli {
+    fn pmf(&self, k: i64) -> f64 {
+        match k {
+            0 => 1.0 - self.p,
+            1 => self.p,
+            _ => 0.0,
+        }
+    }
+
+    fn cdf(&self, k: i64) -> f64 {
+        if k < 0 {
+            0.0
+        } else if k == 0 {
+            1.0 - self.p
+        } else {
+            1.0
+        }
+    }
+
+    fn variate(&self, rng: &mut Rng) -> i64 {
+        (rng.next_f64() < self.p) as i64
+    }
+}
@@ -0,0 +1,62 @@
+use crate::stats::discrete::standardised_moment;
+use crate::stats::special::{ln_beta, ln_choose};
+use crate::stats::{Discrete, Distribution, Support};
+
+/// Binomial count whose success probability is itself Beta(α, β) distributed.
+///
+/// The extra randomness in p makes the variance larger than the binomial's,
+/// which is why it is the standard model for overdispersed counts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BetaBinomial {
+    n: u64,
+    alpha: f64,
+    beta: f64,
+}
+
+impl BetaBinomial {
+    pub fn new(n: u64, alpha: f64, beta: f64) -> Self {
+        Self { n, alpha, beta }
+    }
+}
+
+impl Distribution for BetaBinomial {
+    fn mean(&self) -> f64 {
+        self.n as f64 * self.alpha / (self.alpha + self.beta)
+    }
+
+    fn variance(&self) -> f64 {
+        let (n, a, b) = (self.n as f64, self.alpha, self.beta);
+        n * a * b * (a + b + n) / ((a + b).powi(2) * (a + b + 1.0))
+    }
+
+    fn skewness(&self) -> f64 {
+        standardised_moment(self, 3)
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        standardised_moment(self, 4) - 3.0
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        self.expectation(&|k| (t * k as f64).exp())
+    }
+
+    fn support(&self) -> Support {
+        Support::new(0.0, self.n as f64)
+    }
+}
+
+impl Discrete for BetaBinomial {
+    fn pmf(&self, k: i64) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    fn ln_pmf(&self, k: i64) -> f64 {
+        if k < 0 || k as u64 > self.n {
+            return f64::NEG_INFINITY;
+        }
+        let (n, k) = (self.n as f64, k as f64);
+        ln_choose(n, k) + ln_beta(k + self.alpha, n - k + self.beta)
+            - ln_beta(self.alpha, self.beta)
+    }
+}
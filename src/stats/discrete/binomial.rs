@@ -0,0 +1,97 @@
+use crate::stats::special::{beta_inc, ln_choose, xlogy};
+use crate::stats::{Discrete, Distribution, Support};
+
+/// Number of successes in n independent trials with success probability p.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+}
+
+impl Binomial {
+    pub fn new(n: u64, p: f64) -> Self {
+        Self { n, p }
+    }
+}
+
+impl Distribution for Binomial {
+    fn mean(&self) -> f64 {
+        self.n as f64 * self.p
+    }
+
+    fn variance(&self) -> f64 {
+        self.n as f64 * self.p * (1.0 - self.p)
+    }
+
+    fn skewness(&self) -> f64 {
+        (1.0 - 2.0 * self.p) / self.variance().sqrt()
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        (1.0 - 6.0 * self.p * (1.0 - self.p)) / self.variance()
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        (1.0 - self.p + self.p * t.exp()).powf(self.n as f64)
+    }
+
+    fn support(&self) -> Support {
+        Support::new(0.0, self.n as f64)
+    }
+}
+
+impl Discrete for Binomial {
+    fn pmf(&self, k: i64) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    fn ln_pmf(&self, k: i64) -> f64 {
+        if k < 0 || k as u64 > self.n {
+            return f64::NEG_INFINITY;
+        }
+        let (n, k) = (self.n as f64, k as f64);
+        // xlogy keeps 0 · ln 0 at 0 so that p = 0 and p = 1 stay well defined
+        ln_choose(n, k) + xlogy(k, self.p) + xlogy(n - k, 1.0 - self.p)
+    }
+
+    fn cdf(&self, k: i64) -> f64 {
+        if k < 0 {
+            0.0
+        } else if k as u64 >= self.n {
+            1.0
+        } else {
+            let (n, k) = (self.n as f64, k as f64);
+            beta_inc(n - k, k + 1.0, 1.0 - self.p)
+        }
+    }
+
+    fn sf(&self, k: i64) -> f64 {
+        if k < 0 {
+            1.0
+        } else if k as u64 >= self.n {
+            0.0
+        } else {
+            let (n, k) = (self.n as f64, k as f64);
+            beta_inc(k + 1.0, n - k, self.p)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_degenerate_success_probabilities() {
+        let never = Binomial::new(10, 0.0);
+        assert_eq!(never.pmf(0), 1.0);
+        assert_eq!(never.pmf(1), 0.0);
+        assert_eq!(never.cdf(0), 1.0);
+
+        let always = Binomial::new(10, 1.0);
+        assert_eq!(always.pmf(10), 1.0);
+        assert_eq!(always.pmf(9), 0.0);
+        assert_eq!(always.cdf(9), 0.0);
+        assert_eq!(always.quantile(0.5), 10);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::stats::{Discrete, Distribution, Rng, Support};
+
+/// Equally likely integers a, a + 1, ..., b.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DiscreteUniform {
+    lower: i64,
+    upper: i64,
+}
+
+impl DiscreteUniform {
+    pub fn new(lower: i64, upper: i64) -> Self {
+        Self { lower, upper }
+    }
+
+    fn count(&self) -> f64 {
+        (self.upper - self.lower + 1) as f64
+    }
+}
+
+impl Distribution for DiscreteUniform {
+    fn mean(&self) -> f64 {
+        (self.lower + self.upper) as f64 / 2.0
+    }
+
+    fn variance(&self) -> f64 {
+        (self.count().powi(2) - 1.0) / 12.0
+    }
+
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        let n2 = self.count().powi(2);
+        if n2 > 1.0 {
+            -6.0 * (n2 + 1.0) / (5.0 * (n2 - 1.0))
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        if t == 0.0 {
+            1.0
+        } else {
+            ((t * self.lower as f64).exp() - (t * (self.upper + 1) as f64).exp())
+                / (self.count() * (1.0 - t.exp()))
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(self.lower as f64, self.upper as f64)
+    }
+}
+
+impl Discrete for DiscreteUniform {
+    fn pmf(&self, k: i64) -> f64 {
+        if (self.lower..=self.upper).contains(&k) {
+            1.0 / self.count()
+        } else {
+            0.0
+        }
+    }
+
+    fn cdf(&self, k: i64) -> f64 {
+        ((k - self.lower + 1) as f64 / self.count()).clamp(0.0, 1.0)
+    }
+
+    fn entropy(&self) -> f64 {
+        self.count().ln()
+    }
+
+    fn modes(&self) -> Vec<i64> {
+        Vec::new()
+    }
+
+    fn variate(&self, rng: &mut Rng) -> i64 {
+        (self.lower + (rng.next_f64() * self.count()) as i64).min(self.upper)
+    }
+}
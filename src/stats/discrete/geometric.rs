@@ -0,0 +1,90 @@
+use crate::stats::special::xlogy;
+use crate::stats::{Discrete, Distribution, Support};
+
+/// Number of trials up to and including the first success.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Geometric {
+    p: f64,
+}
+
+impl Geometric {
+    pub fn new(p: f64) -> Self {
+        Self { p }
+    }
+}
+
+impl Distribution for Geometric {
+    fn mean(&self) -> f64 {
+        1.0 / self.p
+    }
+
+    fn variance(&self) -> f64 {
+        (1.0 - self.p) / self.p.powi(2)
+    }
+
+    fn skewness(&self) -> f64 {
+        (2.0 - self.p) / (1.0 - self.p).sqrt()
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        6.0 + self.p.powi(2) / (1.0 - self.p)
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        let q = 1.0 - self.p;
+        if t < -q.ln() {
+            self.p * t.exp() / (1.0 - q * t.exp())
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(1.0, f64::INFINITY)
+    }
+}
+
+impl Discrete for Geometric {
+    fn pmf(&self, k: i64) -> f64 {
+        if k < 1 {
+            0.0
+        } else {
+            (1.0 - self.p).powi(k as i32 - 1) * self.p
+        }
+    }
+
+    fn cdf(&self, k: i64) -> f64 {
+        if k < 1 {
+            0.0
+        } else {
+            1.0 - (1.0 - self.p).powi(k as i32)
+        }
+    }
+
+    fn sf(&self, k: i64) -> f64 {
+        if k < 1 {
+            1.0
+        } else {
+            (1.0 - self.p).powi(k as i32)
+        }
+    }
+
+    fn quantile(&self, p: f64) -> i64 {
+        if p <= 0.0 {
+            return 1;
+        }
+        if self.p >= 1.0 {
+            return 1;
+        }
+        ((1.0 - p).ln() / (1.0 - self.p).ln()).ceil().max(1.0) as i64
+    }
+
+    fn entropy(&self) -> f64 {
+        let (p, q) = (self.p, 1.0 - self.p);
+        -(xlogy(q, q) + xlogy(p, p)) / p
+    }
+
+    fn modes(&self) -> Vec<i64> {
+        vec![1]
+    }
+}
@@ -0,0 +1,86 @@
+use crate::stats::discrete::standardised_moment;
+use crate::stats::special::ln_choose;
+use crate::stats::{Discrete, Distribution, Support};
+
+/// Number of successes in K draws without replacement from a population of
+/// N items containing M successes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hypergeometric {
+    population: u64,
+    successes: u64,
+    draws: u64,
+}
+
+impl Hypergeometric {
+    pub fn new(population: u64, successes: u64, draws: u64) -> Self {
+        Self {
+            population,
+            successes,
+            draws,
+        }
+    }
+}
+
+impl Distribution for Hypergeometric {
+    fn mean(&self) -> f64 {
+        self.draws as f64 * self.successes as f64 / self.population as f64
+    }
+
+    fn variance(&self) -> f64 {
+        let n = self.population as f64;
+        let m = self.successes as f64;
+        let k = self.draws as f64;
+        if n <= 1.0 {
+            return 0.0;
+        }
+        k * m * (n - m) * (n - k) / (n * n * (n - 1.0))
+    }
+
+    fn skewness(&self) -> f64 {
+        standardised_moment(self, 3)
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        standardised_moment(self, 4) - 3.0
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        self.expectation(&|k| (t * k as f64).exp())
+    }
+
+    fn support(&self) -> Support {
+        let lower = (self.draws + self.successes).saturating_sub(self.population);
+        let upper = self.draws.min(self.successes);
+        Support::new(lower as f64, upper as f64)
+    }
+}
+
+impl Discrete for Hypergeometric {
+    fn pmf(&self, k: i64) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    fn ln_pmf(&self, k: i64) -> f64 {
+        let n = self.population as f64;
+        let m = self.successes as f64;
+        let draws = self.draws as f64;
+        let x = k as f64;
+        ln_choose(m, x) + ln_choose(n - m, draws - x) - ln_choose(n, draws)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hypergeometric(x: i64, n: u64, m: u64, k: u64) -> f64 {
+        Hypergeometric::new(n, m, k).pmf(x)
+    }
+
+    #[test]
+    fn test_hypergeometric() {
+        // Test edge cases
+        assert!((hypergeometric(0, 1, 1, 1) - 0.0).abs() < 1e-6);
+        assert!((hypergeometric(1, 1, 1, 1) - 1.0).abs() < 1e-6);
+    }
+}
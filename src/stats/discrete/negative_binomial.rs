@@ -0,0 +1,113 @@
+use crate::stats::special::{beta_inc, ln_gamma};
+use crate::stats::{Discrete, Distribution, Support};
+
+/// Number of failures before the r-th success with success probability p.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NegativeBinomial {
+    r: f64,
+    p: f64,
+}
+
+impl NegativeBinomial {
+    pub fn new(r: f64, p: f64) -> Self {
+        Self { r, p }
+    }
+}
+
+impl Distribution for NegativeBinomial {
+    fn mean(&self) -> f64 {
+        self.r * (1.0 - self.p) / self.p
+    }
+
+    fn variance(&self) -> f64 {
+        self.r * (1.0 - self.p) / self.p.powi(2)
+    }
+
+    fn skewness(&self) -> f64 {
+        (2.0 - self.p) / (self.r * (1.0 - self.p)).sqrt()
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        6.0 / self.r + self.p.powi(2) / (self.r * (1.0 - self.p))
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        let q = 1.0 - self.p;
+        if t < -q.ln() {
+            (self.p / (1.0 - q * t.exp())).powf(self.r)
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn support(&self) -> Support {
+        Support::new(0.0, f64::INFINITY)
+    }
+}
+
+impl Discrete for NegativeBinomial {
+    fn pmf(&self, k: i64) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    fn ln_pmf(&self, k: i64) -> f64 {
+        if k < 0 {
+            return f64::NEG_INFINITY;
+        }
+        let (r, p) = (self.r, self.p);
+        if k == 0 {
+            // C(r - 1, 0) = 1, which also covers the degenerate r = 0 case
+            return r * p.ln();
+        }
+        let k = k as f64;
+        // ln C(r + k - 1, k) via the gamma function so that r need not be an integer
+        ln_gamma(r + k) - ln_gamma(r) - ln_gamma(k + 1.0) + k * (1.0 - p).ln() + r * p.ln()
+    }
+
+    fn cdf(&self, k: i64) -> f64 {
+        if k < 0 {
+            0.0
+        } else {
+            beta_inc(self.r, k as f64 + 1.0, self.p)
+        }
+    }
+
+    fn sf(&self, k: i64) -> f64 {
+        if k < 0 {
+            1.0
+        } else {
+            beta_inc(k as f64 + 1.0, self.r, 1.0 - self.p)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn negative_binomial(k: i64, r: u64, p: f64) -> f64 {
+        NegativeBinomial::new(r as f64, p).pmf(k)
+    }
+
+    #[test]
+    fn test_negative_binomial() {
+        // Too Simple test
+        assert!((negative_binomial(0, 1, 0.5) < 1.0));
+
+        // Test with known values
+        assert!((negative_binomial(2, 3, 0.5) - (3.0 / 16.0)).abs() < 1e-6);
+        assert!((negative_binomial(1, 3, 0.5) - 0.1875).abs() < 1e-6);
+
+        // Test edge cases
+        assert!((negative_binomial(0, 1, 0.5) - 0.5).abs() < 1e-6);
+        assert!((negative_binomial(0, 0, 0.5) - 1.0).abs() < 1e-6);
+        assert!((negative_binomial(0, 1, 0.99) - 1.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_negative_binomial_cdf() {
+        let dist = NegativeBinomial::new(3.0, 0.5);
+        let summed: f64 = (0..=4).map(|k| dist.pmf(k)).sum();
+        assert!((dist.cdf(4) - summed).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::stats::special::{gamma_p, gamma_q, ln_gamma};
+use crate::stats::{Discrete, Distribution, Support};
+
+/// Number of events in a fixed interval with rate λ.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    pub fn new(lambda: f64) -> Self {
+        Self { lambda }
+    }
+}
+
+impl Distribution for Poisson {
+    fn mean(&self) -> f64 {
+        self.lambda
+    }
+
+    fn variance(&self) -> f64 {
+        self.lambda
+    }
+
+    fn skewness(&self) -> f64 {
+        self.lambda.powf(-0.5)
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        1.0 / self.lambda
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        (self.lambda * t.exp_m1()).exp()
+    }
+
+    fn support(&self) -> Support {
+        Support::new(0.0, f64::INFINITY)
+    }
+}
+
+impl Discrete for Poisson {
+    fn pmf(&self, k: i64) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    fn ln_pmf(&self, k: i64) -> f64 {
+        if k < 0 {
+            return f64::NEG_INFINITY;
+        }
+        let k = k as f64;
+        -self.lambda + k * self.lambda.ln() - ln_gamma(k + 1.0)
+    }
+
+    fn cdf(&self, k: i64) -> f64 {
+        if k < 0 {
+            0.0
+        } else {
+            gamma_q(k as f64 + 1.0, self.lambda)
+        }
+    }
+
+    fn sf(&self, k: i64) -> f64 {
+        if k < 0 {
+            1.0
+        } else {
+            gamma_p(k as f64 + 1.0, self.lambda)
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::stats::discrete::Poisson;
+use crate::stats::{Discrete, Distribution, Rng, Support};
+
+/// Difference N₁ - N₂ of independent Poisson counts with rates μ₁ and μ₂.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Skellam {
+    mu1: f64,
+    mu2: f64,
+}
+
+impl Skellam {
+    pub fn new(mu1: f64, mu2: f64) -> Self {
+        Self { mu1, mu2 }
+    }
+
+    /// Σ P(N₂ = n) * f(n) over the bulk of N₂'s mass.
+    fn sum_over_second(&self, f: impl Fn(i64) -> f64) -> f64 {
+        let second = Poisson::new(self.mu2);
+        let last = (self.mu2 + 12.0 * self.mu2.sqrt() + 20.0).ceil() as i64;
+        (0..=last).map(|n| second.pmf(n) * f(n)).sum()
+    }
+}
+
+impl Distribution for Skellam {
+    fn mean(&self) -> f64 {
+        self.mu1 - self.mu2
+    }
+
+    fn variance(&self) -> f64 {
+        self.mu1 + self.mu2
+    }
+
+    fn skewness(&self) -> f64 {
+        (self.mu1 - self.mu2) / (self.mu1 + self.mu2).powf(1.5)
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        1.0 / (self.mu1 + self.mu2)
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        (self.mu1 * t.exp_m1() + self.mu2 * (-t).exp_m1()).exp()
+    }
+
+    fn support(&self) -> Support {
+        Support::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+impl Discrete for Skellam {
+    fn pmf(&self, k: i64) -> f64 {
+        // P(N₁ - N₂ = k) = Σ P(N₂ = n) P(N₁ = k + n)
+        let first = Poisson::new(self.mu1);
+        self.sum_over_second(|n| first.pmf(k + n))
+    }
+
+    fn cdf(&self, k: i64) -> f64 {
+        let first = Poisson::new(self.mu1);
+        self.sum_over_second(|n| first.cdf(k + n)).min(1.0)
+    }
+
+    fn sf(&self, k: i64) -> f64 {
+        let first = Poisson::new(self.mu1);
+        self.sum_over_second(|n| first.sf(k + n)).min(1.0)
+    }
+
+    /// The support is unbounded below, so gallop outwards from the mean
+    /// instead of up from the lower end.
+    fn quantile(&self, p: f64) -> i64 {
+        if p <= 0.0 {
+            return i64::MIN;
+        }
+        if p >= 1.0 {
+            return i64::MAX;
+        }
+        let start = self.mean().floor() as i64;
+        let (mut below, mut above) = (start - 1, start);
+        let mut step = 1;
+        while self.cdf(below) >= p {
+            above = below;
+            below -= step;
+            step *= 2;
+        }
+        while self.cdf(above) < p {
+            below = above;
+            above += step;
+            step *= 2;
+        }
+        while above - below > 1 {
+            let middle = below + (above - below) / 2;
+            if self.cdf(middle) < p {
+                below = middle;
+            } else {
+                above = middle;
+            }
+        }
+        above
+    }
+
+    fn variate(&self, rng: &mut Rng) -> i64 {
+        Poisson::new(self.mu1).variate(rng) - Poisson::new(self.mu2).variate(rng)
+    }
+}
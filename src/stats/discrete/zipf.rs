@@ -0,0 +1,75 @@
+use crate::stats::{Discrete, Distribution, Support};
+
+/// Rank k = 1, ..., N with probability proportional to 1 / k^s.
+///
+/// As N grows with s > 1 this tends to the zeta distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Zipf {
+    n: u64,
+    exponent: f64,
+}
+
+impl Zipf {
+    pub fn new(n: u64, exponent: f64) -> Self {
+        Self { n, exponent }
+    }
+
+    /// Generalized harmonic number H(N, s) = Σ 1 / k^s for k = 1..=N.
+    fn harmonic(&self, exponent: f64) -> f64 {
+        (1..=self.n).map(|k| (k as f64).powf(-exponent)).sum()
+    }
+
+    /// E[Xⁱ] = H(N, s - i) / H(N, s)
+    fn raw_moment(&self, i: i32) -> f64 {
+        self.harmonic(self.exponent - i as f64) / self.harmonic(self.exponent)
+    }
+}
+
+impl Distribution for Zipf {
+    fn mean(&self) -> f64 {
+        self.harmonic(self.exponent - 1.0) / self.harmonic(self.exponent)
+    }
+
+    fn variance(&self) -> f64 {
+        self.harmonic(self.exponent - 2.0) / self.harmonic(self.exponent) - self.mean().powi(2)
+    }
+
+    fn skewness(&self) -> f64 {
+        let (mean, sd) = (self.mean(), self.std_dev());
+        (self.raw_moment(3) - 3.0 * mean * sd.powi(2) - mean.powi(3)) / sd.powi(3)
+    }
+
+    fn excess_kurtosis(&self) -> f64 {
+        let mean = self.mean();
+        let central = self.raw_moment(4) - 4.0 * mean * self.raw_moment(3)
+            + 6.0 * mean.powi(2) * self.raw_moment(2)
+            - 3.0 * mean.powi(4);
+        central / self.variance().powi(2) - 3.0
+    }
+
+    fn mgf(&self, t: f64) -> f64 {
+        self.expectation(&|k| (t * k as f64).exp())
+    }
+
+    fn support(&self) -> Support {
+        Support::new(1.0, self.n as f64)
+    }
+}
+
+impl Discrete for Zipf {
+    fn pmf(&self, k: i64) -> f64 {
+        if k < 1 || k as u64 > self.n {
+            return 0.0;
+        }
+        (k as f64).powf(-self.exponent) / self.harmonic(self.exponent)
+    }
+
+    fn cdf(&self, k: i64) -> f64 {
+        if k < 1 {
+            return 0.0;
+        }
+        let k = (k as u64).min(self.n);
+        let partial: f64 = (1..=k).map(|i| (i as f64).powf(-self.exponent)).sum();
+        (partial / self.harmonic(self.exponent)).min(1.0)
+    }
+}
@@ -0,0 +1,125 @@
+//! Distances between probability distributions.
+//!
+//! Discrete distributions are passed as their pmf or cdf, so that a discrete
+//! law can be compared with a continuous approximation through
+//! [`continuity_corrected`].
+
+use crate::stats::Continuous;
+
+/// Intervals of the Simpson rule in [`continuous_total_variation`]; even.
+const SIMPSON_INTERVALS: usize = 4000;
+
+/// Total variation distance ½ Σ |p(k) - q(k)| between two pmfs over `ks`.
+///
+/// `ks` should cover the mass of both distributions; anything outside it is
+/// ignored.
+pub fn discrete_total_variation(
+    p: impl Fn(i64) -> f64,
+    q: impl Fn(i64) -> f64,
+    ks: impl IntoIterator<Item = i64>,
+) -> f64 {
+    0.5 * ks.into_iter().map(|k| (p(k) - q(k)).abs()).sum::<f64>()
+}
+
+/// Total variation distance ½ ∫ |f(x) - g(x)| dx between two continuous
+/// distributions, by Simpson's rule over `[lower, upper]`.
+///
+/// The mass outside the interval is compared through the cdfs, which is exact
+/// as long as the densities do not cross there.
+pub fn continuous_total_variation(
+    f: &(impl Continuous + ?Sized),
+    g: &(impl Continuous + ?Sized),
+    lower: f64,
+    upper: f64,
+) -> f64 {
+    let h = (upper - lower) / SIMPSON_INTERVALS as f64;
+    let integrand = |x: f64| {
+        let d = (f.pdf(x) - g.pdf(x)).abs();
+        // Poles at the ends of a support contribute nothing measurable
+        if d.is_finite() {
+            d
+        } else {
+            0.0
+        }
+    };
+    let sum: f64 = (0..=SIMPSON_INTERVALS)
+        .map(|i| {
+            let weight = match i {
+                0 => 1.0,
+                i if i == SIMPSON_INTERVALS => 1.0,
+                i if i % 2 == 1 => 4.0,
+                _ => 2.0,
+            };
+            weight * integrand(lower + i as f64 * h)
+        })
+        .sum();
+    let tails = (f.cdf(lower) - g.cdf(lower)).abs() + (f.sf(upper) - g.sf(upper)).abs();
+    0.5 * (sum * h / 3.0 + tails)
+}
+
+/// Kolmogorov distance sup |F(x) - G(x)|, taken over `xs`.
+pub fn kolmogorov_distance(
+    f: impl Fn(f64) -> f64,
+    g: impl Fn(f64) -> f64,
+    xs: impl IntoIterator<Item = f64>,
+) -> f64 {
+    xs.into_iter()
+        .map(|x| (f(x) - g(x)).abs())
+        .fold(0.0, f64::max)
+}
+
+/// Mass that `dist` puts on [k - ½, k + ½]: the continuity-corrected pmf of a
+/// continuous approximation to an integer-valued law.
+pub fn continuity_corrected(dist: &(impl Continuous + ?Sized), k: i64) -> f64 {
+    dist.cdf(k as f64 + 0.5) - dist.cdf(k as f64 - 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::continuous::{Normal, Uniform};
+    use crate::stats::discrete::{Bernoulli, Binomial};
+    use crate::stats::Discrete;
+
+    #[test]
+    fn test_total_variation() {
+        let p = Bernoulli::new(0.2);
+        let q = Bernoulli::new(0.5);
+        let tv = discrete_total_variation(|k| p.pmf(k), |k| q.pmf(k), 0..=1);
+        assert!((tv - 0.3).abs() < 1e-12);
+        assert_eq!(
+            discrete_total_variation(|k| p.pmf(k), |k| p.pmf(k), 0..=1),
+            0.0
+        );
+
+        // Disjoint supports are as far apart as possible
+        let f = Uniform::new(0.0, 1.0);
+        let g = Uniform::new(2.0, 3.0);
+        let tv = continuous_total_variation(&f, &g, -1.0, 4.0);
+        assert!((tv - 1.0).abs() < 1e-2);
+
+        let f = Normal::new(0.0, 1.0);
+        let g = Normal::new(1.0, 1.0);
+        // 2Φ(½) - 1 for unit normals one standard deviation apart
+        let tv = continuous_total_variation(&f, &g, -3.0, 4.0);
+        assert!((tv - 0.382_924_922_548).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_continuity_correction_approximates_binomial() {
+        let binomial = Binomial::new(400, 0.5);
+        let normal = Normal::new(200.0, 10.0);
+        let tv = discrete_total_variation(
+            |k| binomial.pmf(k),
+            |k| continuity_corrected(&normal, k),
+            0..=400,
+        );
+        assert!(tv < 0.01);
+        let d = kolmogorov_distance(
+            |x| binomial.cdf(x.floor() as i64),
+            |x| normal.cdf(x.floor() + 0.5),
+            (0..=400).map(|k| k as f64),
+        );
+        assert!(d < 0.01);
+    }
+}
@@ -0,0 +1,772 @@
+//! Maximum-likelihood fitting of the distribution families to data.
+//!
+//! Every family is fitted the same way: the parameters are mapped to the whole
+//! real line (logarithms of positive parameters, log-odds of probabilities),
+//! the negative log-likelihood is minimised with Nelder–Mead from a method of
+//! moments start, and standard errors come from the inverse of its numerical
+//! Hessian, mapped back with the delta method.
+
+use crate::stats::continuous::{
+    Beta, Cauchy, ChiSquared, Exponential, Gamma, Gumbel, Laplace, LogNormal, Logistic, Normal,
+    Pareto, StudentT, Uniform, Weibull, F,
+};
+use crate::stats::descriptive::{mean, variance};
+use crate::stats::discrete::{
+    Bernoulli, BetaBinomial, Binomial, DiscreteUniform, Geometric, Hypergeometric,
+    NegativeBinomial, Poisson, Skellam, Zipf,
+};
+use crate::stats::linalg::{cholesky, cholesky_solve};
+use crate::stats::special::{gamma, EULER_GAMMA};
+use crate::stats::{Continuous, Discrete, Rng, Sample};
+
+const MAX_ITERATIONS: usize = 5000;
+const TOLERANCE: f64 = 1e-10;
+/// Step of the numerical Hessian on the unconstrained scale.
+const HESSIAN_STEP: f64 = 1e-4;
+/// Largest whole-number parameter, such as a number of trials, that keeps the
+/// probabilities quick to sum.
+const MAX_SIZE: f64 = 10_000.0;
+
+/// Values a parameter can take.
+///
+/// Whole-number parameters only belong to families that are chosen by hand,
+/// never fitted, so they are mapped like their continuous counterparts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Domain {
+    Real,
+    Positive,
+    Probability,
+    /// Whole numbers up to `MAX_SIZE` in magnitude.
+    Integer,
+    /// Whole numbers from 1 to `MAX_SIZE`.
+    Size,
+}
+
+impl Domain {
+    /// Maps an unconstrained value into the domain.
+    fn constrain(self, value: f64) -> f64 {
+        match self {
+            Domain::Real | Domain::Integer => value,
+            Domain::Positive | Domain::Size => value.exp(),
+            Domain::Probability => 1.0 / (1.0 + (-value).exp()),
+        }
+    }
+
+    fn unconstrain(self, parameter: f64) -> f64 {
+        match self {
+            Domain::Real | Domain::Integer => parameter,
+            Domain::Positive | Domain::Size => parameter.ln(),
+            Domain::Probability => (parameter / (1.0 - parameter)).ln(),
+        }
+    }
+
+    fn contains(self, parameter: f64) -> bool {
+        match self {
+            Domain::Real => parameter.is_finite(),
+            Domain::Positive => parameter > 0.0 && parameter.is_finite(),
+            Domain::Probability => parameter > 0.0 && parameter < 1.0,
+            Domain::Integer => parameter.fract() == 0.0 && parameter.abs() <= MAX_SIZE,
+            Domain::Size => parameter.fract() == 0.0 && (1.0..=MAX_SIZE).contains(&parameter),
+        }
+    }
+
+    /// Derivative of `constrain` at the unconstrained value of `parameter`.
+    fn slope(self, parameter: f64) -> f64 {
+        match self {
+            Domain::Real | Domain::Integer => 1.0,
+            Domain::Positive | Domain::Size => parameter,
+            Domain::Probability => parameter * (1.0 - parameter),
+        }
+    }
+}
+
+/// A family of distributions, with parameters fitted to data or chosen by hand.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Family {
+    Normal,
+    Exponential,
+    Gamma,
+    Weibull,
+    LogNormal,
+    Beta,
+    Laplace,
+    Logistic,
+    Gumbel,
+    Cauchy,
+    Poisson,
+    Geometric,
+    NegativeBinomial,
+    Uniform,
+    StudentT,
+    ChiSquared,
+    F,
+    Pareto,
+    Bernoulli,
+    Binomial,
+    BetaBinomial,
+    Hypergeometric,
+    DiscreteUniform,
+    Zipf,
+    Skellam,
+}
+
+impl Family {
+    /// The families [`fit_all`] fits by maximum likelihood.
+    pub const FITTED: [Family; 13] = [
+        Self::Normal,
+        Self::Exponential,
+        Self::Gamma,
+        Self::Weibull,
+        Self::LogNormal,
+        Self::Beta,
+        Self::Laplace,
+        Self::Logistic,
+        Self::Gumbel,
+        Self::Cauchy,
+        Self::Poisson,
+        Self::Geometric,
+        Self::NegativeBinomial,
+    ];
+
+    /// Every family, fitted or only chosen by hand.
+    pub const ALL: [Family; 25] = [
+        Self::Normal,
+        Self::Exponential,
+        Self::Gamma,
+        Self::Weibull,
+        Self::LogNormal,
+        Self::Beta,
+        Self::Laplace,
+        Self::Logistic,
+        Self::Gumbel,
+        Self::Cauchy,
+        Self::Uniform,
+        Self::StudentT,
+        Self::ChiSquared,
+        Self::F,
+        Self::Pareto,
+        Self::Poisson,
+        Self::Geometric,
+        Self::NegativeBinomial,
+        Self::Bernoulli,
+        Self::Binomial,
+        Self::BetaBinomial,
+        Self::Hypergeometric,
+        Self::DiscreteUniform,
+        Self::Zipf,
+        Self::Skellam,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Exponential => "Exponential",
+            Self::Gamma => "Gamma",
+            Self::Weibull => "Weibull",
+            Self::LogNormal => "Log-normal",
+            Self::Beta => "Beta",
+            Self::Laplace => "Laplace",
+            Self::Logistic => "Logistic",
+            Self::Gumbel => "Gumbel",
+            Self::Cauchy => "Cauchy",
+            Self::Poisson => "Poisson",
+            Self::Geometric => "Geometric",
+            Self::NegativeBinomial => "Negative Binomial",
+            Self::Uniform => "Uniform",
+            Self::StudentT => "Student t",
+            Self::ChiSquared => "Chi-squared",
+            Self::F => "F",
+            Self::Pareto => "Pareto",
+            Self::Bernoulli => "Bernoulli",
+            Self::Binomial => "Binomial",
+            Self::BetaBinomial => "Beta-binomial",
+            Self::Hypergeometric => "Hypergeometric",
+            Self::DiscreteUniform => "Discrete Uniform",
+            Self::Zipf => "Zipf",
+            Self::Skellam => "Skellam",
+        }
+    }
+
+    /// Symbols of the parameters, in the order the constructor takes them.
+    pub fn parameter_names(self) -> &'static [&'static str] {
+        match self {
+            Self::Normal | Self::LogNormal => &["μ", "σ"],
+            Self::Exponential | Self::Poisson => &["λ"],
+            Self::Gamma => &["shape", "rate"],
+            Self::Weibull => &["shape", "scale"],
+            Self::Beta => &["α", "β"],
+            Self::Laplace | Self::Logistic | Self::Gumbel | Self::Cauchy => &["location", "scale"],
+            Self::Geometric | Self::Bernoulli => &["p"],
+            Self::NegativeBinomial => &["r", "p"],
+            Self::Uniform | Self::DiscreteUniform => &["a", "b"],
+            Self::StudentT => &["ν"],
+            Self::ChiSquared => &["k"],
+            Self::F => &["d₁", "d₂"],
+            Self::Pareto => &["scale", "shape"],
+            Self::Binomial => &["n", "p"],
+            Self::BetaBinomial => &["n", "α", "β"],
+            Self::Hypergeometric => &["N", "K", "n"],
+            Self::Zipf => &["N", "s"],
+            Self::Skellam => &["μ₁", "μ₂"],
+        }
+    }
+
+    fn domains(self) -> &'static [Domain] {
+        match self {
+            Self::Normal
+            | Self::LogNormal
+            | Self::Laplace
+            | Self::Logistic
+            | Self::Gumbel
+            | Self::Cauchy => &[Domain::Real, Domain::Positive],
+            Self::Exponential | Self::Poisson | Self::StudentT | Self::ChiSquared => {
+                &[Domain::Positive]
+            }
+            Self::Gamma | Self::Weibull | Self::Beta | Self::F | Self::Pareto | Self::Skellam => {
+                &[Domain::Positive, Domain::Positive]
+            }
+            Self::Geometric | Self::Bernoulli => &[Domain::Probability],
+            Self::NegativeBinomial => &[Domain::Positive, Domain::Probability],
+            Self::Uniform => &[Domain::Real, Domain::Real],
+            Self::DiscreteUniform => &[Domain::Integer, Domain::Integer],
+            Self::Binomial => &[Domain::Size, Domain::Probability],
+            Self::BetaBinomial => &[Domain::Size, Domain::Positive, Domain::Positive],
+            Self::Hypergeometric => &[Domain::Size, Domain::Size, Domain::Size],
+            Self::Zipf => &[Domain::Size, Domain::Positive],
+        }
+    }
+
+    /// Parameters to start from when the user picks the family by hand.
+    pub fn default_parameters(self) -> Vec<f64> {
+        match self {
+            Self::Normal | Self::Laplace | Self::Logistic | Self::Gumbel | Self::Cauchy => {
+                vec![0.0, 1.0]
+            }
+            Self::Exponential => vec![1.0],
+            Self::Gamma => vec![2.0, 1.0],
+            Self::Weibull => vec![1.5, 1.0],
+            Self::LogNormal => vec![0.0, 0.5],
+            Self::Beta => vec![2.0, 2.0],
+            Self::Poisson => vec![3.0],
+            Self::Geometric => vec![0.3],
+            Self::NegativeBinomial => vec![3.0, 0.5],
+            Self::Uniform => vec![0.0, 1.0],
+            Self::StudentT => vec![5.0],
+            Self::ChiSquared => vec![3.0],
+            Self::F => vec![5.0, 10.0],
+            Self::Pareto => vec![1.0, 3.0],
+            Self::Bernoulli => vec![0.5],
+            Self::Binomial => vec![10.0, 0.5],
+            Self::BetaBinomial => vec![10.0, 2.0, 2.0],
+            Self::Hypergeometric => vec![50.0, 20.0, 10.0],
+            Self::DiscreteUniform => vec![1.0, 6.0],
+            Self::Zipf => vec![10.0, 1.0],
+            Self::Skellam => vec![3.0, 1.0],
+        }
+    }
+
+    /// Whether `parameters` are valid parameters of the family.
+    pub fn accepts(self, parameters: &[f64]) -> bool {
+        let domains = self.domains();
+        let p = parameters;
+        p.len() == domains.len()
+            && domains
+                .iter()
+                .zip(p)
+                .all(|(domain, &parameter)| domain.contains(parameter))
+            && match self {
+                Self::Uniform => p[0] < p[1],
+                Self::DiscreteUniform => p[0] <= p[1],
+                // Neither the successes nor the draws outnumber the population
+                Self::Hypergeometric => p[1] <= p[0] && p[2] <= p[0],
+                _ => true,
+            }
+    }
+
+    /// Whether [`fit`] can estimate the parameters from data.
+    pub fn is_fitted(self) -> bool {
+        Self::FITTED.contains(&self)
+    }
+
+    pub fn is_discrete(self) -> bool {
+        matches!(
+            self,
+            Self::Poisson
+                | Self::Geometric
+                | Self::NegativeBinomial
+                | Self::Bernoulli
+                | Self::Binomial
+                | Self::BetaBinomial
+                | Self::Hypergeometric
+                | Self::DiscreteUniform
+                | Self::Zipf
+                | Self::Skellam
+        )
+    }
+
+    /// The continuous distribution with `parameters`, or `None` for a
+    /// discrete family.
+    pub fn continuous(self, parameters: &[f64]) -> Option<Box<dyn Continuous>> {
+        let p = parameters;
+        Some(match self {
+            Self::Normal => Box::new(Normal::new(p[0], p[1])),
+            Self::Exponential => Box::new(Exponential::new(p[0])),
+            Self::Gamma => Box::new(Gamma::new(p[0], p[1])),
+            Self::Weibull => Box::new(Weibull::new(p[0], p[1])),
+            Self::LogNormal => Box::new(LogNormal::new(p[0], p[1])),
+            Self::Beta => Box::new(Beta::new(p[0], p[1])),
+            Self::Laplace => Box::new(Laplace::new(p[0], p[1])),
+            Self::Logistic => Box::new(Logistic::new(p[0], p[1])),
+            Self::Gumbel => Box::new(Gumbel::new(p[0], p[1])),
+            Self::Cauchy => Box::new(Cauchy::new(p[0], p[1])),
+            Self::Uniform => Box::new(Uniform::new(p[0], p[1])),
+            Self::StudentT => Box::new(StudentT::new(p[0])),
+            Self::ChiSquared => Box::new(ChiSquared::new(p[0])),
+            Self::F => Box::new(F::new(p[0], p[1])),
+            Self::Pareto => Box::new(Pareto::new(p[0], p[1])),
+            _ => return None,
+        })
+    }
+
+    /// The discrete distribution with `parameters`, or `None` for a
+    /// continuous family.
+    pub fn discrete(self, parameters: &[f64]) -> Option<Box<dyn Discrete>> {
+        let p = parameters;
+        Some(match self {
+            Self::Poisson => Box::new(Poisson::new(p[0])),
+            Self::Geometric => Box::new(Geometric::new(p[0])),
+            Self::NegativeBinomial => Box::new(NegativeBinomial::new(p[0], p[1])),
+            Self::Bernoulli => Box::new(Bernoulli::new(p[0])),
+            Self::Binomial => Box::new(Binomial::new(p[0] as u64, p[1])),
+            Self::BetaBinomial => Box::new(BetaBinomial::new(p[0] as u64, p[1], p[2])),
+            Self::Hypergeometric => {
+                Box::new(Hypergeometric::new(p[0] as u64, p[1] as u64, p[2] as u64))
+            }
+            Self::DiscreteUniform => Box::new(DiscreteUniform::new(p[0] as i64, p[1] as i64)),
+            Self::Zipf => Box::new(Zipf::new(p[0] as u64, p[1])),
+            Self::Skellam => Box::new(Skellam::new(p[0], p[1])),
+            _ => return None,
+        })
+    }
+
+    /// `n` draws from the distribution with `parameters`, counts as floats.
+    pub fn sample(self, parameters: &[f64], rng: &mut Rng, n: usize) -> Vec<f64> {
+        let p = parameters;
+        match self {
+            Self::Normal => Normal::new(p[0], p[1]).sample(rng, n),
+            Self::Exponential => Exponential::new(p[0]).sample(rng, n),
+            Self::Gamma => Gamma::new(p[0], p[1]).sample(rng, n),
+            Self::Weibull => Weibull::new(p[0], p[1]).sample(rng, n),
+            Self::LogNormal => LogNormal::new(p[0], p[1]).sample(rng, n),
+            Self::Beta => Beta::new(p[0], p[1]).sample(rng, n),
+            Self::Laplace => Laplace::new(p[0], p[1]).sample(rng, n),
+            Self::Logistic => Logistic::new(p[0], p[1]).sample(rng, n),
+            Self::Gumbel => Gumbel::new(p[0], p[1]).sample(rng, n),
+            Self::Cauchy => Cauchy::new(p[0], p[1]).sample(rng, n),
+            Self::Uniform => Uniform::new(p[0], p[1]).sample(rng, n),
+            Self::StudentT => StudentT::new(p[0]).sample(rng, n),
+            Self::ChiSquared => ChiSquared::new(p[0]).sample(rng, n),
+            Self::F => F::new(p[0], p[1]).sample(rng, n),
+            Self::Pareto => Pareto::new(p[0], p[1]).sample(rng, n),
+            _ => {
+                let dist = self.discrete(parameters).unwrap();
+                (0..n).map(|_| dist.variate(rng) as f64).collect()
+            }
+        }
+    }
+
+    pub fn log_likelihood(self, parameters: &[f64], data: &[f64]) -> f64 {
+        if let Some(dist) = self.continuous(parameters) {
+            data.iter().map(|&x| dist.ln_pdf(x)).sum()
+        } else {
+            let dist = self.discrete(parameters).unwrap();
+            data.iter().map(|&x| dist.ln_pmf(x as i64)).sum()
+        }
+    }
+
+    /// Method of moments (or quantile) estimates to start the search from.
+    fn initial(self, data: &[f64]) -> Vec<f64> {
+        let m = mean(data);
+        let v = variance(data);
+        let sd = v.sqrt();
+        match self {
+            Self::Normal => vec![m, sd],
+            Self::Exponential => vec![1.0 / m],
+            Self::Gamma => vec![m * m / v, m / v],
+            Self::Weibull => {
+                // Justus' approximation of the shape from the coefficient of
+                // variation
+                let shape = (sd / m).powf(-1.086);
+                vec![shape, m / gamma(1.0 + 1.0 / shape)]
+            }
+            Self::LogNormal => {
+                let logs: Vec<f64> = data.iter().map(|x| x.ln()).collect();
+                vec![mean(&logs), variance(&logs).sqrt()]
+            }
+            Self::Beta => {
+                let common = m * (1.0 - m) / v - 1.0;
+                if common > 0.0 {
+                    vec![m * common, (1.0 - m) * common]
+                } else {
+                    vec![1.0, 1.0]
+                }
+            }
+            Self::Laplace => vec![m, sd / 2.0_f64.sqrt()],
+            Self::Logistic => vec![m, sd * 3.0_f64.sqrt() / std::f64::consts::PI],
+            Self::Gumbel => {
+                let scale = sd * 6.0_f64.sqrt() / std::f64::consts::PI;
+                vec![m - EULER_GAMMA * scale, scale]
+            }
+            Self::Cauchy => {
+                let mut sorted = data.to_vec();
+                sorted.sort_by(f64::total_cmp);
+                let at = |p: f64| sorted[((sorted.len() - 1) as f64 * p).round() as usize];
+                vec![at(0.5), ((at(0.75) - at(0.25)) / 2.0).max(sd * 1e-3)]
+            }
+            Self::Poisson => vec![m],
+            Self::Geometric => vec![1.0 / m],
+            Self::NegativeBinomial => {
+                // Overdispersion is needed for a finite r; otherwise start
+                // close to the Poisson limit
+                let p = if v > m { m / v } else { 0.99 };
+                vec![m * p / (1.0 - p), p]
+            }
+            _ => unreachable!("{} is not fitted", self.name()),
+        }
+    }
+}
+
+/// Estimates of one family.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fit {
+    pub family: Family,
+    pub parameters: Vec<f64>,
+    /// `NAN` where the information matrix could not be inverted.
+    pub standard_errors: Vec<f64>,
+    pub log_likelihood: f64,
+    pub aic: f64,
+    pub bic: f64,
+}
+
+/// Minimises `f` from `start` with the Nelder–Mead simplex method.
+fn nelder_mead(f: impl Fn(&[f64]) -> f64, start: Vec<f64>) -> Vec<f64> {
+    let dimension = start.len();
+    let mut simplex: Vec<(Vec<f64>, f64)> = (0..=dimension)
+        .map(|i| {
+            let mut point = start.clone();
+            if i > 0 {
+                point[i - 1] += 0.5;
+            }
+            let value = f(&point);
+            (point, value)
+        })
+        .collect();
+
+    for _ in 0..MAX_ITERATIONS {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let (best, worst) = (simplex[0].1, simplex[dimension].1);
+        if (worst - best).abs() <= TOLERANCE * (best.abs() + TOLERANCE) {
+            break;
+        }
+
+        let centroid: Vec<f64> = (0..dimension)
+            .map(|j| simplex[..dimension].iter().map(|(p, _)| p[j]).sum::<f64>() / dimension as f64)
+            .collect();
+        let toward = |t: f64| -> Vec<f64> {
+            centroid
+                .iter()
+                .zip(&simplex[dimension].0)
+                .map(|(c, w)| c + t * (w - c))
+                .collect()
+        };
+
+        let reflected = toward(-1.0);
+        let reflected_value = f(&reflected);
+        if reflected_value < best {
+            let expanded = toward(-2.0);
+            let expanded_value = f(&expanded);
+            simplex[dimension] = if expanded_value < reflected_value {
+                (expanded, expanded_value)
+            } else {
+                (reflected, reflected_value)
+            };
+        } else if reflected_value < simplex[dimension - 1].1 {
+            simplex[dimension] = (reflected, reflected_value);
+        } else {
+            let contracted = toward(0.5);
+            let contracted_value = f(&contracted);
+            if contracted_value < worst {
+                simplex[dimension] = (contracted, contracted_value);
+            } else {
+                // Shrink everything towards the best point
+                let best_point = simplex[0].0.clone();
+                for (point, value) in simplex.iter_mut().skip(1) {
+                    for (x, b) in point.iter_mut().zip(&best_point) {
+                        *x = b + 0.5 * (*x - b);
+                    }
+                    *value = f(point);
+                }
+            }
+        }
+    }
+    simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+    simplex.swap_remove(0).0
+}
+
+/// Hessian of `f` at `point` by central differences.
+fn hessian(f: &impl Fn(&[f64]) -> f64, point: &[f64]) -> Vec<Vec<f64>> {
+    let n = point.len();
+    let h: Vec<f64> = point
+        .iter()
+        .map(|x| HESSIAN_STEP * x.abs().max(1.0))
+        .collect();
+    let at = |steps: &[(usize, f64)]| {
+        let mut shifted = point.to_vec();
+        for &(i, sign) in steps {
+            shifted[i] += sign * h[i];
+        }
+        f(&shifted)
+    };
+    let center = f(point);
+    let mut matrix = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        matrix[i][i] = (at(&[(i, 1.0)]) - 2.0 * center + at(&[(i, -1.0)])) / (h[i] * h[i]);
+        for j in 0..i {
+            let value = (at(&[(i, 1.0), (j, 1.0)])
+                - at(&[(i, 1.0), (j, -1.0)])
+                - at(&[(i, -1.0), (j, 1.0)])
+                + at(&[(i, -1.0), (j, -1.0)]))
+                / (4.0 * h[i] * h[j]);
+            matrix[i][j] = value;
+            matrix[j][i] = value;
+        }
+    }
+    matrix
+}
+
+/// Maximum-likelihood fit of `family`, or `None` if the family is not fitted
+/// or cannot have produced the data (values outside its support, or
+/// non-integers for a discrete family).
+pub fn fit(family: Family, data: &[f64]) -> Option<Fit> {
+    if !family.is_fitted()
+        || data.len() < 2
+        || (family.is_discrete() && data.iter().any(|x| x.fract() != 0.0))
+    {
+        return None;
+    }
+    let domains = family.domains();
+    let constrain = |values: &[f64]| -> Vec<f64> {
+        domains
+            .iter()
+            .zip(values)
+            .map(|(domain, &value)| domain.constrain(value))
+            .collect()
+    };
+    let negative_log_likelihood = |values: &[f64]| {
+        let value = -family.log_likelihood(&constrain(values), data);
+        if value.is_finite() {
+            value
+        } else {
+            f64::INFINITY
+        }
+    };
+
+    let initial = family.initial(data);
+    if !initial.iter().all(|x| x.is_finite()) || !family.log_likelihood(&initial, data).is_finite()
+    {
+        return None;
+    }
+    let start = domains
+        .iter()
+        .zip(&initial)
+        .map(|(domain, &parameter)| domain.unconstrain(parameter))
+        .collect();
+    let optimum = nelder_mead(negative_log_likelihood, start);
+    let parameters = constrain(&optimum);
+    let log_likelihood = family.log_likelihood(&parameters, data);
+
+    let n = data.len() as f64;
+    let information = hessian(&negative_log_likelihood, &optimum);
+    let standard_errors = match cholesky(&information) {
+        // The Laplace likelihood has a kink at every observation, so its
+        // curvature comes from the expected information n / b² instead
+        _ if family == Family::Laplace => vec![parameters[1] / n.sqrt(); 2],
+        Some(lower) => (0..parameters.len())
+            .map(|i| {
+                let mut unit = vec![0.0; parameters.len()];
+                unit[i] = 1.0;
+                let variance = cholesky_solve(&lower, &unit)[i];
+                domains[i].slope(parameters[i]).abs() * variance.sqrt()
+            })
+            .collect(),
+        None => vec![f64::NAN; parameters.len()],
+    };
+
+    let k = parameters.len() as f64;
+    Some(Fit {
+        family,
+        parameters,
+        standard_errors,
+        log_likelihood,
+        aic: 2.0 * k - 2.0 * log_likelihood,
+        bic: k * n.ln() - 2.0 * log_likelihood,
+    })
+}
+
+/// Whether the discrete families apply to `data`: all non-negative integers.
+pub fn are_counts(data: &[f64]) -> bool {
+    data.iter().all(|&x| x >= 0.0 && x.fract() == 0.0)
+}
+
+/// Whether `data` can be checked against a discrete family chosen by hand,
+/// some of which reach below zero: all integers.
+pub fn are_integers(data: &[f64]) -> bool {
+    data.iter().all(|&x| x.fract() == 0.0)
+}
+
+/// Fits of every applicable family: the continuous families for any data and
+/// the discrete ones as well for counts.
+///
+/// Densities and probabilities are not comparable, so each group is ranked by
+/// AIC on its own: continuous fits come first, best (lowest AIC) first,
+/// followed by the discrete fits in the same order.
+pub fn fit_all(data: &[f64]) -> Vec<Fit> {
+    let counts = are_counts(data);
+    let mut fits: Vec<Fit> = Family::FITTED
+        .into_iter()
+        .filter(|family| counts || !family.is_discrete())
+        .filter_map(|family| fit(family, data))
+        .collect();
+    fits.sort_by(|a, b| {
+        let group = a.family.is_discrete().cmp(&b.family.is_discrete());
+        group.then(a.aic.total_cmp(&b.aic))
+    });
+    fits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closed_form_estimates() {
+        let data = [0.5, 1.5, 2.0, 3.5, 0.2, 1.1, 2.4];
+        let n = data.len() as f64;
+        let m = mean(&data);
+
+        // Exponential: λ̂ = 1 / x̄ with standard error λ̂ / √n
+        let exponential = fit(Family::Exponential, &data).unwrap();
+        assert!((exponential.parameters[0] - 1.0 / m).abs() < 1e-5);
+        assert!((exponential.standard_errors[0] - 1.0 / m / n.sqrt()).abs() < 1e-3);
+
+        // Normal: σ̂ is the biased standard deviation, SE(μ̂) = σ̂ / √n
+        let normal = fit(Family::Normal, &data).unwrap();
+        let sigma = (variance(&data) * (n - 1.0) / n).sqrt();
+        assert!((normal.parameters[0] - m).abs() < 1e-5);
+        assert!((normal.parameters[1] - sigma).abs() < 1e-5);
+        assert!((normal.standard_errors[0] - sigma / n.sqrt()).abs() < 1e-3);
+        assert!((normal.aic - (4.0 - 2.0 * normal.log_likelihood)).abs() < 1e-12);
+
+        // Poisson: λ̂ = x̄
+        let counts = [0.0, 2.0, 1.0, 3.0, 1.0];
+        let poisson = fit(Family::Poisson, &counts).unwrap();
+        assert!((poisson.parameters[0] - 1.4).abs() < 1e-5);
+
+        // Laplace: SE = b̂ / √n for both parameters
+        let laplace = fit(Family::Laplace, &data).unwrap();
+        assert!((laplace.standard_errors[0] - laplace.parameters[1] / n.sqrt()).abs() < 1e-12);
+
+        assert!(fit(Family::Poisson, &data).is_none());
+        assert!(fit(Family::Beta, &data).is_none());
+        assert!(fit(Family::Geometric, &counts).is_none());
+    }
+
+    #[test]
+    fn test_recovers_sampled_parameters() {
+        let data = Gamma::new(3.0, 2.0).sample(&mut Rng::new(11), 4000);
+        let fits = fit_all(&data);
+        let gamma = fits.iter().find(|fit| fit.family == Family::Gamma).unwrap();
+        for (estimate, (truth, se)) in gamma
+            .parameters
+            .iter()
+            .zip([3.0, 2.0].iter().zip(&gamma.standard_errors))
+        {
+            assert!(
+                (estimate - truth).abs() < 4.0 * se,
+                "{} vs {}",
+                estimate,
+                truth
+            );
+        }
+        assert_eq!(fits[0].family, Family::Gamma);
+
+        let counts: Vec<f64> = NegativeBinomial::new(2.0, 0.4)
+            .sample(&mut Rng::new(5), 3000)
+            .into_iter()
+            .map(|k| k as f64)
+            .collect();
+        let fits = fit_all(&counts);
+        let best = fits.iter().find(|fit| fit.family.is_discrete()).unwrap();
+        assert_eq!(best.family, Family::NegativeBinomial);
+        assert!((best.parameters[1] - 0.4).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_groups_of_families_fitted_to_integers() {
+        let ranked = |fits: &[Fit]| {
+            fits.windows(2).all(|pair| {
+                let key = |fit: &Fit| (fit.family.is_discrete(), fit.aic);
+                key(&pair[0]) <= key(&pair[1])
+            })
+        };
+
+        // Rounded measurements are integers but still continuous data
+        let heights: Vec<f64> = Normal::new(170.0, 8.0)
+            .sample(&mut Rng::new(2), 500)
+            .into_iter()
+            .map(f64::round)
+            .collect();
+        let fits = fit_all(&heights);
+        assert!(ranked(&fits));
+        let normal = fits
+            .iter()
+            .find(|fit| fit.family == Family::Normal)
+            .unwrap();
+        assert!(normal.aic < fits[0].aic + 2.0);
+        assert!(fits.iter().any(|fit| fit.family.is_discrete()));
+
+        // Negative integers rule out the discrete families only
+        let changes = [-3.0, -1.0, 0.0, 2.0, -2.0, 1.0, 0.0, -1.0, 3.0, 1.0];
+        let fits = fit_all(&changes);
+        assert!(ranked(&fits));
+        assert!(!fits.is_empty());
+        assert!(fits.iter().all(|fit| !fit.family.is_discrete()));
+        assert!(fits.iter().any(|fit| fit.family == Family::Normal));
+    }
+
+    #[test]
+    fn test_default_parameters_are_accepted_and_sampled() {
+        for family in Family::ALL {
+            let parameters = family.default_parameters();
+            assert!(family.accepts(&parameters), "{:?}", family);
+            let data = family.sample(&parameters, &mut Rng::new(3), 2000);
+            assert_eq!(
+                fit(family, &data).is_some(),
+                family.is_fitted(),
+                "{:?}",
+                family
+            );
+            let log_likelihood = family.log_likelihood(&parameters, &data);
+            assert!(log_likelihood.is_finite(), "{:?}", family);
+        }
+        assert!(!Family::Normal.accepts(&[0.0]));
+        assert!(!Family::Normal.accepts(&[0.0, -1.0]));
+        assert!(!Family::Geometric.accepts(&[1.5]));
+        assert!(!Family::Exponential.accepts(&[f64::NAN]));
+        assert!(!Family::Uniform.accepts(&[1.0, 1.0]));
+        assert!(Family::DiscreteUniform.accepts(&[-3.0, -3.0]));
+        assert!(!Family::Binomial.accepts(&[10.5, 0.5]));
+        assert!(!Family::Zipf.accepts(&[1e9, 1.0]));
+        assert!(!Family::Hypergeometric.accepts(&[10.0, 20.0, 5.0]));
+    }
+}
@@ -0,0 +1,242 @@
+//! Goodness-of-fit tests of data against a distribution.
+//!
+//! The Kolmogorov–Smirnov and Anderson–Darling p-values assume the
+//! distribution was fixed in advance; with parameters estimated from the same
+//! data they are conservative. The χ² test subtracts one degree of freedom
+//! per estimated parameter instead.
+
+use crate::stats::hypothesis::{chi_squared_goodness_of_fit, Reference, TestStatistic};
+use crate::stats::{Continuous, Discrete};
+
+/// Smallest expected count of a χ² bin.
+const MIN_EXPECTED: f64 = 5.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TestResult {
+    pub statistic: f64,
+    pub p_value: f64,
+}
+
+/// P(K > λ) for the Kolmogorov distribution.
+fn kolmogorov_sf(lambda: f64) -> f64 {
+    if lambda <= 0.0 {
+        return 1.0;
+    }
+    if lambda < 1.18 {
+        // The alternating series converges slowly here, so use the dual form
+        let y = (-std::f64::consts::PI.powi(2) / (8.0 * lambda * lambda)).exp();
+        let sum: f64 = (0..6).map(|k| y.powi((2 * k + 1) * (2 * k + 1))).sum();
+        1.0 - (2.0 * std::f64::consts::PI).sqrt() / lambda * sum
+    } else {
+        let sum: f64 = (1..=6)
+            .map(|k| {
+                let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+                sign * (-2.0 * (k * k) as f64 * lambda * lambda).exp()
+            })
+            .sum();
+        (2.0 * sum).clamp(0.0, 1.0)
+    }
+}
+
+fn sorted(data: &[f64]) -> Vec<f64> {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+/// Largest distance between the empirical cdf and the cdf of `dist`, with
+/// Stephens' small-sample correction of the asymptotic p-value.
+pub fn kolmogorov_smirnov(data: &[f64], dist: &(impl Continuous + ?Sized)) -> TestResult {
+    let n = data.len() as f64;
+    let statistic = sorted(data)
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let cdf = dist.cdf(x);
+            ((i + 1) as f64 / n - cdf).max(cdf - i as f64 / n)
+        })
+        .fold(0.0, f64::max);
+    let root = n.sqrt();
+    TestResult {
+        statistic,
+        p_value: kolmogorov_sf((root + 0.12 + 0.11 / root) * statistic),
+    }
+}
+
+/// P(A² > z) for a large sample, by Marsaglia and Marsaglia's approximation.
+fn anderson_darling_sf(z: f64) -> f64 {
+    if z <= 0.0 {
+        return 1.0;
+    }
+    let cdf = if z < 2.0 {
+        (-1.2337141 / z).exp() / z.sqrt()
+            * (2.00012
+                + (0.247105 - (0.0649821 - (0.0347962 - (0.0116720 - 0.00168691 * z) * z) * z) * z)
+                    * z)
+    } else {
+        (-(1.0776
+            - (2.30695 - (0.43424 - (0.082433 - (0.008056 - 0.0003146 * z) * z) * z) * z) * z)
+            .exp())
+        .exp()
+    };
+    (1.0 - cdf).clamp(0.0, 1.0)
+}
+
+/// Weighted squared distance between the empirical cdf and the cdf of `dist`,
+/// which weighs the tails more than Kolmogorov–Smirnov does.
+pub fn anderson_darling(data: &[f64], dist: &(impl Continuous + ?Sized)) -> TestResult {
+    let sorted = sorted(data);
+    let n = sorted.len();
+    let sum: f64 = (0..n)
+        .map(|i| {
+            let lower = dist.cdf(sorted[i]).ln();
+            let upper = dist.sf(sorted[n - 1 - i]).ln();
+            (2 * i + 1) as f64 * (lower + upper)
+        })
+        .sum();
+    let statistic = -(n as f64) - sum / n as f64;
+    TestResult {
+        statistic,
+        p_value: anderson_darling_sf(statistic),
+    }
+}
+
+/// Drops one degree of freedom per estimated parameter.
+fn with_estimated(test: TestStatistic, estimated: usize) -> Option<TestStatistic> {
+    let Reference::ChiSquared { df } = test.reference else {
+        return None;
+    };
+    let df = df - estimated as f64;
+    (df >= 1.0).then_some(TestStatistic {
+        reference: Reference::ChiSquared { df },
+        ..test
+    })
+}
+
+/// Pearson's χ² over bins equally likely under `dist`, as many as keep the
+/// expected counts at five or more.
+///
+/// Returns `None` when too few bins remain for the degrees of freedom.
+pub fn continuous_chi_squared(
+    data: &[f64],
+    dist: &(impl Continuous + ?Sized),
+    estimated: usize,
+) -> Option<TestStatistic> {
+    let n = data.len() as f64;
+    let bins = ((2.0 * n.powf(0.4)).round()).min((n / MIN_EXPECTED).floor()) as usize;
+    if bins < 2 {
+        return None;
+    }
+    let mut observed = vec![0.0; bins];
+    for &x in data {
+        let bin = (dist.cdf(x) * bins as f64) as usize;
+        observed[bin.min(bins - 1)] += 1.0;
+    }
+    let test = chi_squared_goodness_of_fit(&observed, &vec![1.0; bins])?;
+    with_estimated(test, estimated)
+}
+
+/// Pearson's χ² over runs of consecutive values of `dist`, merged until each
+/// expects five or more of the data; the end bins take in the tails.
+///
+/// Returns `None` when too few bins remain for the degrees of freedom.
+pub fn discrete_chi_squared(
+    data: &[f64],
+    dist: &(impl Discrete + ?Sized),
+    estimated: usize,
+) -> Option<TestStatistic> {
+    let n = data.len() as f64;
+    let last = dist.quantile(1.0 - MIN_EXPECTED / n / 2.0);
+    // Upper ends of the bins, each bin holding the values above the last one
+    let mut uppers = Vec::new();
+    let mut below = 0.0;
+    // Nothing below this holds enough to fill a bin, and the support may
+    // reach down to -∞
+    let mut k = dist.quantile(MIN_EXPECTED / n / 2.0);
+    while k < last {
+        let cdf = dist.cdf(k);
+        if (cdf - below) * n >= MIN_EXPECTED && (1.0 - cdf) * n >= MIN_EXPECTED {
+            uppers.push(k);
+            below = cdf;
+        }
+        k += 1;
+    }
+
+    let bin = |x: f64| {
+        uppers
+            .iter()
+            .position(|&upper| x <= upper as f64)
+            .unwrap_or(uppers.len())
+    };
+    let mut observed = vec![0.0; uppers.len() + 1];
+    for &x in data {
+        observed[bin(x)] += 1.0;
+    }
+    let mut previous = 0.0;
+    let mut proportions: Vec<f64> = uppers
+        .iter()
+        .map(|&upper| {
+            let cdf = dist.cdf(upper);
+            let p = cdf - previous;
+            previous = cdf;
+            p
+        })
+        .collect();
+    proportions.push(1.0 - previous);
+
+    let test = chi_squared_goodness_of_fit(&observed, &proportions)?;
+    with_estimated(test, estimated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::continuous::{Exponential, Normal};
+    use crate::stats::discrete::Poisson;
+    use crate::stats::hypothesis::Alternative;
+    use crate::stats::{Rng, Sample};
+
+    #[test]
+    fn test_limiting_distributions() {
+        // Tabulated values of the Kolmogorov and Anderson–Darling limits
+        assert!((kolmogorov_sf(1.0) - 0.26999967).abs() < 1e-6);
+        assert!((kolmogorov_sf(1.3581) - 0.05).abs() < 1e-4);
+        assert!((kolmogorov_sf(1.1) - kolmogorov_sf(1.2)) > 0.0);
+        assert!((anderson_darling_sf(2.492) - 0.05).abs() < 1e-3);
+        assert!((anderson_darling_sf(3.857) - 0.01).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_tests_tell_samples_apart() {
+        let normal = Normal::new(1.0, 1.0);
+        let exponential = Exponential::new(1.0);
+        let data = exponential.sample(&mut Rng::new(2), 300);
+
+        assert!(kolmogorov_smirnov(&data, &exponential).p_value > 0.01);
+        assert!(anderson_darling(&data, &exponential).p_value > 0.01);
+        assert!(kolmogorov_smirnov(&data, &normal).p_value < 1e-3);
+        assert!(anderson_darling(&data, &normal).p_value < 1e-3);
+
+        let fitted = continuous_chi_squared(&data, &exponential, 1).unwrap();
+        assert!(fitted.p_value(Alternative::Greater) > 0.01);
+        // 20 equally likely bins, less one degree of freedom for λ
+        assert!(matches!(fitted.reference, Reference::ChiSquared { df } if df == 18.0));
+        let wrong = continuous_chi_squared(&data, &normal, 2).unwrap();
+        assert!(wrong.p_value(Alternative::Greater) < 1e-3);
+
+        let poisson = Poisson::new(4.0);
+        let counts: Vec<f64> = poisson
+            .sample(&mut Rng::new(8), 500)
+            .into_iter()
+            .map(|k| k as f64)
+            .collect();
+        let test = discrete_chi_squared(&counts, &poisson, 1).unwrap();
+        assert!(test.p_value(Alternative::Greater) > 0.01);
+        assert!(
+            discrete_chi_squared(&counts, &Poisson::new(6.0), 1)
+                .unwrap()
+                .p_value(Alternative::Greater)
+                < 1e-3
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Classical hypothesis tests: t, χ² and F.
+//!
+//! Each test reduces the data to a statistic and the distribution it follows
+//! under the null hypothesis. p-values and critical regions then come from
+//! that distribution's cdf and quantile function.
+
+use crate::stats::continuous::{ChiSquared, StudentT, F};
+use crate::stats::descriptive::{mean, variance};
+use crate::stats::Continuous;
+
+/// Side of the null value the alternative hypothesis lies on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Alternative {
+    TwoSided,
+    Less,
+    Greater,
+}
+
+/// Mean, standard deviation and size of a sample.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Summary {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub n: usize,
+}
+
+impl Summary {
+    pub fn of(data: &[f64]) -> Self {
+        Self {
+            mean: mean(data),
+            std_dev: variance(data).sqrt(),
+            n: data.len(),
+        }
+    }
+
+    fn variance(&self) -> f64 {
+        self.std_dev.powi(2)
+    }
+}
+
+/// Distribution of a test statistic under the null hypothesis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Reference {
+    StudentT { df: f64 },
+    ChiSquared { df: f64 },
+    F { df1: f64, df2: f64 },
+}
+
+impl Reference {
+    pub fn dist(&self) -> Box<dyn Continuous> {
+        match *self {
+            Reference::StudentT { df } => Box::new(StudentT::new(df)),
+            Reference::ChiSquared { df } => Box::new(ChiSquared::new(df)),
+            Reference::F { df1, df2 } => Box::new(F::new(df1, df2)),
+        }
+    }
+
+    /// Symbol of the statistic: t, χ² or F.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Reference::StudentT { .. } => "t",
+            Reference::ChiSquared { .. } => "χ²",
+            Reference::F { .. } => "F",
+        }
+    }
+
+    /// The distribution with its degrees of freedom, such as "t(5)".
+    pub fn name(&self) -> String {
+        match *self {
+            Reference::StudentT { df } | Reference::ChiSquared { df } => {
+                format!("{}({})", self.symbol(), format_df(df))
+            }
+            Reference::F { df1, df2 } => format!("F({}, {})", format_df(df1), format_df(df2)),
+        }
+    }
+}
+
+/// Whole degrees of freedom print as integers, fractional ones such as Welch's
+/// to two decimals.
+fn format_df(df: f64) -> String {
+    if df.fract() == 0.0 {
+        df.to_string()
+    } else {
+        format!("{:.2}", df)
+    }
+}
+
+/// A test statistic and its null distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TestStatistic {
+    pub statistic: f64,
+    pub reference: Reference,
+}
+
+impl TestStatistic {
+    /// Probability under the null of a statistic at least as extreme.
+    pub fn p_value(&self, alternative: Alternative) -> f64 {
+        let dist = self.reference.dist();
+        match alternative {
+            Alternative::Less => dist.cdf(self.statistic),
+            Alternative::Greater => dist.sf(self.statistic),
+            Alternative::TwoSided => {
+                (2.0 * dist.cdf(self.statistic).min(dist.sf(self.statistic))).min(1.0)
+            }
+        }
+    }
+
+    /// Statistics below the first or above the second value reject the null
+    /// at level `alpha`.
+    pub fn critical_values(
+        &self,
+        alternative: Alternative,
+        alpha: f64,
+    ) -> (Option<f64>, Option<f64>) {
+        let dist = self.reference.dist();
+        match alternative {
+            Alternative::Less => (Some(dist.quantile(alpha)), None),
+            Alternative::Greater => (None, Some(dist.quantile(1.0 - alpha))),
+            Alternative::TwoSided => (
+                Some(dist.quantile(alpha / 2.0)),
+                Some(dist.quantile(1.0 - alpha / 2.0)),
+            ),
+        }
+    }
+}
+
+/// One-sample t-test of the mean against `mu0`.
+pub fn one_sample_t(sample: Summary, mu0: f64) -> TestStatistic {
+    let n = sample.n as f64;
+    TestStatistic {
+        statistic: (sample.mean - mu0) / (sample.std_dev / n.sqrt()),
+        reference: Reference::StudentT { df: n - 1.0 },
+    }
+}
+
+/// Welch's two-sample t-test of equal means, without assuming equal variances.
+pub fn welch_t(a: Summary, b: Summary) -> TestStatistic {
+    let (na, nb) = (a.n as f64, b.n as f64);
+    let (va, vb) = (a.variance() / na, b.variance() / nb);
+    // Welch–Satterthwaite degrees of freedom
+    let df = (va + vb).powi(2) / (va.powi(2) / (na - 1.0) + vb.powi(2) / (nb - 1.0));
+    TestStatistic {
+        statistic: (a.mean - b.mean) / (va + vb).sqrt(),
+        reference: Reference::StudentT { df },
+    }
+}
+
+/// F-test of equal variances, with the first sample's variance on top.
+pub fn f_test(a: Summary, b: Summary) -> TestStatistic {
+    TestStatistic {
+        statistic: a.variance() / b.variance(),
+        reference: Reference::F {
+            df1: a.n as f64 - 1.0,
+            df2: b.n as f64 - 1.0,
+        },
+    }
+}
+
+/// Pearson's χ² goodness-of-fit test of `observed` counts against category
+/// `proportions`, which are normalised to sum to one.
+///
+/// Returns `None` unless there are at least two categories, as many
+/// proportions as counts, and every expected count is positive.
+pub fn chi_squared_goodness_of_fit(observed: &[f64], proportions: &[f64]) -> Option<TestStatistic> {
+    if observed.len() < 2 || observed.len() != proportions.len() {
+        return None;
+    }
+    let total: f64 = observed.iter().sum();
+    let weight: f64 = proportions.iter().sum();
+    let mut statistic = 0.0;
+    for (&count, &proportion) in observed.iter().zip(proportions) {
+        let expected = total * proportion / weight;
+        if expected.is_nan() || expected <= 0.0 {
+            return None;
+        }
+        statistic += (count - expected).powi(2) / expected;
+    }
+    Some(TestStatistic {
+        statistic,
+        reference: Reference::ChiSquared {
+            df: observed.len() as f64 - 1.0,
+        },
+    })
+}
+
+/// Pearson's χ² test of independence for a contingency table given row by row.
+///
+/// Returns `None` unless the table is rectangular, at least 2 × 2, and every
+/// row and column has a positive total.
+pub fn chi_squared_independence(table: &[Vec<f64>]) -> Option<TestStatistic> {
+    let rows = table.len();
+    let columns = table.first().map_or(0, Vec::len);
+    if rows < 2 || columns < 2 || table.iter().any(|row| row.len() != columns) {
+        return None;
+    }
+    let row_totals: Vec<f64> = table.iter().map(|row| row.iter().sum()).collect();
+    let column_totals: Vec<f64> = (0..columns)
+        .map(|j| table.iter().map(|row| row[j]).sum())
+        .collect();
+    let total: f64 = row_totals.iter().sum();
+    if row_totals
+        .iter()
+        .chain(&column_totals)
+        .any(|&sum| sum.is_nan() || sum <= 0.0)
+    {
+        return None;
+    }
+    let statistic = table
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| {
+            let row_totals = &row_totals;
+            let column_totals = &column_totals;
+            row.iter().enumerate().map(move |(j, &count)| {
+                let expected = row_totals[i] * column_totals[j] / total;
+                (count - expected).powi(2) / expected
+            })
+        })
+        .sum();
+    Some(TestStatistic {
+        statistic,
+        reference: Reference::ChiSquared {
+            df: ((rows - 1) * (columns - 1)) as f64,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{} is not within {} of {}",
+            actual,
+            tolerance,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_t_tests() {
+        // Reference values by numerical integration of the t density
+        let sample = Summary::of(&[5.1, 4.9, 5.6, 5.8, 6.0, 5.3]);
+        let test = one_sample_t(sample, 5.0);
+        assert_close(test.statistic, 2.60532, 1e-4);
+        assert_close(test.p_value(Alternative::TwoSided), 0.04794, 1e-4);
+        assert_close(test.p_value(Alternative::Greater), 0.02397, 1e-4);
+        let (lower, upper) = test.critical_values(Alternative::TwoSided, 0.05);
+        assert_close(upper.unwrap(), 2.5706, 1e-3);
+        assert_close(lower.unwrap(), -2.5706, 1e-3);
+        assert_eq!(test.reference.name(), "t(5)");
+
+        let test = welch_t(
+            Summary::of(&[1.0, 2.0, 3.0, 4.0, 5.0]),
+            Summary::of(&[2.0, 4.0, 6.0, 8.0, 10.0, 12.0]),
+        );
+        assert_close(test.statistic, -2.37635, 1e-4);
+        assert_close(test.p_value(Alternative::TwoSided), 0.04928, 1e-4);
+        assert_eq!(test.reference.name(), "t(6.97)");
+    }
+
+    #[test]
+    fn test_f_test() {
+        let test = f_test(
+            Summary::of(&[1.0, 2.0, 3.0, 4.0, 5.0]),
+            Summary::of(&[2.0, 4.0, 6.0, 8.0, 10.0, 12.0]),
+        );
+        assert_close(test.statistic, 2.5 / 14.0, 1e-12);
+        assert_close(test.p_value(Alternative::TwoSided), 0.12004, 1e-4);
+    }
+
+    #[test]
+    fn test_chi_squared_tests() {
+        let test =
+            chi_squared_goodness_of_fit(&[18.0, 22.0, 30.0, 30.0], &[1.0, 1.0, 1.0, 1.0]).unwrap();
+        assert_close(test.statistic, 4.32, 1e-12);
+        assert_close(test.p_value(Alternative::Greater), 0.22892, 1e-4);
+        assert!(chi_squared_goodness_of_fit(&[1.0, 2.0], &[1.0]).is_none());
+        assert!(chi_squared_goodness_of_fit(&[1.0, 2.0], &[1.0, 0.0]).is_none());
+
+        let test = chi_squared_independence(&[vec![20.0, 30.0], vec![25.0, 25.0]]).unwrap();
+        assert_close(test.statistic, 100.0 / 99.0, 1e-12);
+        assert_close(test.p_value(Alternative::Greater), 0.31488, 1e-4);
+        assert!(chi_squared_independence(&[vec![1.0, 2.0], vec![3.0]]).is_none());
+    }
+}
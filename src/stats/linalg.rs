@@ -0,0 +1,145 @@
+//! Small dense linear algebra for covariance matrices.
+//!
+//! Matrices are row-major `Vec<Vec<f64>>`; the dimensions involved are tiny,
+//! so clarity wins over speed.
+
+pub type Matrix = Vec<Vec<f64>>;
+
+/// Lower-triangular L with L Lᵀ = `matrix`.
+///
+/// Returns `None` unless `matrix` is square, symmetric and positive definite.
+pub fn cholesky(matrix: &[Vec<f64>]) -> Option<Matrix> {
+    let n = matrix.len();
+    if matrix.iter().any(|row| row.len() != n) {
+        return None;
+    }
+    let symmetric = (0..n).all(|i| {
+        (0..i).all(|j| {
+            let scale = matrix[i][j].abs().max(matrix[j][i].abs()).max(1.0);
+            (matrix[i][j] - matrix[j][i]).abs() <= 1e-12 * scale
+        })
+    });
+    if !symmetric {
+        return None;
+    }
+
+    let mut lower = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..=i {
+            let dot: f64 = (0..j).map(|k| lower[i][k] * lower[j][k]).sum();
+            if i == j {
+                let pivot = matrix[i][i] - dot;
+                if pivot <= 0.0 || !pivot.is_finite() {
+                    return None;
+                }
+                lower[i][i] = pivot.sqrt();
+            } else {
+                lower[i][j] = (matrix[i][j] - dot) / lower[j][j];
+            }
+        }
+    }
+    Some(lower)
+}
+
+/// Solves L x = b for lower-triangular L.
+pub fn solve_lower(lower: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let mut x = vec![0.0; b.len()];
+    for i in 0..b.len() {
+        let dot: f64 = (0..i).map(|k| lower[i][k] * x[k]).sum();
+        x[i] = (b[i] - dot) / lower[i][i];
+    }
+    x
+}
+
+/// Solves Lᵀ x = b for lower-triangular L.
+pub fn solve_upper_transposed(lower: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n = b.len();
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let dot: f64 = (i + 1..n).map(|k| lower[k][i] * x[k]).sum();
+        x[i] = (b[i] - dot) / lower[i][i];
+    }
+    x
+}
+
+/// Solves A x = b given the Cholesky factor L of A.
+pub fn cholesky_solve(lower: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    solve_upper_transposed(lower, &solve_lower(lower, b))
+}
+
+/// Rows `rows` and columns `columns` of `matrix`.
+pub fn submatrix(matrix: &[Vec<f64>], rows: &[usize], columns: &[usize]) -> Matrix {
+    rows.iter()
+        .map(|&i| columns.iter().map(|&j| matrix[i][j]).collect())
+        .collect()
+}
+
+/// Eigen-decomposition of the symmetric matrix [[a, b], [b, d]].
+///
+/// Returns (eigenvalue, unit eigenvector) pairs, largest eigenvalue first.
+pub fn symmetric_eigen_2x2(a: f64, b: f64, d: f64) -> [(f64, [f64; 2]); 2] {
+    let middle = (a + d) / 2.0;
+    let radius = (((a - d) / 2.0).powi(2) + b * b).sqrt();
+    let (major, minor) = (middle + radius, middle - radius);
+    let direction = if b != 0.0 {
+        let (x, y) = (major - d, b);
+        let norm = x.hypot(y);
+        [x / norm, y / norm]
+    } else if a >= d {
+        [1.0, 0.0]
+    } else {
+        [0.0, 1.0]
+    };
+    [(major, direction), (minor, [-direction[1], direction[0]])]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cholesky_reconstructs_matrix() {
+        let matrix = vec![
+            vec![4.0, 2.0, 0.4],
+            vec![2.0, 5.0, 1.0],
+            vec![0.4, 1.0, 3.0],
+        ];
+        let lower = cholesky(&matrix).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let product: f64 = (0..3).map(|k| lower[i][k] * lower[j][k]).sum();
+                assert!((product - matrix[i][j]).abs() < 1e-12);
+            }
+        }
+
+        let x = cholesky_solve(&lower, &[1.0, 2.0, 3.0]);
+        for (i, row) in matrix.iter().enumerate() {
+            let product: f64 = row.iter().zip(&x).map(|(a, x)| a * x).sum();
+            assert!((product - (i + 1) as f64).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_symmetric_eigen_2x2() {
+        let (a, b, d) = (2.0, 0.8, 1.0);
+        for (value, [x, y]) in symmetric_eigen_2x2(a, b, d) {
+            assert!((a * x + b * y - value * x).abs() < 1e-12);
+            assert!((b * x + d * y - value * y).abs() < 1e-12);
+            assert!((x.hypot(y) - 1.0).abs() < 1e-12);
+        }
+        assert_eq!(symmetric_eigen_2x2(1.0, 0.0, 3.0)[0], (3.0, [0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_cholesky_rejects_invalid_matrices() {
+        // Not positive definite: correlations 0.9, 0.9 and -0.9 are inconsistent
+        let indefinite = vec![
+            vec![1.0, 0.9, 0.9],
+            vec![0.9, 1.0, -0.9],
+            vec![0.9, -0.9, 1.0],
+        ];
+        assert!(cholesky(&indefinite).is_none());
+        assert!(cholesky(&[vec![1.0, 0.5], vec![0.2, 1.0]]).is_none());
+        assert!(cholesky(&[vec![1.0, 0.0]]).is_none());
+    }
+}
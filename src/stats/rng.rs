@@ -0,0 +1,119 @@
+//! Seedable pseudo-random number generator used for sampling.
+//!
+//! xoshiro256** seeded through SplitMix64. It is pure Rust, so the same seed
+//! yields the same draws natively, in tests and on wasm32.
+
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: [u64; 4],
+    spare_normal: Option<f64>,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        let mut seed = seed;
+        let mut splitmix = || {
+            seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        Self {
+            state: [splitmix(), splitmix(), splitmix(), splitmix()],
+            spare_normal: None,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    /// Uniform draw from [0, 1) with 53 bits of precision.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Uniform draw from (0, 1], safe to take the logarithm of.
+    pub fn next_f64_nonzero(&mut self) -> f64 {
+        1.0 - self.next_f64()
+    }
+
+    /// Standard normal draw via the Box–Muller transform.
+    ///
+    /// Each transform yields two independent normals; the second is kept for
+    /// the next call.
+    pub fn standard_normal(&mut self) -> f64 {
+        if let Some(spare) = self.spare_normal.take() {
+            return spare;
+        }
+        let radius = (-2.0 * self.next_f64_nonzero().ln()).sqrt();
+        let angle = 2.0 * std::f64::consts::PI * self.next_f64();
+        self.spare_normal = Some(radius * angle.sin());
+        radius * angle.cos()
+    }
+
+    /// Exponential draw with rate 1.
+    pub fn standard_exponential(&mut self) -> f64 {
+        -self.next_f64_nonzero().ln()
+    }
+
+    /// Gamma draw with the given shape and rate 1 (Marsaglia–Tsang).
+    pub fn standard_gamma(&mut self, shape: f64) -> f64 {
+        if shape < 1.0 {
+            // Boost to shape + 1 and scale back: G(a) = G(a + 1) * U^(1/a)
+            let u = self.next_f64_nonzero();
+            return self.standard_gamma(shape + 1.0) * u.powf(1.0 / shape);
+        }
+
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+        loop {
+            let (x, v) = loop {
+                let x = self.standard_normal();
+                let v = 1.0 + c * x;
+                if v > 0.0 {
+                    break (x, v * v * v);
+                }
+            };
+            let u = self.next_f64_nonzero();
+            if u < 1.0 - 0.0331 * x.powi(4) || u.ln() < 0.5 * x * x + d * (1.0 - v + v.ln()) {
+                return d * v;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_uniform_range_and_mean() {
+        let mut rng = Rng::new(7);
+        let draws: Vec<f64> = (0..100_000).map(|_| rng.next_f64()).collect();
+        assert!(draws.iter().all(|u| (0.0..1.0).contains(u)));
+        let mean = draws.iter().sum::<f64>() / draws.len() as f64;
+        assert!((mean - 0.5).abs() < 0.01);
+    }
+}
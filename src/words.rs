@@ -88,7 +88,7 @@ pub fn words() -> Html {
     }
 
     let mut sorted_words = (*words).clone();
-    sorted_words.sort_by(|a, b| b.priority.cmp(&a.priority));
+    sorted_words.sort_by_key(|word| std::cmp::Reverse(word.priority));
 
     let words_props = WordsProps {
         words: sorted_words,
@@ -115,7 +115,7 @@ fn word_list(WordsProps { words }: &WordsProps) -> Html {
     let words_per_page = 2;
     let selected_word = use_state(|| None);
 
-    let total_pages = (words.len() + words_per_page - 1) / words_per_page;
+    let total_pages = words.len().div_ceil(words_per_page);
 
     let on_next = {
         let page_index = page_index.clone();